@@ -0,0 +1,172 @@
+//! 20 years of daily closes for 3000 symbols, columnar `TimeSeries1D` against the `BTreeMap`
+//! layout it replaced.
+//!
+//! `cargo bench --bench time_series`
+use std::collections::BTreeMap;
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+use luckless::time_series::{DataPointValue, TimeSeries1D, TimeStamp};
+
+const SYMBOLS: usize = 3000;
+const TRADING_DAYS: usize = 20 * 252;
+const WINDOW: usize = 200;
+
+/// Storage and operations of `TimeSeries1D` before it was columnar
+struct TreeSeries {
+    data: BTreeMap<TimeStamp, DataPointValue>,
+}
+
+impl TreeSeries {
+    fn from_vec(index: Vec<TimeStamp>, values: Vec<DataPointValue>) -> Self {
+        TreeSeries {
+            data: index.into_iter().zip(values).collect(),
+        }
+    }
+    fn index(&self) -> Vec<TimeStamp> {
+        self.data.keys().cloned().collect()
+    }
+    fn values(&self) -> Vec<DataPointValue> {
+        self.data.values().cloned().collect()
+    }
+    fn sma(&self, window_size: usize) -> Self {
+        let mut index = self.index();
+        index.drain(..window_size - 1);
+        let values = self
+            .values()
+            .windows(window_size)
+            .map(|window| window.iter().sum::<DataPointValue>() / window_size as DataPointValue)
+            .collect();
+        TreeSeries::from_vec(index, values)
+    }
+    fn ts_div(&self, rhs: &TreeSeries) -> Self {
+        TreeSeries {
+            data: self
+                .data
+                .iter()
+                .filter_map(|(timestamp, l)| rhs.data.get(timestamp).map(|r| (*timestamp, l / r)))
+                .collect(),
+        }
+    }
+    fn filter_ge(&self, timestamp: &TimeStamp) -> Self {
+        TreeSeries {
+            data: self
+                .data
+                .iter()
+                .filter(|(t, _)| *t >= timestamp)
+                .map(|(t, value)| (*t, *value))
+                .collect(),
+        }
+    }
+}
+
+fn universe() -> (Vec<TimeStamp>, Vec<Vec<DataPointValue>>) {
+    let index = TimeSeries1D::from_values(vec![0.; TRADING_DAYS]).index();
+    let prices = (0..SYMBOLS)
+        .map(|symbol| {
+            (0..TRADING_DAYS)
+                .map(|day| 100. + ((symbol * 7 + day) % 101) as DataPointValue)
+                .collect()
+        })
+        .collect();
+    (index, prices)
+}
+
+fn columnar(c: &mut Criterion) {
+    let (index, prices) = universe();
+    let last_year = index[TRADING_DAYS - 252];
+    let mut group = c.benchmark_group("columnar");
+    group.sample_size(10);
+    group.bench_function(BenchmarkId::new("from_vec", SYMBOLS), |b| {
+        b.iter(|| {
+            prices
+                .iter()
+                .map(|values| TimeSeries1D::from_vec(index.clone(), values.clone()))
+                .collect::<Vec<_>>()
+        })
+    });
+    let universe: Vec<TimeSeries1D> = prices
+        .iter()
+        .map(|values| TimeSeries1D::from_vec(index.clone(), values.clone()))
+        .collect();
+    group.bench_function(BenchmarkId::new("sma", SYMBOLS), |b| {
+        b.iter(|| {
+            universe
+                .iter()
+                .map(|ts| ts.sma(WINDOW).len())
+                .sum::<usize>()
+        })
+    });
+    group.bench_function(BenchmarkId::new("ts_div", SYMBOLS), |b| {
+        b.iter(|| {
+            universe
+                .windows(2)
+                .map(|pair| pair[0].ts_div(&pair[1]).len())
+                .sum::<usize>()
+        })
+    });
+    group.bench_function(BenchmarkId::new("filter_ge", SYMBOLS), |b| {
+        b.iter(|| {
+            universe
+                .iter()
+                .map(|ts| ts.filter_ge(black_box(&last_year)).len())
+                .sum::<usize>()
+        })
+    });
+    group.bench_function(BenchmarkId::new("range", SYMBOLS), |b| {
+        b.iter(|| {
+            universe
+                .iter()
+                .map(|ts| ts.range(black_box(last_year)..).len())
+                .sum::<usize>()
+        })
+    });
+    group.finish();
+}
+
+fn btree_map(c: &mut Criterion) {
+    let (index, prices) = universe();
+    let last_year = index[TRADING_DAYS - 252];
+    let mut group = c.benchmark_group("btree_map");
+    group.sample_size(10);
+    group.bench_function(BenchmarkId::new("from_vec", SYMBOLS), |b| {
+        b.iter(|| {
+            prices
+                .iter()
+                .map(|values| TreeSeries::from_vec(index.clone(), values.clone()))
+                .collect::<Vec<_>>()
+        })
+    });
+    let universe: Vec<TreeSeries> = prices
+        .iter()
+        .map(|values| TreeSeries::from_vec(index.clone(), values.clone()))
+        .collect();
+    group.bench_function(BenchmarkId::new("sma", SYMBOLS), |b| {
+        b.iter(|| {
+            universe
+                .iter()
+                .map(|ts| ts.sma(WINDOW).data.len())
+                .sum::<usize>()
+        })
+    });
+    group.bench_function(BenchmarkId::new("ts_div", SYMBOLS), |b| {
+        b.iter(|| {
+            universe
+                .windows(2)
+                .map(|pair| pair[0].ts_div(&pair[1]).data.len())
+                .sum::<usize>()
+        })
+    });
+    group.bench_function(BenchmarkId::new("filter_ge", SYMBOLS), |b| {
+        b.iter(|| {
+            universe
+                .iter()
+                .map(|ts| ts.filter_ge(black_box(&last_year)).data.len())
+                .sum::<usize>()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, columnar, btree_map);
+criterion_main!(benches);
@@ -6,6 +6,7 @@ use std::path::PathBuf;
 use dotenv::dotenv;
 
 const DEFAULT_PROTO: &str = "proto";
+const PROTO_FILES: &[&str] = &["broker_service.proto", "query.proto", "scoring.proto"];
 
 fn main() {
     dotenv().ok();
@@ -20,12 +21,15 @@ fn main() {
             PathBuf::from(DEFAULT_PROTO)
         }
     };
-    let proto_path = proto_root.join("query.proto");
-    println!("generating code from: {:?}", proto_path);
+    let proto_paths: Vec<PathBuf> = PROTO_FILES
+        .iter()
+        .map(|proto_file| proto_root.join(proto_file))
+        .collect();
+    println!("generating code from: {:?}", proto_paths);
     protoc_rust_grpc::Codegen::new()
         .out_dir("src")
         .include("proto")
-        .inputs(&[proto_path.to_str().expect("query.proto")])
+        .inputs(&proto_paths)
         .rust_protobuf(true)
         .run()
         .expect("protoc-rust-grpc");
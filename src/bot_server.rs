@@ -0,0 +1,357 @@
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::thread;
+
+use grpc::{GrpcStatus, ServerHandlerContext, ServerRequestSingle, ServerResponseUnarySink};
+use log::{info, warn};
+use protobuf::{RepeatedField, SingularPtrField};
+
+use crate::bot::asset_score::{AssetScore, RunnableStrategy};
+use crate::calendar::TradingCalendar;
+use crate::data::{from_proto, Asset, DataClient, Symbol};
+use crate::dto::strategy::StrategyDto;
+use crate::errors::{
+    AssetNotFoundError, CliArgError, GenError, GenResult, InvalidStrategyError, OrderError,
+    QueryError,
+};
+use crate::query_client::{parse_strategy_yaml, to_proto_time_series};
+use crate::scoring::{
+    BackTestRequest, BackTestResponse, ScoreRequest, ScoreResponse, ScoredAsset, ValidateRequest,
+    ValidateResponse,
+};
+use crate::scoring_grpc::{Bot, BotServer};
+use crate::time_series::{Frequency, TimeSeries1D, TimeStamp};
+
+pub const DEFAULT_BOT_PORT: u16 = 50053;
+
+/// Implements the `Bot` gRPC service on top of `RunnableStrategy`.
+///
+/// Each request is handled on its own thread so long back tests do not block the event loop.
+#[derive(Clone)]
+pub struct BotService {
+    data_client: Arc<dyn DataClient + Send + Sync>,
+    /// Directory of `<strategy_id>.yaml` files
+    strategy_dir: PathBuf,
+    calendar: TradingCalendar,
+}
+
+impl BotService {
+    pub fn new(data_client: Arc<dyn DataClient + Send + Sync>, strategy_dir: PathBuf) -> Self {
+        BotService {
+            data_client,
+            strategy_dir,
+            calendar: TradingCalendar::default(),
+        }
+    }
+
+    /// Trading calendar of every strategy run by the service
+    pub fn set_calendar(&mut self, calendar: TradingCalendar) {
+        self.calendar = calendar;
+    }
+
+    /// Resolve the strategy given inline or by id.
+    fn strategy(&self, strategy_yaml: &str, strategy_id: &str) -> GenResult<StrategyDto> {
+        if !strategy_yaml.is_empty() {
+            return Ok(serde_yaml::from_str(strategy_yaml)?);
+        }
+        if strategy_id.is_empty() {
+            return Err(CliArgError::new(String::from(
+                "strategy_yaml or strategy_id is required",
+            )));
+        }
+        // ids must not escape strategy_dir
+        if Path::new(strategy_id).components().count() != 1 {
+            return Err(CliArgError::new(format!("strategy_id {}", strategy_id)));
+        }
+        let strategy_path = self.strategy_dir.join(format!("{}.yaml", strategy_id));
+        match parse_strategy_yaml(strategy_path.to_str().unwrap_or_default()) {
+            Ok(strategy) => Ok(strategy),
+            Err(reason) => Err(InvalidStrategyError::new(strategy_id.to_string(), reason)),
+        }
+    }
+
+    fn runnable_strategy(
+        &self,
+        strategy_yaml: &str,
+        strategy_id: &str,
+    ) -> GenResult<RunnableStrategy> {
+        let mut runnable_strategy = RunnableStrategy::new(
+            self.strategy(strategy_yaml, strategy_id)?,
+            self.data_client.duplicate(),
+        )?;
+        runnable_strategy.set_calendar(self.calendar.clone());
+        Ok(runnable_strategy)
+    }
+
+    /// Resolve the strategy universe as of `timestamp`, `symbols` replace its included symbols
+    /// unless empty.
+    fn assets(
+        &self,
+        runnable_strategy: &RunnableStrategy,
+        symbols: &[Symbol],
+        timestamp: TimeStamp,
+    ) -> GenResult<Vec<Asset>> {
+        runnable_strategy
+            .strategy()
+            .universe()
+            .with_include(symbols.to_vec())
+            .resolve(self.data_client.as_ref(), timestamp)
+    }
+
+    pub fn score_assets(&self, request: &ScoreRequest) -> GenResult<ScoreResponse> {
+        let runnable_strategy =
+            self.runnable_strategy(&request.strategy_yaml, &request.strategy_id)?;
+        let timestamp = required_timestamp(&request.timestamp, "timestamp")?;
+        let assets = self.assets(&runnable_strategy, request.get_symbols(), timestamp)?;
+        let asset_scores = runnable_strategy.run_on_assets(assets, timestamp)?;
+        let mut response = ScoreResponse::new();
+        response.scores = asset_scores.values().map(scored_asset).collect();
+        Ok(response)
+    }
+
+    pub fn run_back_test(&self, request: &BackTestRequest) -> GenResult<BackTestResponse> {
+        let runnable_strategy =
+            self.runnable_strategy(&request.strategy_yaml, &request.strategy_id)?;
+        let first = required_timestamp(&request.first, "first")?;
+        let last = required_timestamp(&request.last, "last")?;
+        if first > last {
+            return Err(CliArgError::new(String::from("first > last")));
+        }
+        let assets = self.assets(&runnable_strategy, request.get_symbols(), first)?;
+        let frequency = if request.frequency.is_empty() {
+            Frequency::Daily
+        } else {
+            Frequency::try_from(request.frequency.as_str())
+                .map_err(|e| CliArgError::new(format!("frequency: {}", e)) as GenError)?
+        };
+        let back_test: BTreeMap<Asset, TimeSeries1D> =
+            runnable_strategy.back_test_every(assets, first, last, frequency)?;
+        let mut response = BackTestResponse::new();
+        response.scores = back_test
+            .iter()
+            .map(|(asset, scores)| {
+                let mut scored_asset = ScoredAsset::new();
+                scored_asset.symbol = asset.symbol().to_string();
+                scored_asset.status = String::from("Complete");
+                scored_asset.score = SingularPtrField::some(to_proto_time_series(scores));
+                scored_asset
+            })
+            .collect();
+        Ok(response)
+    }
+
+    /// An invalid strategy is a valid response, not an error.
+    pub fn validate_strategy(&self, request: &ValidateRequest) -> ValidateResponse {
+        let mut response = ValidateResponse::new();
+        match self.runnable_strategy(&request.strategy_yaml, &request.strategy_id) {
+            Ok(runnable_strategy) => {
+                response.valid = true;
+                response.execution_order =
+                    RepeatedField::from_vec(runnable_strategy.execution_order());
+            }
+            Err(e) => {
+                response.valid = false;
+                response.reason = e.to_string();
+            }
+        }
+        response
+    }
+}
+
+impl Bot for BotService {
+    fn score_assets(
+        &self,
+        _o: ServerHandlerContext,
+        req: ServerRequestSingle<ScoreRequest>,
+        resp: ServerResponseUnarySink<ScoreResponse>,
+    ) -> grpc::Result<()> {
+        let service = self.clone();
+        thread::spawn(move || reply(resp, service.score_assets(&req.message)));
+        Ok(())
+    }
+
+    fn run_back_test(
+        &self,
+        _o: ServerHandlerContext,
+        req: ServerRequestSingle<BackTestRequest>,
+        resp: ServerResponseUnarySink<BackTestResponse>,
+    ) -> grpc::Result<()> {
+        let service = self.clone();
+        thread::spawn(move || reply(resp, service.run_back_test(&req.message)));
+        Ok(())
+    }
+
+    fn validate_strategy(
+        &self,
+        _o: ServerHandlerContext,
+        req: ServerRequestSingle<ValidateRequest>,
+        resp: ServerResponseUnarySink<ValidateResponse>,
+    ) -> grpc::Result<()> {
+        let service = self.clone();
+        thread::spawn(move || reply(resp, Ok(service.validate_strategy(&req.message))));
+        Ok(())
+    }
+}
+
+pub(crate) fn reply<Resp: Send + 'static>(
+    resp: ServerResponseUnarySink<Resp>,
+    result: GenResult<Resp>,
+) {
+    let sent = match result {
+        Ok(response) => resp.finish(response),
+        Err(e) => resp.send_grpc_error(grpc_status(&e), e.to_string()),
+    };
+    if let Err(e) = sent {
+        warn!("unable to send response: {}", e);
+    }
+}
+
+/// Client mistakes map to INVALID_ARGUMENT and NOT_FOUND, anything else is INTERNAL.
+pub(crate) fn grpc_status(error: &GenError) -> GrpcStatus {
+    if error.is::<CliArgError>()
+        || error.is::<InvalidStrategyError>()
+        || error.is::<OrderError>()
+        || error.is::<serde_yaml::Error>()
+    {
+        GrpcStatus::Argument
+    } else if error.is::<AssetNotFoundError>() {
+        GrpcStatus::NotFound
+    } else {
+        GrpcStatus::Internal
+    }
+}
+
+fn required_timestamp(
+    timestamp: &SingularPtrField<protobuf::well_known_types::Timestamp>,
+    name: &str,
+) -> GenResult<TimeStamp> {
+    match timestamp.as_ref() {
+        Some(timestamp) => Ok(from_proto(timestamp.clone())),
+        None => Err(CliArgError::new(format!("{} is required", name))),
+    }
+}
+
+fn scored_asset(asset_score: &AssetScore) -> ScoredAsset {
+    let mut scored_asset = ScoredAsset::new();
+    scored_asset.symbol = asset_score.asset().symbol().to_string();
+    scored_asset.status = format!("{:?}", asset_score.status());
+    scored_asset.score = SingularPtrField::some(to_proto_time_series(asset_score.score()));
+    scored_asset
+}
+
+/// Host the `Bot` service on `port`, the returned server stops when dropped.
+pub fn serve(service: BotService, port: u16) -> GenResult<grpc::Server> {
+    let mut server_builder = grpc::ServerBuilder::new_plain();
+    server_builder
+        .http
+        .set_addr(("0.0.0.0", port))
+        .map_err(|e| QueryError::new(format!("invalid port {}: {}", port, e)))?;
+    server_builder.add_service(BotServer::new_service_def(service));
+    let server = server_builder
+        .build()
+        .map_err(|e| QueryError::new(format!("unable to serve on port {}: {}", port, e)))?;
+    info!("Bot service listening on {}", server.local_addr());
+    Ok(server)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use std::sync::Arc;
+
+    use grpc::GrpcStatus;
+
+    use crate::bot_server::{grpc_status, BotService};
+    use crate::data::to_proto;
+    use crate::errors::{AssetNotFoundError, GenError, GenResult, QueryError};
+    use crate::mock_client::MockDataClient;
+    use crate::scoring::{BackTestRequest, ScoreRequest, ValidateRequest};
+    use crate::time_series::TimeSeries1D;
+    use protobuf::{RepeatedField, SingularPtrField};
+
+    fn service_fixture() -> BotService {
+        BotService::new(Arc::new(MockDataClient::new()), PathBuf::from("."))
+    }
+
+    #[test]
+    fn score_assets_by_strategy_id() -> GenResult<()> {
+        let mut request = ScoreRequest::new();
+        request.strategy_id = String::from("strategy");
+        request.symbols = RepeatedField::from_vec(vec![String::from("A")]);
+        request.timestamp = SingularPtrField::some(to_proto(MockDataClient::today()));
+        let response = service_fixture().score_assets(&request)?;
+        assert_eq!(response.scores.len(), 1);
+        assert_eq!(response.scores[0].symbol, "A");
+        assert_eq!(response.scores[0].status, "Complete");
+        assert!(!response.scores[0].get_score().data.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn score_unknown_symbol() {
+        let mut request = ScoreRequest::new();
+        request.strategy_id = String::from("strategy");
+        request.symbols = RepeatedField::from_vec(vec![String::from("ZZZ")]);
+        request.timestamp = SingularPtrField::some(to_proto(MockDataClient::today()));
+        let error = service_fixture()
+            .score_assets(&request)
+            .err()
+            .expect("error");
+        assert!(matches!(grpc_status(&error), GrpcStatus::NotFound));
+    }
+
+    #[test]
+    fn run_back_test() -> GenResult<()> {
+        let last = MockDataClient::today();
+        let mut request = BackTestRequest::new();
+        request.strategy_id = String::from("strategy");
+        request.first = SingularPtrField::some(to_proto(last - TimeSeries1D::index_unit()));
+        request.last = SingularPtrField::some(to_proto(last));
+        let response = service_fixture().run_back_test(&request)?;
+        assert_eq!(response.scores.len(), 3);
+        response
+            .scores
+            .iter()
+            .for_each(|scored_asset| assert_eq!(scored_asset.get_score().data.len(), 2));
+        // a single step closes the week
+        request.frequency = String::from("weekly");
+        let response = service_fixture().run_back_test(&request)?;
+        assert_eq!(response.scores[0].get_score().data.len(), 1);
+        request.frequency = String::from("hourly");
+        let error = service_fixture()
+            .run_back_test(&request)
+            .err()
+            .expect("error");
+        assert!(matches!(grpc_status(&error), GrpcStatus::Argument));
+        Ok(())
+    }
+
+    #[test]
+    fn validate_strategy() {
+        let service = service_fixture();
+        let mut request = ValidateRequest::new();
+        request.strategy_id = String::from("strategy");
+        let response = service.validate_strategy(&request);
+        assert!(response.valid);
+        assert_eq!(
+            response.execution_order.last().map(|s| s.as_str()),
+            Some("sma_gap")
+        );
+
+        let mut request = ValidateRequest::new();
+        request.strategy_id = String::from("../strategy");
+        let response = service.validate_strategy(&request);
+        assert!(!response.valid);
+        assert!(!response.reason.is_empty());
+    }
+
+    #[test]
+    fn error_status() {
+        let not_found: GenError = AssetNotFoundError::new(String::from("A"));
+        let internal: GenError = QueryError::new(String::from("down"));
+        assert!(matches!(grpc_status(&not_found), GrpcStatus::NotFound));
+        assert!(matches!(grpc_status(&internal), GrpcStatus::Internal));
+    }
+}
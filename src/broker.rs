@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+
+use crate::data::Symbol;
+use crate::errors::GenResult;
+use crate::orders::{Order, Positions, Price, Quantity, Side};
+use crate::time_series::TimeStamp;
+
+pub type OrderId = String;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OrderStatus {
+    Pending,
+    Filled,
+    Rejected { reason: String },
+}
+
+/// Execution of (part of) an order.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Fill {
+    pub order_id: OrderId,
+    pub symbol: Symbol,
+    pub side: Side,
+    pub quantity: Quantity,
+    pub price: Price,
+    pub timestamp: TimeStamp,
+}
+
+impl Fill {
+    pub fn value(&self) -> f64 {
+        self.quantity * self.price
+    }
+}
+
+/// Account at a broker, the trading counterpart of `DataClient`.
+pub trait BrokerClient {
+    fn positions(&self) -> GenResult<Positions>;
+    fn cash(&self) -> GenResult<f64>;
+    /// Submit `order` for execution, its progress is polled with `order_status`.
+    fn submit_order(&mut self, order: Order) -> GenResult<OrderId>;
+    fn order_status(&self, order_id: &str) -> GenResult<OrderStatus>;
+    /// Every fill of the account, oldest first.
+    fn fills(&self) -> GenResult<Vec<Fill>>;
+}
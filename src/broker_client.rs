@@ -0,0 +1,318 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use grpc::{ClientConf, ClientStubExt};
+use log::info;
+use protobuf::SingularPtrField;
+use serde::{Deserialize, Serialize};
+
+use crate::broker::{BrokerClient, Fill, OrderId, OrderStatus};
+use crate::broker_service;
+use crate::broker_service::{
+    Account, AccountRequest, FillsRequest, OrderState, OrderStatusRequest, OrderStatusResponse,
+    SubmitOrderRequest,
+};
+use crate::broker_service_grpc::BrokerClient as BrokerStub;
+use crate::data::{from_proto, to_proto};
+use crate::errors::{BrokerError, GenError, GenResult};
+use crate::orders::{Order, Positions, Side};
+use crate::query_client::call_with_deadline;
+
+pub const DEFAULT_BROKER_PORT: u16 = 50054;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BrokerClientConfig {
+    pub host: String,
+    pub port: u16,
+    pub tls: bool,
+    /// Deadline of each request
+    pub timeout_ms: u64,
+    /// Account traded by the bot
+    pub account_id: String,
+}
+
+impl Default for BrokerClientConfig {
+    fn default() -> Self {
+        BrokerClientConfig {
+            host: String::from("localhost"),
+            port: DEFAULT_BROKER_PORT,
+            tls: false,
+            timeout_ms: 5000,
+            account_id: String::from("paper"),
+        }
+    }
+}
+
+impl BrokerClientConfig {
+    pub fn timeout(&self) -> Duration {
+        Duration::from_millis(self.timeout_ms)
+    }
+}
+
+/// `BrokerClient` of an account held by the Luckless broker service.
+///
+/// Requests are never retried, a timed out `SubmitOrder` may still have been executed.
+pub struct GrpcBrokerClient {
+    config: BrokerClientConfig,
+    broker_stub: Arc<BrokerStub>,
+}
+
+impl GrpcBrokerClient {
+    pub fn new(config: BrokerClientConfig) -> GenResult<Self> {
+        info!(
+            "Building gRPC client for {}:{:?} (tls: {})",
+            config.host, config.port, config.tls
+        );
+        let conf = ClientConf::new();
+        let broker_stub = if config.tls {
+            BrokerStub::new_tls::<tls_api_native_tls::TlsConnector>(&config.host, config.port, conf)
+        } else {
+            BrokerStub::new_plain(&config.host, config.port, conf)
+        }
+        .map_err(|e| {
+            BrokerError::new(format!(
+                "unable to build client for {}:{}: {}",
+                config.host, config.port, e
+            )) as GenError
+        })?;
+        Ok(GrpcBrokerClient {
+            config,
+            broker_stub: Arc::new(broker_stub),
+        })
+    }
+
+    pub fn config(&self) -> &BrokerClientConfig {
+        &self.config
+    }
+
+    pub fn account(&self) -> GenResult<Account> {
+        let mut request = AccountRequest::new();
+        request.account_id = self.config.account_id.clone();
+        self.call("GetAccount", request, |stub, o, req| {
+            stub.get_account(o, req)
+        })
+    }
+
+    fn call<Req, Resp, F>(&self, rpc_name: &str, request: Req, rpc: F) -> GenResult<Resp>
+    where
+        Req: Send + 'static,
+        Resp: Send + 'static,
+        F: Fn(&BrokerStub, grpc::RequestOptions, Req) -> grpc::SingleResponse<Resp>
+            + Send
+            + 'static,
+    {
+        call_with_deadline(
+            self.broker_stub.clone(),
+            request,
+            rpc,
+            self.config.timeout(),
+        )
+        .map_err(|e| BrokerError::new(format!("{} failed: {}", rpc_name, e)) as GenError)
+    }
+}
+
+impl BrokerClient for GrpcBrokerClient {
+    fn positions(&self) -> GenResult<Positions> {
+        Ok(from_proto_account(&self.account()?))
+    }
+
+    fn cash(&self) -> GenResult<f64> {
+        Ok(self.account()?.cash)
+    }
+
+    fn submit_order(&mut self, order: Order) -> GenResult<OrderId> {
+        let mut request = SubmitOrderRequest::new();
+        request.account_id = self.config.account_id.clone();
+        request.order = SingularPtrField::some(to_proto_order(&order));
+        let response = self.call("SubmitOrder", request, |stub, o, req| {
+            stub.submit_order(o, req)
+        })?;
+        Ok(response.order_id)
+    }
+
+    fn order_status(&self, order_id: &str) -> GenResult<OrderStatus> {
+        let mut request = OrderStatusRequest::new();
+        request.account_id = self.config.account_id.clone();
+        request.order_id = order_id.to_string();
+        let response = self.call("GetOrderStatus", request, |stub, o, req| {
+            stub.get_order_status(o, req)
+        })?;
+        Ok(from_proto_status(&response))
+    }
+
+    fn fills(&self) -> GenResult<Vec<Fill>> {
+        let mut request = FillsRequest::new();
+        request.account_id = self.config.account_id.clone();
+        let response = self.call("ListFills", request, |stub, o, req| stub.list_fills(o, req))?;
+        Ok(response.fills.iter().map(from_proto_fill).collect())
+    }
+}
+
+pub(crate) fn to_proto_side(side: Side) -> broker_service::Side {
+    match side {
+        Side::Buy => broker_service::Side::BUY,
+        Side::Sell => broker_service::Side::SELL,
+    }
+}
+
+pub(crate) fn from_proto_side(side: broker_service::Side) -> Side {
+    match side {
+        broker_service::Side::BUY => Side::Buy,
+        broker_service::Side::SELL => Side::Sell,
+    }
+}
+
+pub(crate) fn to_proto_order(order: &Order) -> broker_service::Order {
+    let mut proto_order = broker_service::Order::new();
+    proto_order.symbol = order.symbol.clone();
+    proto_order.side = to_proto_side(order.side);
+    proto_order.quantity = order.quantity;
+    proto_order.price = order.price;
+    proto_order
+}
+
+pub(crate) fn from_proto_order(order: &broker_service::Order) -> Order {
+    Order::new(
+        order.symbol.clone(),
+        from_proto_side(order.side),
+        order.quantity,
+        order.price,
+    )
+}
+
+pub(crate) fn to_proto_fill(fill: &Fill) -> broker_service::Fill {
+    let mut proto_fill = broker_service::Fill::new();
+    proto_fill.order_id = fill.order_id.clone();
+    proto_fill.symbol = fill.symbol.clone();
+    proto_fill.side = to_proto_side(fill.side);
+    proto_fill.quantity = fill.quantity;
+    proto_fill.price = fill.price;
+    proto_fill.timestamp = SingularPtrField::some(to_proto(fill.timestamp));
+    proto_fill
+}
+
+pub(crate) fn from_proto_fill(fill: &broker_service::Fill) -> Fill {
+    Fill {
+        order_id: fill.order_id.clone(),
+        symbol: fill.symbol.clone(),
+        side: from_proto_side(fill.side),
+        quantity: fill.quantity,
+        price: fill.price,
+        timestamp: from_proto(fill.get_timestamp().clone()),
+    }
+}
+
+pub(crate) fn to_proto_status(order_id: &str, status: &OrderStatus) -> OrderStatusResponse {
+    let mut response = OrderStatusResponse::new();
+    response.order_id = order_id.to_string();
+    match status {
+        OrderStatus::Pending => response.state = OrderState::PENDING,
+        OrderStatus::Filled => response.state = OrderState::FILLED,
+        OrderStatus::Rejected { reason } => {
+            response.state = OrderState::REJECTED;
+            response.reason = reason.clone();
+        }
+    }
+    response
+}
+
+pub(crate) fn from_proto_status(response: &OrderStatusResponse) -> OrderStatus {
+    match response.state {
+        OrderState::PENDING => OrderStatus::Pending,
+        OrderState::FILLED => OrderStatus::Filled,
+        OrderState::REJECTED => OrderStatus::Rejected {
+            reason: response.reason.clone(),
+        },
+    }
+}
+
+pub(crate) fn to_proto_account(account_id: &str, positions: &Positions) -> Account {
+    let mut account = Account::new();
+    account.account_id = account_id.to_string();
+    account.cash = positions.cash;
+    account.positions = positions
+        .quantities
+        .iter()
+        .map(|(symbol, quantity)| {
+            let mut position = broker_service::Position::new();
+            position.symbol = symbol.clone();
+            position.quantity = *quantity;
+            position
+        })
+        .collect();
+    account
+}
+
+pub(crate) fn from_proto_account(account: &Account) -> Positions {
+    Positions::new(
+        account
+            .positions
+            .iter()
+            .map(|position| (position.symbol.clone(), position.quantity))
+            .collect(),
+        account.cash,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::broker::{BrokerClient, OrderStatus};
+    use crate::broker_client::{
+        from_proto_status, to_proto_status, BrokerClientConfig, GrpcBrokerClient,
+    };
+    use crate::broker_server::serve;
+    use crate::errors::GenResult;
+    use crate::mock_client::MockDataClient;
+    use crate::orders::{Order, Side};
+    use crate::paper_broker::PaperBrokerConfig;
+    use crate::query_server::LOCAL_HOST;
+
+    #[test]
+    fn status_round_trip() {
+        let statuses = vec![
+            OrderStatus::Pending,
+            OrderStatus::Filled,
+            OrderStatus::Rejected {
+                reason: String::from("insufficient cash"),
+            },
+        ];
+        for status in statuses {
+            assert_eq!(from_proto_status(&to_proto_status("1", &status)), status);
+        }
+    }
+
+    #[test]
+    fn trade_through_local_server() -> GenResult<()> {
+        let server = serve(
+            Arc::new(MockDataClient::new()),
+            PaperBrokerConfig::default(),
+            0,
+        )?;
+        let config = BrokerClientConfig {
+            host: LOCAL_HOST.to_string(),
+            port: server.local_addr().port()?,
+            ..BrokerClientConfig::default()
+        };
+        let mut client = GrpcBrokerClient::new(config)?;
+        let initial_cash = client.cash()?;
+        assert_eq!(initial_cash, PaperBrokerConfig::default().initial_cash);
+        let order_id = client.submit_order(Order::new(String::from("C"), Side::Buy, 10., 0.))?;
+        assert_eq!(client.order_status(&order_id)?, OrderStatus::Filled);
+        assert_eq!(client.positions()?.quantity("C"), 10.);
+        assert!(client.cash()? < initial_cash);
+        let fills = client.fills()?;
+        assert_eq!(fills.len(), 1);
+        assert_eq!(fills[0].order_id, order_id);
+        // selling more than is held is rejected, not an error
+        let order_id = client.submit_order(Order::new(String::from("C"), Side::Sell, 20., 0.))?;
+        assert!(matches!(
+            client.order_status(&order_id)?,
+            OrderStatus::Rejected { .. }
+        ));
+        assert!(client.order_status("unknown").is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,195 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use chrono::Utc;
+use grpc::{ServerHandlerContext, ServerRequestSingle, ServerResponseUnarySink};
+
+use crate::bot_server::reply;
+use crate::broker_client::{from_proto_order, to_proto_account, to_proto_fill, to_proto_status};
+use crate::broker_service::{
+    Account, AccountRequest, Fills, FillsRequest, OrderStatusRequest, OrderStatusResponse,
+    SubmitOrderRequest, SubmitOrderResponse,
+};
+use crate::broker_service_grpc::{Broker, BrokerServer};
+use crate::data::DataClient;
+use crate::errors::{CliArgError, GenError, GenResult, OrderError, QueryError};
+use crate::orders::latest_prices;
+use crate::paper_broker::{PaperAccount, PaperBrokerConfig};
+use crate::query_server::LOCAL_HOST;
+
+/// Stand-in for the Luckless broker service, keeping a `PaperAccount` ledger per account id.
+///
+/// Accounts are opened with `config.initial_cash` on first use.  Orders fill immediately at the
+/// latest `DataClient` price, plus slippage.
+#[derive(Clone)]
+pub struct BrokerService {
+    data_client: Arc<dyn DataClient + Send + Sync>,
+    config: PaperBrokerConfig,
+    accounts: Arc<Mutex<BTreeMap<String, PaperAccount>>>,
+}
+
+impl BrokerService {
+    pub fn new(data_client: Arc<dyn DataClient + Send + Sync>, config: PaperBrokerConfig) -> Self {
+        BrokerService {
+            data_client,
+            config,
+            accounts: Arc::new(Mutex::new(BTreeMap::new())),
+        }
+    }
+
+    fn with_account<T, F: FnOnce(&mut PaperAccount) -> T>(&self, account_id: &str, f: F) -> T {
+        let mut accounts = self.accounts.lock().expect("accounts");
+        let account = accounts
+            .entry(account_id.to_string())
+            .or_insert_with(|| PaperAccount::new(self.config.initial_cash));
+        f(account)
+    }
+
+    pub fn get_account(&self, request: &AccountRequest) -> Account {
+        self.with_account(&request.account_id, |account| {
+            to_proto_account(&request.account_id, &account.positions)
+        })
+    }
+
+    pub fn submit_order(&self, request: &SubmitOrderRequest) -> GenResult<SubmitOrderResponse> {
+        let order = match request.order.as_ref() {
+            Some(order) => from_proto_order(order),
+            None => return Err(CliArgError::new(String::from("order is required"))),
+        };
+        let timestamp = Utc::now();
+        let symbols: BTreeSet<String> = vec![order.symbol.clone()].into_iter().collect();
+        let price = latest_prices(&symbols, &*self.data_client, &timestamp)?[&order.symbol];
+        let price = self.config.fill_price(order.side, price);
+        let mut response = SubmitOrderResponse::new();
+        response.order_id = self.with_account(&request.account_id, |account| {
+            account.execute(order, price, timestamp)
+        });
+        Ok(response)
+    }
+
+    pub fn get_order_status(&self, request: &OrderStatusRequest) -> GenResult<OrderStatusResponse> {
+        self.with_account(&request.account_id, |account| {
+            match account.orders.get(&request.order_id) {
+                Some(order_record) => Ok(to_proto_status(&request.order_id, &order_record.status)),
+                None => {
+                    Err(OrderError::new(format!("unknown order {}", request.order_id)) as GenError)
+                }
+            }
+        })
+    }
+
+    pub fn list_fills(&self, request: &FillsRequest) -> Fills {
+        self.with_account(&request.account_id, |account| {
+            let mut fills = Fills::new();
+            fills.fills = account.fills.iter().map(to_proto_fill).collect();
+            fills
+        })
+    }
+}
+
+impl Broker for BrokerService {
+    fn get_account(
+        &self,
+        _o: ServerHandlerContext,
+        req: ServerRequestSingle<AccountRequest>,
+        resp: ServerResponseUnarySink<Account>,
+    ) -> grpc::Result<()> {
+        let service = self.clone();
+        thread::spawn(move || reply(resp, Ok(service.get_account(&req.message))));
+        Ok(())
+    }
+
+    fn submit_order(
+        &self,
+        _o: ServerHandlerContext,
+        req: ServerRequestSingle<SubmitOrderRequest>,
+        resp: ServerResponseUnarySink<SubmitOrderResponse>,
+    ) -> grpc::Result<()> {
+        let service = self.clone();
+        thread::spawn(move || reply(resp, service.submit_order(&req.message)));
+        Ok(())
+    }
+
+    fn get_order_status(
+        &self,
+        _o: ServerHandlerContext,
+        req: ServerRequestSingle<OrderStatusRequest>,
+        resp: ServerResponseUnarySink<OrderStatusResponse>,
+    ) -> grpc::Result<()> {
+        let service = self.clone();
+        thread::spawn(move || reply(resp, service.get_order_status(&req.message)));
+        Ok(())
+    }
+
+    fn list_fills(
+        &self,
+        _o: ServerHandlerContext,
+        req: ServerRequestSingle<FillsRequest>,
+        resp: ServerResponseUnarySink<Fills>,
+    ) -> grpc::Result<()> {
+        let service = self.clone();
+        thread::spawn(move || reply(resp, Ok(service.list_fills(&req.message))));
+        Ok(())
+    }
+}
+
+/// Host the `Broker` service on `port` (0 for an ephemeral port), the returned server stops when
+/// dropped.
+pub fn serve(
+    data_client: Arc<dyn DataClient + Send + Sync>,
+    config: PaperBrokerConfig,
+    port: u16,
+) -> GenResult<grpc::Server> {
+    let mut server_builder = grpc::ServerBuilder::new_plain();
+    server_builder
+        .http
+        .set_addr((LOCAL_HOST, port))
+        .map_err(|e| QueryError::new(format!("invalid port {}: {}", port, e)))?;
+    server_builder.add_service(BrokerServer::new_service_def(BrokerService::new(
+        data_client,
+        config,
+    )));
+    let server = server_builder
+        .build()
+        .map_err(|e| QueryError::new(format!("unable to serve on port {}: {}", port, e)))?;
+    Ok(server)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use protobuf::SingularPtrField;
+
+    use crate::broker_client::to_proto_order;
+    use crate::broker_server::BrokerService;
+    use crate::broker_service::{AccountRequest, SubmitOrderRequest};
+    use crate::errors::GenResult;
+    use crate::mock_client::MockDataClient;
+    use crate::orders::{Order, Side};
+    use crate::paper_broker::PaperBrokerConfig;
+
+    #[test]
+    fn accounts_are_separate() -> GenResult<()> {
+        let service = BrokerService::new(
+            Arc::new(MockDataClient::new()),
+            PaperBrokerConfig::default(),
+        );
+        let mut request = SubmitOrderRequest::new();
+        request.account_id = String::from("a");
+        request.order = SingularPtrField::some(to_proto_order(&Order::new(
+            String::from("A"),
+            Side::Buy,
+            1.,
+            0.,
+        )));
+        service.submit_order(&request)?;
+        let mut request = AccountRequest::new();
+        request.account_id = String::from("a");
+        assert_eq!(service.get_account(&request).positions.len(), 1);
+        request.account_id = String::from("b");
+        assert!(service.get_account(&request).positions.is_empty());
+        Ok(())
+    }
+}
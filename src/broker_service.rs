@@ -0,0 +1,2523 @@
+// This file is generated by rust-protobuf 2.18.2. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `broker_service.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_18_2;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct AccountRequest {
+    // message fields
+    pub account_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a AccountRequest {
+    fn default() -> &'a AccountRequest {
+        <AccountRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AccountRequest {
+    pub fn new() -> AccountRequest {
+        ::std::default::Default::default()
+    }
+
+    // string account_id = 1;
+
+
+    pub fn get_account_id(&self) -> &str {
+        &self.account_id
+    }
+    pub fn clear_account_id(&mut self) {
+        self.account_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_id(&mut self, v: ::std::string::String) {
+        self.account_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_account_id(&mut self) -> &mut ::std::string::String {
+        &mut self.account_id
+    }
+
+    // Take field
+    pub fn take_account_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.account_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for AccountRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.account_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.account_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.account_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.account_id.is_empty() {
+            os.write_string(1, &self.account_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> AccountRequest {
+        AccountRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "account_id",
+                |m: &AccountRequest| { &m.account_id },
+                |m: &mut AccountRequest| { &mut m.account_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<AccountRequest>(
+                "AccountRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static AccountRequest {
+        static instance: ::protobuf::rt::LazyV2<AccountRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(AccountRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for AccountRequest {
+    fn clear(&mut self) {
+        self.account_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for AccountRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AccountRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Position {
+    // message fields
+    pub symbol: ::std::string::String,
+    pub quantity: f64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Position {
+    fn default() -> &'a Position {
+        <Position as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Position {
+    pub fn new() -> Position {
+        ::std::default::Default::default()
+    }
+
+    // string symbol = 1;
+
+
+    pub fn get_symbol(&self) -> &str {
+        &self.symbol
+    }
+    pub fn clear_symbol(&mut self) {
+        self.symbol.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_symbol(&mut self, v: ::std::string::String) {
+        self.symbol = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_symbol(&mut self) -> &mut ::std::string::String {
+        &mut self.symbol
+    }
+
+    // Take field
+    pub fn take_symbol(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.symbol, ::std::string::String::new())
+    }
+
+    // double quantity = 2;
+
+
+    pub fn get_quantity(&self) -> f64 {
+        self.quantity
+    }
+    pub fn clear_quantity(&mut self) {
+        self.quantity = 0.;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_quantity(&mut self, v: f64) {
+        self.quantity = v;
+    }
+}
+
+impl ::protobuf::Message for Position {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.symbol)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_double()?;
+                    self.quantity = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.symbol.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.symbol);
+        }
+        if self.quantity != 0. {
+            my_size += 9;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.symbol.is_empty() {
+            os.write_string(1, &self.symbol)?;
+        }
+        if self.quantity != 0. {
+            os.write_double(2, self.quantity)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Position {
+        Position::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "symbol",
+                |m: &Position| { &m.symbol },
+                |m: &mut Position| { &mut m.symbol },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeDouble>(
+                "quantity",
+                |m: &Position| { &m.quantity },
+                |m: &mut Position| { &mut m.quantity },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Position>(
+                "Position",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Position {
+        static instance: ::protobuf::rt::LazyV2<Position> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Position::new)
+    }
+}
+
+impl ::protobuf::Clear for Position {
+    fn clear(&mut self) {
+        self.symbol.clear();
+        self.quantity = 0.;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Position {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Position {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Account {
+    // message fields
+    pub account_id: ::std::string::String,
+    pub cash: f64,
+    pub positions: ::protobuf::RepeatedField<Position>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Account {
+    fn default() -> &'a Account {
+        <Account as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Account {
+    pub fn new() -> Account {
+        ::std::default::Default::default()
+    }
+
+    // string account_id = 1;
+
+
+    pub fn get_account_id(&self) -> &str {
+        &self.account_id
+    }
+    pub fn clear_account_id(&mut self) {
+        self.account_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_id(&mut self, v: ::std::string::String) {
+        self.account_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_account_id(&mut self) -> &mut ::std::string::String {
+        &mut self.account_id
+    }
+
+    // Take field
+    pub fn take_account_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.account_id, ::std::string::String::new())
+    }
+
+    // double cash = 2;
+
+
+    pub fn get_cash(&self) -> f64 {
+        self.cash
+    }
+    pub fn clear_cash(&mut self) {
+        self.cash = 0.;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cash(&mut self, v: f64) {
+        self.cash = v;
+    }
+
+    // repeated .broker.Position positions = 3;
+
+
+    pub fn get_positions(&self) -> &[Position] {
+        &self.positions
+    }
+    pub fn clear_positions(&mut self) {
+        self.positions.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_positions(&mut self, v: ::protobuf::RepeatedField<Position>) {
+        self.positions = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_positions(&mut self) -> &mut ::protobuf::RepeatedField<Position> {
+        &mut self.positions
+    }
+
+    // Take field
+    pub fn take_positions(&mut self) -> ::protobuf::RepeatedField<Position> {
+        ::std::mem::replace(&mut self.positions, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for Account {
+    fn is_initialized(&self) -> bool {
+        for v in &self.positions {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.account_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_double()?;
+                    self.cash = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.positions)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.account_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.account_id);
+        }
+        if self.cash != 0. {
+            my_size += 9;
+        }
+        for value in &self.positions {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.account_id.is_empty() {
+            os.write_string(1, &self.account_id)?;
+        }
+        if self.cash != 0. {
+            os.write_double(2, self.cash)?;
+        }
+        for v in &self.positions {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Account {
+        Account::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "account_id",
+                |m: &Account| { &m.account_id },
+                |m: &mut Account| { &mut m.account_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeDouble>(
+                "cash",
+                |m: &Account| { &m.cash },
+                |m: &mut Account| { &mut m.cash },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Position>>(
+                "positions",
+                |m: &Account| { &m.positions },
+                |m: &mut Account| { &mut m.positions },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Account>(
+                "Account",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Account {
+        static instance: ::protobuf::rt::LazyV2<Account> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Account::new)
+    }
+}
+
+impl ::protobuf::Clear for Account {
+    fn clear(&mut self) {
+        self.account_id.clear();
+        self.cash = 0.;
+        self.positions.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Account {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Account {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Order {
+    // message fields
+    pub symbol: ::std::string::String,
+    pub side: Side,
+    pub quantity: f64,
+    pub price: f64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Order {
+    fn default() -> &'a Order {
+        <Order as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Order {
+    pub fn new() -> Order {
+        ::std::default::Default::default()
+    }
+
+    // string symbol = 1;
+
+
+    pub fn get_symbol(&self) -> &str {
+        &self.symbol
+    }
+    pub fn clear_symbol(&mut self) {
+        self.symbol.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_symbol(&mut self, v: ::std::string::String) {
+        self.symbol = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_symbol(&mut self) -> &mut ::std::string::String {
+        &mut self.symbol
+    }
+
+    // Take field
+    pub fn take_symbol(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.symbol, ::std::string::String::new())
+    }
+
+    // .broker.Side side = 2;
+
+
+    pub fn get_side(&self) -> Side {
+        self.side
+    }
+    pub fn clear_side(&mut self) {
+        self.side = Side::BUY;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_side(&mut self, v: Side) {
+        self.side = v;
+    }
+
+    // double quantity = 3;
+
+
+    pub fn get_quantity(&self) -> f64 {
+        self.quantity
+    }
+    pub fn clear_quantity(&mut self) {
+        self.quantity = 0.;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_quantity(&mut self, v: f64) {
+        self.quantity = v;
+    }
+
+    // double price = 4;
+
+
+    pub fn get_price(&self) -> f64 {
+        self.price
+    }
+    pub fn clear_price(&mut self) {
+        self.price = 0.;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_price(&mut self, v: f64) {
+        self.price = v;
+    }
+}
+
+impl ::protobuf::Message for Order {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.symbol)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.side, 2, &mut self.unknown_fields)?
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_double()?;
+                    self.quantity = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_double()?;
+                    self.price = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.symbol.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.symbol);
+        }
+        if self.side != Side::BUY {
+            my_size += ::protobuf::rt::enum_size(2, self.side);
+        }
+        if self.quantity != 0. {
+            my_size += 9;
+        }
+        if self.price != 0. {
+            my_size += 9;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.symbol.is_empty() {
+            os.write_string(1, &self.symbol)?;
+        }
+        if self.side != Side::BUY {
+            os.write_enum(2, ::protobuf::ProtobufEnum::value(&self.side))?;
+        }
+        if self.quantity != 0. {
+            os.write_double(3, self.quantity)?;
+        }
+        if self.price != 0. {
+            os.write_double(4, self.price)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Order {
+        Order::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "symbol",
+                |m: &Order| { &m.symbol },
+                |m: &mut Order| { &mut m.symbol },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<Side>>(
+                "side",
+                |m: &Order| { &m.side },
+                |m: &mut Order| { &mut m.side },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeDouble>(
+                "quantity",
+                |m: &Order| { &m.quantity },
+                |m: &mut Order| { &mut m.quantity },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeDouble>(
+                "price",
+                |m: &Order| { &m.price },
+                |m: &mut Order| { &mut m.price },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Order>(
+                "Order",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Order {
+        static instance: ::protobuf::rt::LazyV2<Order> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Order::new)
+    }
+}
+
+impl ::protobuf::Clear for Order {
+    fn clear(&mut self) {
+        self.symbol.clear();
+        self.side = Side::BUY;
+        self.quantity = 0.;
+        self.price = 0.;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Order {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Order {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SubmitOrderRequest {
+    // message fields
+    pub account_id: ::std::string::String,
+    pub order: ::protobuf::SingularPtrField<Order>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SubmitOrderRequest {
+    fn default() -> &'a SubmitOrderRequest {
+        <SubmitOrderRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SubmitOrderRequest {
+    pub fn new() -> SubmitOrderRequest {
+        ::std::default::Default::default()
+    }
+
+    // string account_id = 1;
+
+
+    pub fn get_account_id(&self) -> &str {
+        &self.account_id
+    }
+    pub fn clear_account_id(&mut self) {
+        self.account_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_id(&mut self, v: ::std::string::String) {
+        self.account_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_account_id(&mut self) -> &mut ::std::string::String {
+        &mut self.account_id
+    }
+
+    // Take field
+    pub fn take_account_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.account_id, ::std::string::String::new())
+    }
+
+    // .broker.Order order = 2;
+
+
+    pub fn get_order(&self) -> &Order {
+        self.order.as_ref().unwrap_or_else(|| <Order as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_order(&mut self) {
+        self.order.clear();
+    }
+
+    pub fn has_order(&self) -> bool {
+        self.order.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_order(&mut self, v: Order) {
+        self.order = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_order(&mut self) -> &mut Order {
+        if self.order.is_none() {
+            self.order.set_default();
+        }
+        self.order.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_order(&mut self) -> Order {
+        self.order.take().unwrap_or_else(|| Order::new())
+    }
+}
+
+impl ::protobuf::Message for SubmitOrderRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.order {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.account_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.order)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.account_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.account_id);
+        }
+        if let Some(ref v) = self.order.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.account_id.is_empty() {
+            os.write_string(1, &self.account_id)?;
+        }
+        if let Some(ref v) = self.order.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SubmitOrderRequest {
+        SubmitOrderRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "account_id",
+                |m: &SubmitOrderRequest| { &m.account_id },
+                |m: &mut SubmitOrderRequest| { &mut m.account_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Order>>(
+                "order",
+                |m: &SubmitOrderRequest| { &m.order },
+                |m: &mut SubmitOrderRequest| { &mut m.order },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SubmitOrderRequest>(
+                "SubmitOrderRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SubmitOrderRequest {
+        static instance: ::protobuf::rt::LazyV2<SubmitOrderRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SubmitOrderRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for SubmitOrderRequest {
+    fn clear(&mut self) {
+        self.account_id.clear();
+        self.order.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SubmitOrderRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SubmitOrderRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SubmitOrderResponse {
+    // message fields
+    pub order_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SubmitOrderResponse {
+    fn default() -> &'a SubmitOrderResponse {
+        <SubmitOrderResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SubmitOrderResponse {
+    pub fn new() -> SubmitOrderResponse {
+        ::std::default::Default::default()
+    }
+
+    // string order_id = 1;
+
+
+    pub fn get_order_id(&self) -> &str {
+        &self.order_id
+    }
+    pub fn clear_order_id(&mut self) {
+        self.order_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_order_id(&mut self, v: ::std::string::String) {
+        self.order_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_order_id(&mut self) -> &mut ::std::string::String {
+        &mut self.order_id
+    }
+
+    // Take field
+    pub fn take_order_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.order_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for SubmitOrderResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.order_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.order_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.order_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.order_id.is_empty() {
+            os.write_string(1, &self.order_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SubmitOrderResponse {
+        SubmitOrderResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "order_id",
+                |m: &SubmitOrderResponse| { &m.order_id },
+                |m: &mut SubmitOrderResponse| { &mut m.order_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SubmitOrderResponse>(
+                "SubmitOrderResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SubmitOrderResponse {
+        static instance: ::protobuf::rt::LazyV2<SubmitOrderResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SubmitOrderResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for SubmitOrderResponse {
+    fn clear(&mut self) {
+        self.order_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SubmitOrderResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SubmitOrderResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct OrderStatusRequest {
+    // message fields
+    pub account_id: ::std::string::String,
+    pub order_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a OrderStatusRequest {
+    fn default() -> &'a OrderStatusRequest {
+        <OrderStatusRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl OrderStatusRequest {
+    pub fn new() -> OrderStatusRequest {
+        ::std::default::Default::default()
+    }
+
+    // string account_id = 1;
+
+
+    pub fn get_account_id(&self) -> &str {
+        &self.account_id
+    }
+    pub fn clear_account_id(&mut self) {
+        self.account_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_id(&mut self, v: ::std::string::String) {
+        self.account_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_account_id(&mut self) -> &mut ::std::string::String {
+        &mut self.account_id
+    }
+
+    // Take field
+    pub fn take_account_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.account_id, ::std::string::String::new())
+    }
+
+    // string order_id = 2;
+
+
+    pub fn get_order_id(&self) -> &str {
+        &self.order_id
+    }
+    pub fn clear_order_id(&mut self) {
+        self.order_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_order_id(&mut self, v: ::std::string::String) {
+        self.order_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_order_id(&mut self) -> &mut ::std::string::String {
+        &mut self.order_id
+    }
+
+    // Take field
+    pub fn take_order_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.order_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for OrderStatusRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.account_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.order_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.account_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.account_id);
+        }
+        if !self.order_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.order_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.account_id.is_empty() {
+            os.write_string(1, &self.account_id)?;
+        }
+        if !self.order_id.is_empty() {
+            os.write_string(2, &self.order_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> OrderStatusRequest {
+        OrderStatusRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "account_id",
+                |m: &OrderStatusRequest| { &m.account_id },
+                |m: &mut OrderStatusRequest| { &mut m.account_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "order_id",
+                |m: &OrderStatusRequest| { &m.order_id },
+                |m: &mut OrderStatusRequest| { &mut m.order_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<OrderStatusRequest>(
+                "OrderStatusRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static OrderStatusRequest {
+        static instance: ::protobuf::rt::LazyV2<OrderStatusRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(OrderStatusRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for OrderStatusRequest {
+    fn clear(&mut self) {
+        self.account_id.clear();
+        self.order_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for OrderStatusRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for OrderStatusRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct OrderStatusResponse {
+    // message fields
+    pub order_id: ::std::string::String,
+    pub state: OrderState,
+    pub reason: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a OrderStatusResponse {
+    fn default() -> &'a OrderStatusResponse {
+        <OrderStatusResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl OrderStatusResponse {
+    pub fn new() -> OrderStatusResponse {
+        ::std::default::Default::default()
+    }
+
+    // string order_id = 1;
+
+
+    pub fn get_order_id(&self) -> &str {
+        &self.order_id
+    }
+    pub fn clear_order_id(&mut self) {
+        self.order_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_order_id(&mut self, v: ::std::string::String) {
+        self.order_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_order_id(&mut self) -> &mut ::std::string::String {
+        &mut self.order_id
+    }
+
+    // Take field
+    pub fn take_order_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.order_id, ::std::string::String::new())
+    }
+
+    // .broker.OrderState state = 2;
+
+
+    pub fn get_state(&self) -> OrderState {
+        self.state
+    }
+    pub fn clear_state(&mut self) {
+        self.state = OrderState::PENDING;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_state(&mut self, v: OrderState) {
+        self.state = v;
+    }
+
+    // string reason = 3;
+
+
+    pub fn get_reason(&self) -> &str {
+        &self.reason
+    }
+    pub fn clear_reason(&mut self) {
+        self.reason.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_reason(&mut self, v: ::std::string::String) {
+        self.reason = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_reason(&mut self) -> &mut ::std::string::String {
+        &mut self.reason
+    }
+
+    // Take field
+    pub fn take_reason(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.reason, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for OrderStatusResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.order_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.state, 2, &mut self.unknown_fields)?
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.reason)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.order_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.order_id);
+        }
+        if self.state != OrderState::PENDING {
+            my_size += ::protobuf::rt::enum_size(2, self.state);
+        }
+        if !self.reason.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.reason);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.order_id.is_empty() {
+            os.write_string(1, &self.order_id)?;
+        }
+        if self.state != OrderState::PENDING {
+            os.write_enum(2, ::protobuf::ProtobufEnum::value(&self.state))?;
+        }
+        if !self.reason.is_empty() {
+            os.write_string(3, &self.reason)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> OrderStatusResponse {
+        OrderStatusResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "order_id",
+                |m: &OrderStatusResponse| { &m.order_id },
+                |m: &mut OrderStatusResponse| { &mut m.order_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<OrderState>>(
+                "state",
+                |m: &OrderStatusResponse| { &m.state },
+                |m: &mut OrderStatusResponse| { &mut m.state },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "reason",
+                |m: &OrderStatusResponse| { &m.reason },
+                |m: &mut OrderStatusResponse| { &mut m.reason },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<OrderStatusResponse>(
+                "OrderStatusResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static OrderStatusResponse {
+        static instance: ::protobuf::rt::LazyV2<OrderStatusResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(OrderStatusResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for OrderStatusResponse {
+    fn clear(&mut self) {
+        self.order_id.clear();
+        self.state = OrderState::PENDING;
+        self.reason.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for OrderStatusResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for OrderStatusResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct FillsRequest {
+    // message fields
+    pub account_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a FillsRequest {
+    fn default() -> &'a FillsRequest {
+        <FillsRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl FillsRequest {
+    pub fn new() -> FillsRequest {
+        ::std::default::Default::default()
+    }
+
+    // string account_id = 1;
+
+
+    pub fn get_account_id(&self) -> &str {
+        &self.account_id
+    }
+    pub fn clear_account_id(&mut self) {
+        self.account_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_id(&mut self, v: ::std::string::String) {
+        self.account_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_account_id(&mut self) -> &mut ::std::string::String {
+        &mut self.account_id
+    }
+
+    // Take field
+    pub fn take_account_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.account_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for FillsRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.account_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.account_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.account_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.account_id.is_empty() {
+            os.write_string(1, &self.account_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> FillsRequest {
+        FillsRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "account_id",
+                |m: &FillsRequest| { &m.account_id },
+                |m: &mut FillsRequest| { &mut m.account_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<FillsRequest>(
+                "FillsRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static FillsRequest {
+        static instance: ::protobuf::rt::LazyV2<FillsRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(FillsRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for FillsRequest {
+    fn clear(&mut self) {
+        self.account_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for FillsRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FillsRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Fill {
+    // message fields
+    pub order_id: ::std::string::String,
+    pub symbol: ::std::string::String,
+    pub side: Side,
+    pub quantity: f64,
+    pub price: f64,
+    pub timestamp: ::protobuf::SingularPtrField<::protobuf::well_known_types::Timestamp>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Fill {
+    fn default() -> &'a Fill {
+        <Fill as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Fill {
+    pub fn new() -> Fill {
+        ::std::default::Default::default()
+    }
+
+    // string order_id = 1;
+
+
+    pub fn get_order_id(&self) -> &str {
+        &self.order_id
+    }
+    pub fn clear_order_id(&mut self) {
+        self.order_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_order_id(&mut self, v: ::std::string::String) {
+        self.order_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_order_id(&mut self) -> &mut ::std::string::String {
+        &mut self.order_id
+    }
+
+    // Take field
+    pub fn take_order_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.order_id, ::std::string::String::new())
+    }
+
+    // string symbol = 2;
+
+
+    pub fn get_symbol(&self) -> &str {
+        &self.symbol
+    }
+    pub fn clear_symbol(&mut self) {
+        self.symbol.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_symbol(&mut self, v: ::std::string::String) {
+        self.symbol = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_symbol(&mut self) -> &mut ::std::string::String {
+        &mut self.symbol
+    }
+
+    // Take field
+    pub fn take_symbol(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.symbol, ::std::string::String::new())
+    }
+
+    // .broker.Side side = 3;
+
+
+    pub fn get_side(&self) -> Side {
+        self.side
+    }
+    pub fn clear_side(&mut self) {
+        self.side = Side::BUY;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_side(&mut self, v: Side) {
+        self.side = v;
+    }
+
+    // double quantity = 4;
+
+
+    pub fn get_quantity(&self) -> f64 {
+        self.quantity
+    }
+    pub fn clear_quantity(&mut self) {
+        self.quantity = 0.;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_quantity(&mut self, v: f64) {
+        self.quantity = v;
+    }
+
+    // double price = 5;
+
+
+    pub fn get_price(&self) -> f64 {
+        self.price
+    }
+    pub fn clear_price(&mut self) {
+        self.price = 0.;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_price(&mut self, v: f64) {
+        self.price = v;
+    }
+
+    // .google.protobuf.Timestamp timestamp = 6;
+
+
+    pub fn get_timestamp(&self) -> &::protobuf::well_known_types::Timestamp {
+        self.timestamp.as_ref().unwrap_or_else(|| <::protobuf::well_known_types::Timestamp as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_timestamp(&mut self) {
+        self.timestamp.clear();
+    }
+
+    pub fn has_timestamp(&self) -> bool {
+        self.timestamp.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_timestamp(&mut self, v: ::protobuf::well_known_types::Timestamp) {
+        self.timestamp = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_timestamp(&mut self) -> &mut ::protobuf::well_known_types::Timestamp {
+        if self.timestamp.is_none() {
+            self.timestamp.set_default();
+        }
+        self.timestamp.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_timestamp(&mut self) -> ::protobuf::well_known_types::Timestamp {
+        self.timestamp.take().unwrap_or_else(|| ::protobuf::well_known_types::Timestamp::new())
+    }
+}
+
+impl ::protobuf::Message for Fill {
+    fn is_initialized(&self) -> bool {
+        for v in &self.timestamp {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.order_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.symbol)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.side, 3, &mut self.unknown_fields)?
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_double()?;
+                    self.quantity = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_double()?;
+                    self.price = tmp;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.timestamp)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.order_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.order_id);
+        }
+        if !self.symbol.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.symbol);
+        }
+        if self.side != Side::BUY {
+            my_size += ::protobuf::rt::enum_size(3, self.side);
+        }
+        if self.quantity != 0. {
+            my_size += 9;
+        }
+        if self.price != 0. {
+            my_size += 9;
+        }
+        if let Some(ref v) = self.timestamp.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.order_id.is_empty() {
+            os.write_string(1, &self.order_id)?;
+        }
+        if !self.symbol.is_empty() {
+            os.write_string(2, &self.symbol)?;
+        }
+        if self.side != Side::BUY {
+            os.write_enum(3, ::protobuf::ProtobufEnum::value(&self.side))?;
+        }
+        if self.quantity != 0. {
+            os.write_double(4, self.quantity)?;
+        }
+        if self.price != 0. {
+            os.write_double(5, self.price)?;
+        }
+        if let Some(ref v) = self.timestamp.as_ref() {
+            os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Fill {
+        Fill::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "order_id",
+                |m: &Fill| { &m.order_id },
+                |m: &mut Fill| { &mut m.order_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "symbol",
+                |m: &Fill| { &m.symbol },
+                |m: &mut Fill| { &mut m.symbol },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<Side>>(
+                "side",
+                |m: &Fill| { &m.side },
+                |m: &mut Fill| { &mut m.side },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeDouble>(
+                "quantity",
+                |m: &Fill| { &m.quantity },
+                |m: &mut Fill| { &mut m.quantity },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeDouble>(
+                "price",
+                |m: &Fill| { &m.price },
+                |m: &mut Fill| { &mut m.price },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<::protobuf::well_known_types::Timestamp>>(
+                "timestamp",
+                |m: &Fill| { &m.timestamp },
+                |m: &mut Fill| { &mut m.timestamp },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Fill>(
+                "Fill",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Fill {
+        static instance: ::protobuf::rt::LazyV2<Fill> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Fill::new)
+    }
+}
+
+impl ::protobuf::Clear for Fill {
+    fn clear(&mut self) {
+        self.order_id.clear();
+        self.symbol.clear();
+        self.side = Side::BUY;
+        self.quantity = 0.;
+        self.price = 0.;
+        self.timestamp.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Fill {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Fill {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Fills {
+    // message fields
+    pub fills: ::protobuf::RepeatedField<Fill>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Fills {
+    fn default() -> &'a Fills {
+        <Fills as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Fills {
+    pub fn new() -> Fills {
+        ::std::default::Default::default()
+    }
+
+    // repeated .broker.Fill fills = 1;
+
+
+    pub fn get_fills(&self) -> &[Fill] {
+        &self.fills
+    }
+    pub fn clear_fills(&mut self) {
+        self.fills.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_fills(&mut self, v: ::protobuf::RepeatedField<Fill>) {
+        self.fills = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_fills(&mut self) -> &mut ::protobuf::RepeatedField<Fill> {
+        &mut self.fills
+    }
+
+    // Take field
+    pub fn take_fills(&mut self) -> ::protobuf::RepeatedField<Fill> {
+        ::std::mem::replace(&mut self.fills, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for Fills {
+    fn is_initialized(&self) -> bool {
+        for v in &self.fills {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.fills)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.fills {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.fills {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Fills {
+        Fills::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Fill>>(
+                "fills",
+                |m: &Fills| { &m.fills },
+                |m: &mut Fills| { &mut m.fills },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Fills>(
+                "Fills",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Fills {
+        static instance: ::protobuf::rt::LazyV2<Fills> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Fills::new)
+    }
+}
+
+impl ::protobuf::Clear for Fills {
+    fn clear(&mut self) {
+        self.fills.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Fills {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Fills {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum Side {
+    BUY = 0,
+    SELL = 1,
+}
+
+impl ::protobuf::ProtobufEnum for Side {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<Side> {
+        match value {
+            0 => ::std::option::Option::Some(Side::BUY),
+            1 => ::std::option::Option::Some(Side::SELL),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [Side] = &[
+            Side::BUY,
+            Side::SELL,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<Side>("Side", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for Side {
+}
+
+impl ::std::default::Default for Side {
+    fn default() -> Self {
+        Side::BUY
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Side {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum OrderState {
+    PENDING = 0,
+    FILLED = 1,
+    REJECTED = 2,
+}
+
+impl ::protobuf::ProtobufEnum for OrderState {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<OrderState> {
+        match value {
+            0 => ::std::option::Option::Some(OrderState::PENDING),
+            1 => ::std::option::Option::Some(OrderState::FILLED),
+            2 => ::std::option::Option::Some(OrderState::REJECTED),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [OrderState] = &[
+            OrderState::PENDING,
+            OrderState::FILLED,
+            OrderState::REJECTED,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<OrderState>("OrderState", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for OrderState {
+}
+
+impl ::std::default::Default for OrderState {
+    fn default() -> Self {
+        OrderState::PENDING
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for OrderState {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x14broker_service.proto\x12\x06broker\x1a\x1fgoogle/protobuf/timestam\
+    p.proto\"/\n\x0eAccountRequest\x12\x1d\n\naccount_id\x18\x01\x20\x01(\tR\
+    \taccountId\">\n\x08Position\x12\x16\n\x06symbol\x18\x01\x20\x01(\tR\x06\
+    symbol\x12\x1a\n\x08quantity\x18\x02\x20\x01(\x01R\x08quantity\"l\n\x07A\
+    ccount\x12\x1d\n\naccount_id\x18\x01\x20\x01(\tR\taccountId\x12\x12\n\
+    \x04cash\x18\x02\x20\x01(\x01R\x04cash\x12.\n\tpositions\x18\x03\x20\x03\
+    (\x0b2\x10.broker.PositionR\tpositions\"s\n\x05Order\x12\x16\n\x06symbol\
+    \x18\x01\x20\x01(\tR\x06symbol\x12\x20\n\x04side\x18\x02\x20\x01(\x0e2\
+    \x0c.broker.SideR\x04side\x12\x1a\n\x08quantity\x18\x03\x20\x01(\x01R\
+    \x08quantity\x12\x14\n\x05price\x18\x04\x20\x01(\x01R\x05price\"X\n\x12S\
+    ubmitOrderRequest\x12\x1d\n\naccount_id\x18\x01\x20\x01(\tR\taccountId\
+    \x12#\n\x05order\x18\x02\x20\x01(\x0b2\r.broker.OrderR\x05order\"0\n\x13\
+    SubmitOrderResponse\x12\x19\n\x08order_id\x18\x01\x20\x01(\tR\x07orderId\
+    \"N\n\x12OrderStatusRequest\x12\x1d\n\naccount_id\x18\x01\x20\x01(\tR\ta\
+    ccountId\x12\x19\n\x08order_id\x18\x02\x20\x01(\tR\x07orderId\"r\n\x13Or\
+    derStatusResponse\x12\x19\n\x08order_id\x18\x01\x20\x01(\tR\x07orderId\
+    \x12(\n\x05state\x18\x02\x20\x01(\x0e2\x12.broker.OrderStateR\x05state\
+    \x12\x16\n\x06reason\x18\x03\x20\x01(\tR\x06reason\"-\n\x0cFillsRequest\
+    \x12\x1d\n\naccount_id\x18\x01\x20\x01(\tR\taccountId\"\xc7\x01\n\x04Fil\
+    l\x12\x19\n\x08order_id\x18\x01\x20\x01(\tR\x07orderId\x12\x16\n\x06symb\
+    ol\x18\x02\x20\x01(\tR\x06symbol\x12\x20\n\x04side\x18\x03\x20\x01(\x0e2\
+    \x0c.broker.SideR\x04side\x12\x1a\n\x08quantity\x18\x04\x20\x01(\x01R\
+    \x08quantity\x12\x14\n\x05price\x18\x05\x20\x01(\x01R\x05price\x128\n\tt\
+    imestamp\x18\x06\x20\x01(\x0b2\x1a.google.protobuf.TimestampR\ttimestamp\
+    \"+\n\x05Fills\x12\"\n\x05fills\x18\x01\x20\x03(\x0b2\x0c.broker.FillR\
+    \x05fills*\x19\n\x04Side\x12\x07\n\x03BUY\x10\0\x12\x08\n\x04SELL\x10\
+    \x01*3\n\nOrderState\x12\x0b\n\x07PENDING\x10\0\x12\n\n\x06FILLED\x10\
+    \x01\x12\x0c\n\x08REJECTED\x10\x022\x84\x02\n\x06Broker\x125\n\nGetAccou\
+    nt\x12\x16.broker.AccountRequest\x1a\x0f.broker.Account\x12F\n\x0bSubmit\
+    Order\x12\x1a.broker.SubmitOrderRequest\x1a\x1b.broker.SubmitOrderRespon\
+    se\x12I\n\x0eGetOrderStatus\x12\x1a.broker.OrderStatusRequest\x1a\x1b.br\
+    oker.OrderStatusResponse\x120\n\tListFills\x12\x14.broker.FillsRequest\
+    \x1a\r.broker.FillsB!Z\x1fgithub.com/luckless-finance/botb\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
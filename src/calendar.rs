@@ -0,0 +1,428 @@
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+
+use chrono::{Datelike, Duration, FixedOffset, NaiveDate, NaiveTime, TimeZone, Utc, Weekday};
+use log::warn;
+use serde::{Deserialize, Serialize};
+
+use crate::errors::{CalendarError, GenResult};
+use crate::time_series::{Frequency, Index, TimeSeries1D, TimeStamp};
+use crate::trader::MarketCalendar;
+
+pub const DEFAULT_EXCHANGE: &str = "DEFAULT";
+
+/// Trading days and regular session hours of an exchange.
+///
+/// Timestamps fall on their date in the exchange timezone, a fixed offset from UTC: daylight saving
+/// time is not modelled.  Daily time series are indexed by timestamps within the trading day, eg.
+/// at the close, whatever their time of day.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TradingCalendar {
+    pub exchange: String,
+    /// Offset of the exchange timezone east of UTC
+    pub utc_offset_minutes: i32,
+    /// Start of the regular session, exchange time
+    pub open: NaiveTime,
+    /// End of the regular session, exchange time
+    pub close: NaiveTime,
+    /// Days of the week the exchange is closed
+    pub weekend: Vec<Weekday>,
+    /// Full day closures
+    pub holidays: BTreeSet<NaiveDate>,
+    /// First year `holidays` are listed for, the year of the first holiday if `None`
+    pub first_holiday_year: Option<i32>,
+    /// Last year `holidays` are listed for, the year of the last holiday if `None`
+    pub last_holiday_year: Option<i32>,
+}
+
+impl Default for TradingCalendar {
+    /// Weekdays without holidays, regular NYSE session hours
+    fn default() -> Self {
+        TradingCalendar {
+            exchange: DEFAULT_EXCHANGE.to_string(),
+            utc_offset_minutes: -5 * 60,
+            open: NaiveTime::from_hms_opt(9, 30, 0).expect("valid time"),
+            close: NaiveTime::from_hms_opt(16, 0, 0).expect("valid time"),
+            weekend: vec![Weekday::Sat, Weekday::Sun],
+            holidays: BTreeSet::new(),
+            first_holiday_year: None,
+            last_holiday_year: None,
+        }
+    }
+}
+
+impl TradingCalendar {
+    /// Load a calendar yaml file such as `calendars/NYSE.yaml`.
+    pub fn from_path(path: &Path) -> GenResult<Self> {
+        let calendar: TradingCalendar = serde_yaml::from_str(&fs::read_to_string(path)?)?;
+        calendar.check()?;
+        Ok(calendar)
+    }
+
+    /// Load the calendar of `exchange` from `<calendar_dir>/<exchange>.yaml`.
+    pub fn for_exchange(calendar_dir: &Path, exchange: &str) -> GenResult<Self> {
+        let calendar =
+            TradingCalendar::from_path(&calendar_dir.join(format!("{}.yaml", exchange)))?;
+        if calendar.exchange == exchange {
+            Ok(calendar)
+        } else {
+            Err(CalendarError::new(format!(
+                "{}.yaml holds the calendar of {}",
+                exchange, calendar.exchange
+            )))
+        }
+    }
+
+    fn check(&self) -> GenResult<()> {
+        if FixedOffset::east_opt(self.utc_offset_minutes * 60).is_none() {
+            return Err(CalendarError::new(format!(
+                "{}: utc_offset_minutes {} out of range",
+                self.exchange, self.utc_offset_minutes
+            )));
+        }
+        if self.open >= self.close {
+            return Err(CalendarError::new(format!(
+                "{}: session opens at {} after it closes at {}",
+                self.exchange, self.open, self.close
+            )));
+        }
+        let weekend: BTreeSet<u32> = self
+            .weekend
+            .iter()
+            .map(|day| day.num_days_from_monday())
+            .collect();
+        if weekend.len() == 7 {
+            return Err(CalendarError::new(format!(
+                "{}: every day is a weekend",
+                self.exchange
+            )));
+        }
+        if let Some((first, last)) = self.holiday_years() {
+            if first > last {
+                return Err(CalendarError::new(format!(
+                    "{}: holidays listed from {} to {}",
+                    self.exchange, first, last
+                )));
+            }
+            if let Some(holiday) = self
+                .holidays
+                .iter()
+                .find(|holiday| holiday.year() < first || holiday.year() > last)
+            {
+                return Err(CalendarError::new(format!(
+                    "{}: holiday {} outside {} to {}",
+                    self.exchange, holiday, first, last
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Years `holidays` are listed for, `None` for a calendar without holidays.
+    pub fn holiday_years(&self) -> Option<(i32, i32)> {
+        let first = self
+            .first_holiday_year
+            .or_else(|| self.holidays.iter().next().map(|holiday| holiday.year()))?;
+        let last = self.last_holiday_year.or_else(|| {
+            self.holidays
+                .iter()
+                .next_back()
+                .map(|holiday| holiday.year())
+        })?;
+        Some((first, last))
+    }
+
+    /// Warn when days from `first` to `last` fall in years the holidays are not listed for.
+    fn check_holiday_years(&self, first: NaiveDate, last: NaiveDate) {
+        if let Some((first_year, last_year)) = self.holiday_years() {
+            if first.year() < first_year || last.year() > last_year {
+                warn!(
+                    "{}: holidays are listed from {} to {}, days from {} to {} may be trading days \
+                     in error",
+                    self.exchange, first_year, last_year, first, last
+                );
+            }
+        }
+    }
+
+    pub fn timezone(&self) -> FixedOffset {
+        FixedOffset::east_opt(self.utc_offset_minutes * 60).expect("utc offset out of range")
+    }
+
+    /// Date of `timestamp` in the exchange timezone.
+    pub fn local_date(&self, timestamp: &TimeStamp) -> NaiveDate {
+        timestamp.with_timezone(&self.timezone()).date_naive()
+    }
+
+    pub fn is_trading_day(&self, date: NaiveDate) -> bool {
+        !self.weekend.contains(&date.weekday()) && !self.holidays.contains(&date)
+    }
+
+    /// First trading day after `date`.
+    pub fn next_trading_day(&self, date: NaiveDate) -> NaiveDate {
+        let mut next = date.succ_opt().expect("date out of range");
+        while !self.is_trading_day(next) {
+            next = next.succ_opt().expect("date out of range");
+        }
+        next
+    }
+
+    /// Last trading day before `date`.
+    pub fn previous_trading_day(&self, date: NaiveDate) -> NaiveDate {
+        let mut previous = date.pred_opt().expect("date out of range");
+        while !self.is_trading_day(previous) {
+            previous = previous.pred_opt().expect("date out of range");
+        }
+        previous
+    }
+
+    /// Open and close of the regular session on `date`, `None` when the exchange is closed.
+    pub fn session(&self, date: NaiveDate) -> Option<(TimeStamp, TimeStamp)> {
+        if !self.is_trading_day(date) {
+            return None;
+        }
+        let timezone = self.timezone();
+        let at = |time: NaiveTime| {
+            timezone
+                .from_local_datetime(&date.and_time(time))
+                .single()
+                .map(|timestamp| timestamp.with_timezone(&Utc))
+        };
+        Some((at(self.open)?, at(self.close)?))
+    }
+
+    /// Whole days from `first` to `last` inclusive that fall on trading days.
+    pub fn trading_days(&self, first: TimeStamp, last: TimeStamp) -> Index {
+        self.check_holiday_years(self.local_date(&first), self.local_date(&last));
+        let mut index = vec![];
+        let mut day = first;
+        while day <= last {
+            if self.is_trading_day(self.local_date(&day)) {
+                index.push(day);
+            }
+            day += Duration::days(1);
+        }
+        index
+    }
+
+    /// Last trading day of each `frequency` period from `first` to `last` inclusive, `last` closing
+    /// a partial period.
+    pub fn rebalance_days(&self, first: TimeStamp, last: TimeStamp, frequency: Frequency) -> Index {
+        let trading_days = self.trading_days(first, last);
+        let mut trading_days = trading_days.iter().peekable();
+        let mut index = vec![];
+        while let Some(day) = trading_days.next() {
+            let period_ends = match trading_days.peek() {
+                Some(next) => frequency.period(next) != frequency.period(day),
+                None => true,
+            };
+            if period_ends {
+                index.push(*day);
+            }
+        }
+        index
+    }
+
+    /// The `len` trading days from `first` onward, keeping the time of day of `first`.
+    pub fn trading_index(&self, first: TimeStamp, len: usize) -> Index {
+        let mut index = Vec::with_capacity(len);
+        let mut day = first;
+        while index.len() < len {
+            if self.is_trading_day(self.local_date(&day)) {
+                index.push(day);
+            }
+            day += Duration::days(1);
+        }
+        if let Some(last) = index.last() {
+            self.check_holiday_years(self.local_date(&first), self.local_date(last));
+        }
+        index
+    }
+
+    /// Drop the values of `time_series` observed on non trading days so windows count trading days.
+    pub fn trading_days_only(&self, time_series: &TimeSeries1D) -> TimeSeries1D {
+        let index = time_series.index_slice();
+        if let (Some(first), Some(last)) = (index.first(), index.last()) {
+            self.check_holiday_years(self.local_date(first), self.local_date(last));
+        }
+        let (index, values) = time_series
+            .iter()
+            .filter(|(timestamp, _)| self.is_trading_day(self.local_date(timestamp)))
+            .unzip();
+        TimeSeries1D::from_vec(index, values)
+    }
+}
+
+impl MarketCalendar for TradingCalendar {
+    fn is_open(&self, timestamp: &TimeStamp) -> bool {
+        let local = timestamp.with_timezone(&self.timezone());
+        self.is_trading_day(self.local_date(timestamp))
+            && self.open <= local.time()
+            && local.time() < self.close
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use chrono::{Datelike, Duration, NaiveDate, NaiveTime, TimeZone, Utc, Weekday};
+
+    use crate::calendar::TradingCalendar;
+    use crate::errors::GenResult;
+    use crate::time_series::{Frequency, TimeSeries1D};
+    use crate::trader::MarketCalendar;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).expect("valid date")
+    }
+
+    #[test]
+    fn weekends_and_holidays() -> GenResult<()> {
+        let calendar = TradingCalendar::for_exchange(Path::new("calendars"), "NYSE")?;
+        // Independence Day 2012 fell on a Wednesday
+        assert!(calendar.is_trading_day(date(2012, 7, 6)));
+        assert!(!calendar.is_trading_day(date(2012, 7, 7)));
+        assert!(!calendar.is_trading_day(date(2012, 7, 4)));
+        assert_eq!(
+            calendar.next_trading_day(date(2012, 7, 6)),
+            date(2012, 7, 9)
+        );
+        assert_eq!(
+            calendar.next_trading_day(date(2012, 7, 3)),
+            date(2012, 7, 5)
+        );
+        assert_eq!(
+            calendar.previous_trading_day(date(2012, 7, 9)),
+            date(2012, 7, 6)
+        );
+        assert!(TradingCalendar::for_exchange(Path::new("calendars"), "XXXX").is_err());
+        assert_eq!(calendar.holiday_years(), Some((2010, 2013)));
+        assert_eq!(TradingCalendar::default().holiday_years(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn session_in_exchange_timezone() {
+        let calendar = TradingCalendar::default();
+        let (open, close) = calendar.session(date(2012, 7, 6)).expect("open");
+        assert_eq!(open, Utc.with_ymd_and_hms(2012, 7, 6, 14, 30, 0).unwrap());
+        assert_eq!(close, Utc.with_ymd_and_hms(2012, 7, 6, 21, 0, 0).unwrap());
+        assert_eq!(calendar.session(date(2012, 7, 7)), None);
+        assert!(calendar.is_open(&open));
+        assert!(!calendar.is_open(&close));
+        assert!(!calendar.is_open(&(open - Duration::minutes(1))));
+        // Friday 20:00 in Tokyo is Friday 11:00 UTC
+        let tokyo = TradingCalendar {
+            utc_offset_minutes: 9 * 60,
+            open: NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            close: NaiveTime::from_hms_opt(15, 0, 0).unwrap(),
+            ..TradingCalendar::default()
+        };
+        assert!(tokyo.is_open(&Utc.with_ymd_and_hms(2012, 7, 6, 1, 0, 0).unwrap()));
+        assert!(!tokyo.is_open(&Utc.with_ymd_and_hms(2012, 7, 6, 11, 0, 0).unwrap()));
+        // Friday 22:00 UTC is Saturday in Tokyo, still Friday in New York
+        let late = Utc.with_ymd_and_hms(2012, 7, 6, 22, 0, 0).unwrap();
+        assert_eq!(tokyo.local_date(&late), date(2012, 7, 7));
+        assert_eq!(calendar.local_date(&late), date(2012, 7, 6));
+    }
+
+    #[test]
+    fn trading_days_skip_weekends() {
+        let calendar = TradingCalendar::default();
+        let friday = Utc.with_ymd_and_hms(2012, 7, 6, 21, 0, 0).unwrap();
+        let index = calendar.trading_days(friday, friday + Duration::days(7));
+        assert_eq!(index.len(), 6);
+        assert!(index.iter().all(|day| !calendar
+            .weekend
+            .contains(&calendar.local_date(day).weekday())));
+        // midnight UTC is still the day before in New York
+        let saturday = Utc.with_ymd_and_hms(2012, 7, 7, 0, 0, 0).unwrap();
+        assert_eq!(calendar.trading_days(saturday, saturday), vec![saturday]);
+        assert_eq!(calendar.trading_index(friday, 6), index);
+        assert_eq!(index[1].weekday(), Weekday::Mon);
+    }
+
+    #[test]
+    fn month_ends() -> GenResult<()> {
+        let calendar = TradingCalendar::for_exchange(Path::new("calendars"), "NYSE")?;
+        let first = Utc.with_ymd_and_hms(2012, 1, 1, 21, 0, 0).unwrap();
+        let last = Utc.with_ymd_and_hms(2012, 4, 15, 21, 0, 0).unwrap();
+        let days: Vec<NaiveDate> = calendar
+            .rebalance_days(first, last, Frequency::Monthly)
+            .iter()
+            .map(|day| calendar.local_date(day))
+            .collect();
+        // March 31 is a Saturday, April 15 a Sunday
+        assert_eq!(
+            days,
+            vec![
+                date(2012, 1, 31),
+                date(2012, 2, 29),
+                date(2012, 3, 30),
+                date(2012, 4, 13)
+            ]
+        );
+        assert_eq!(
+            calendar.rebalance_days(first, last, Frequency::Daily),
+            calendar.trading_days(first, last)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn trading_days_only() {
+        let calendar = TradingCalendar::default();
+        // Friday to Monday, at noon UTC
+        let friday = Utc.with_ymd_and_hms(2012, 7, 6, 12, 0, 0).unwrap();
+        let ts = TimeSeries1D::from_vec(
+            (0..4).map(|x| friday + Duration::days(x)).collect(),
+            vec![1., 2., 3., 4.],
+        );
+        let trading = calendar.trading_days_only(&ts);
+        assert_eq!(trading.values(), vec![1., 4.]);
+        assert_eq!(trading.sma(2).values(), vec![2.5]);
+    }
+
+    #[test]
+    fn invalid_calendar() {
+        let calendar = TradingCalendar {
+            weekend: vec![
+                Weekday::Mon,
+                Weekday::Tue,
+                Weekday::Wed,
+                Weekday::Thu,
+                Weekday::Fri,
+                Weekday::Sat,
+                Weekday::Sun,
+            ],
+            ..TradingCalendar::default()
+        };
+        assert!(calendar.check().is_err());
+        let calendar = TradingCalendar {
+            open: calendar.close,
+            ..TradingCalendar::default()
+        };
+        assert!(calendar.check().is_err());
+        let calendar = TradingCalendar {
+            holidays: vec![date(2012, 7, 4)].into_iter().collect(),
+            first_holiday_year: Some(2013),
+            ..TradingCalendar::default()
+        };
+        assert!(calendar.check().is_err());
+        let calendar = TradingCalendar {
+            last_holiday_year: Some(2013),
+            ..calendar
+        };
+        assert!(calendar.check().is_err());
+        let calendar = TradingCalendar {
+            first_holiday_year: None,
+            ..calendar
+        };
+        assert_eq!(calendar.holiday_years(), Some((2012, 2013)));
+        assert!(calendar.check().is_ok());
+        assert!(TradingCalendar::default().check().is_ok());
+    }
+}
@@ -0,0 +1,401 @@
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::dto::strategy::{
+    load, namespace_calc, namespaced, validate, CalculationDto, OperandDto, OperandType, Operation,
+    ScoreDto, StrategyDto, TimeSeriesName, COLUMN_SEPARATOR, NAMESPACE_SEPARATOR,
+};
+use crate::errors::{GenError, GenResult, InvalidStrategyError};
+use crate::time_series::DataPointValue;
+
+/// Calc summing the weighted scores of the components, the primary score of a composite strategy
+pub const BLEND_CALC: &str = "blend";
+
+/// Strategy blended into a composite strategy, an item of the `composite` section of a strategy
+/// yaml.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ComponentDto {
+    /// Strategy yaml file, relative to the composite strategy yaml file
+    pub strategy: PathBuf,
+    pub weight: DataPointValue,
+    /// Namespace of the calcs of the component, the file stem of `strategy` by default
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Named score of the component blended, its primary score by default
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub score: Option<String>,
+}
+
+impl ComponentDto {
+    pub fn new(strategy: PathBuf, weight: DataPointValue) -> Self {
+        ComponentDto {
+            strategy,
+            weight,
+            name: None,
+            score: None,
+        }
+    }
+
+    pub fn namespace(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => self
+                .strategy
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().to_string())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// Replaces the components of a composite strategy with their calcs, each component in its own
+/// namespace, and the calcs blending their scores.
+///
+/// The blend is the primary score unless the composite strategy has scores of its own, in which
+/// case it is the score named `blend`. The score of each component is named after its namespace.
+/// The missing data and invalid values policies of the components must match the composite's,
+/// which apply to the composed strategy as a whole, as does its universe.
+pub(crate) fn compose(
+    mut strategy: StrategyDto,
+    strategy_dir: &Path,
+    loading: &mut Vec<PathBuf>,
+) -> GenResult<StrategyDto> {
+    let strategy_name = strategy.name().to_string();
+    let invalid =
+        |reason: String| -> GenError { InvalidStrategyError::new(strategy_name.clone(), reason) };
+    let has_score = strategy.has_score();
+    let blend_namespace = namespaced(BLEND_CALC, "");
+    if let Some(calc) = strategy
+        .calcs()
+        .iter()
+        .find(|calc| calc.name() == BLEND_CALC || calc.name().starts_with(&blend_namespace))
+    {
+        return Err(invalid(format!(
+            "calc {} clashes with the calcs blending the components, {} is reserved",
+            calc.name(),
+            BLEND_CALC
+        )));
+    }
+    if strategy
+        .scores()
+        .iter()
+        .any(|score| score.name() == Some(BLEND_CALC))
+    {
+        return Err(invalid(format!(
+            "score {} is reserved for the blended score",
+            BLEND_CALC
+        )));
+    }
+    let mut namespaces = HashSet::new();
+    let mut weighted_scores = vec![];
+    for component in strategy.take_composite() {
+        let namespace = component.namespace();
+        if namespace.is_empty()
+            || namespace.contains(COLUMN_SEPARATOR)
+            || namespace.contains(NAMESPACE_SEPARATOR)
+        {
+            return Err(invalid(format!(
+                "component {} needs a name without {} or {}",
+                component.strategy.display(),
+                COLUMN_SEPARATOR,
+                NAMESPACE_SEPARATOR
+            )));
+        }
+        if namespace == BLEND_CALC {
+            return Err(invalid(format!(
+                "component {} needs a name other than {}, which is reserved for the blended score",
+                component.strategy.display(),
+                BLEND_CALC
+            )));
+        }
+        if !namespaces.insert(namespace.clone()) {
+            return Err(invalid(format!(
+                "component {} is named more than once, give each component a distinct name",
+                namespace
+            )));
+        }
+        if !component.weight.is_finite() {
+            return Err(invalid(format!(
+                "component {} weight {} is not finite",
+                namespace, component.weight
+            )));
+        }
+        let component_strategy = load(&strategy_dir.join(&component.strategy), loading)
+            .and_then(|component_strategy| {
+                validate(&component_strategy)?;
+                Ok(component_strategy)
+            })
+            .map_err(|e| invalid(format!("component {}: {}", namespace, e)))?;
+        if component_strategy.missing_data() != strategy.missing_data()
+            || component_strategy.invalid_values() != strategy.invalid_values()
+        {
+            return Err(invalid(format!(
+                "component {} missing_data or invalid_values differ from the composite's",
+                namespace
+            )));
+        }
+        let score_calc = match &component.score {
+            None => component_strategy.score().calc(),
+            Some(score_name) => component_strategy
+                .scores()
+                .iter()
+                .find(|score| score.name() == Some(score_name.as_str()))
+                .ok_or_else(|| {
+                    invalid(format!(
+                        "component {} has no score named {}",
+                        namespace, score_name
+                    ))
+                })?
+                .calc(),
+        };
+        let score_calc = namespaced(&namespace, score_calc);
+        strategy.calcs_mut().extend(
+            component_strategy
+                .calcs()
+                .iter()
+                .map(|calc| namespace_calc(&namespace, calc)),
+        );
+        strategy
+            .scores_mut()
+            .push(ScoreDto::named(namespace.clone(), score_calc.clone()));
+        weighted_scores.push((namespace, score_calc, component.weight));
+    }
+    strategy.calcs_mut().extend(blend_calcs(weighted_scores));
+    if has_score {
+        strategy.scores_mut().push(ScoreDto::named(
+            BLEND_CALC.to_string(),
+            BLEND_CALC.to_string(),
+        ));
+    } else {
+        strategy.set_score(ScoreDto::new(BLEND_CALC.to_string()));
+    }
+    validate(&strategy)?;
+    Ok(strategy)
+}
+
+/// Calcs weighting each score then summing them, `blend` is the last
+fn blend_calcs(
+    weighted_scores: Vec<(String, TimeSeriesName, DataPointValue)>,
+) -> Vec<CalculationDto> {
+    let reference = |name: &str, value: &str| {
+        OperandDto::new(name.to_string(), OperandType::Reference, value.to_string())
+    };
+    let last = weighted_scores.len().saturating_sub(1);
+    let mut calcs = vec![];
+    let mut total: Option<TimeSeriesName> = None;
+    for (index, (namespace, score_calc, weight)) in weighted_scores.into_iter().enumerate() {
+        let name = |partial: String| {
+            if index == last {
+                BLEND_CALC.to_string()
+            } else {
+                partial
+            }
+        };
+        let weighted = match total {
+            Some(_) => namespaced(BLEND_CALC, &namespace),
+            None => name(namespaced(BLEND_CALC, &namespace)),
+        };
+        calcs.push(CalculationDto::new(
+            weighted.clone(),
+            Operation::MUL,
+            vec![
+                reference("time_series", &score_calc),
+                OperandDto::new(
+                    String::from("scalar"),
+                    OperandType::Decimal,
+                    weight.to_string(),
+                ),
+            ],
+        ));
+        total = Some(match total {
+            None => weighted,
+            Some(left) => {
+                let sum = name(namespaced(BLEND_CALC, &format!("sum{}", index)));
+                calcs.push(CalculationDto::new(
+                    sum.clone(),
+                    Operation::TS_ADD,
+                    vec![reference("left", &left), reference("right", &weighted)],
+                ));
+                sum
+            }
+        });
+    }
+    calcs
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    use crate::bot::asset_score::{RetainCalcs, RunnableStrategy};
+    use crate::composite::BLEND_CALC;
+    use crate::data::Asset;
+    use crate::dto::strategy::from_path;
+    use crate::errors::GenResult;
+    use crate::mock_client::MockDataClient;
+    use crate::persist::{load_time_series, save_calcs};
+
+    const VALUE_YAML: &str = r#"
+name: Value
+score:
+  calc: value
+calcs:
+  - name: value
+    operation: TS_DIV
+    operands:
+      - name: left
+        type: Reference
+        value: sma20
+      - name: right
+        type: Reference
+        value: price
+  - name: sma20
+    operation: SMA
+    operands:
+      - name: window_size
+        type: Integer
+        value: "20"
+      - name: time_series
+        type: Reference
+        value: price
+  - name: price
+    operation: QUERY
+    operands:
+      - name: field
+        type: Text
+        value: close
+"#;
+
+    /// Directory of strategy files, with the trend and value components
+    fn strategy_dir(test: &str, files: &[(&str, &str)]) -> GenResult<PathBuf> {
+        let dir = env::temp_dir().join(format!("composite_{}_{}", test, std::process::id()));
+        fs::create_dir_all(&dir)?;
+        fs::copy("strategy.yaml", dir.join("trend.yaml"))?;
+        fs::write(dir.join("value.yaml"), VALUE_YAML)?;
+        for (file_name, yaml) in files {
+            fs::write(dir.join(file_name), yaml)?;
+        }
+        Ok(dir)
+    }
+
+    fn latest_score(path: &Path) -> GenResult<f64> {
+        let runnable_strategy =
+            RunnableStrategy::new(from_path(path)?, Box::new(MockDataClient::new()))?;
+        let asset_score = runnable_strategy
+            .run_on_asset(Asset::new(String::from("A")), MockDataClient::today())?;
+        Ok(*asset_score.score().values_slice().last().expect("score"))
+    }
+
+    #[test]
+    fn blend() -> GenResult<()> {
+        let dir = strategy_dir(
+            "blend",
+            &[(
+                "blend.yaml",
+                "name: Blend\ncomposite:\n  - strategy: trend.yaml\n    weight: 0.6\n  - strategy: value.yaml\n    weight: 0.4\n",
+            )],
+        )?;
+        let strategy = from_path(&dir.join("blend.yaml"))?;
+        assert_eq!(strategy.score().calc(), BLEND_CALC);
+        assert_eq!(strategy.score_names(), vec!["trend", "value"]);
+        assert!(strategy
+            .calcs()
+            .iter()
+            .any(|calc| calc.name() == "trend/sma50"));
+        let runnable_strategy = RunnableStrategy::new(strategy, Box::new(MockDataClient::new()))?;
+        let asset_score = runnable_strategy
+            .run_on_asset(Asset::new(String::from("A")), MockDataClient::today())?;
+        let latest = |name: &str| *asset_score.scores()[name].values_slice().last().unwrap();
+        assert_eq!(latest("trend"), latest_score(&dir.join("trend.yaml"))?);
+        assert_eq!(latest("value"), latest_score(&dir.join("value.yaml"))?);
+        let blend = *asset_score.score().values_slice().last().unwrap();
+        assert!((blend - (0.6 * latest("trend") + 0.4 * latest("value"))).abs() < 1e-12);
+        fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn dump_namespaced_calcs() -> GenResult<()> {
+        let dir = strategy_dir(
+            "dump",
+            &[(
+                "blend.yaml",
+                "name: Blend\ncomposite:\n  - strategy: trend.yaml\n    weight: 0.6\n  - strategy: value.yaml\n    weight: 0.4\n",
+            )],
+        )?;
+        let mut runnable_strategy = RunnableStrategy::new(
+            from_path(&dir.join("blend.yaml"))?,
+            Box::new(MockDataClient::new()),
+        )?;
+        runnable_strategy.set_retain_calcs(RetainCalcs::All)?;
+        let asset_score = runnable_strategy
+            .run_on_asset(Asset::new(String::from("A")), MockDataClient::today())?;
+        save_calcs(&dir.join("dump"), &asset_score)?;
+        assert_eq!(
+            load_time_series(&dir.join("dump/A/trend/sma50.csv"))?.len(),
+            asset_score.calcs()["trend/sma50"].len()
+        );
+        assert!(dir.join("dump/A/blend.csv").is_file());
+        fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn cycle() -> GenResult<()> {
+        let dir = strategy_dir(
+            "cycle",
+            &[
+                ("a.yaml", "name: A\ncomposite:\n  - strategy: b.yaml\n    weight: 1\n"),
+                (
+                    "b.yaml",
+                    "name: B\ncomposite:\n  - strategy: trend.yaml\n    weight: 1\n  - strategy: a.yaml\n    weight: 1\n",
+                ),
+            ],
+        )?;
+        let error = from_path(&dir.join("a.yaml")).unwrap_err().to_string();
+        assert!(error.contains("composite cycle"), "{}", error);
+        fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_components() -> GenResult<()> {
+        let dir = strategy_dir(
+            "invalid",
+            &[
+                (
+                    "twice.yaml",
+                    "name: Twice\ncomposite:\n  - strategy: trend.yaml\n    weight: 1\n  - strategy: trend.yaml\n    weight: 1\n",
+                ),
+                (
+                    "missing.yaml",
+                    "name: Missing\ncomposite:\n  - strategy: momentum.yaml\n    weight: 1\n",
+                ),
+                (
+                    "unknown_score.yaml",
+                    "name: Unknown Score\ncomposite:\n  - strategy: trend.yaml\n    weight: 1\n    score: momentum\n",
+                ),
+                (
+                    "blend_component.yaml",
+                    "name: Blend Component\ncomposite:\n  - strategy: trend.yaml\n    weight: 1\n    name: blend\n",
+                ),
+                (
+                    "blend_calc.yaml",
+                    "name: Blend Calc\ncomposite:\n  - strategy: trend.yaml\n    weight: 1\ncalcs:\n  - name: blend\n    operation: QUERY\n    operands:\n      - name: field\n        type: Text\n        value: close\n",
+                ),
+            ],
+        )?;
+        let error = |file_name: &str| from_path(&dir.join(file_name)).unwrap_err().to_string();
+        assert!(error("twice.yaml").contains("named more than once"));
+        assert!(error("missing.yaml").contains("component momentum"));
+        assert!(error("unknown_score.yaml").contains("no score named momentum"));
+        assert!(error("blend_component.yaml").contains("reserved"));
+        assert!(error("blend_calc.yaml").contains("calc blend clashes"));
+        fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+}
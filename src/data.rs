@@ -141,6 +141,10 @@ pub trait DataClient {
     // TODO encapsulate params in struct
     // TODO support date ranges to minimize payloads
     fn query(&self, query: Query) -> GenResult<TimeSeries1D>;
+    /// Hint that `queries` are about to be executed so they can be fetched in bulk.
+    fn prefetch(&self, _queries: Vec<Query>) -> GenResult<()> {
+        Ok(())
+    }
 }
 
 impl Clone for Box<dyn DataClient> {
@@ -157,7 +161,7 @@ impl fmt::Debug for dyn DataClient {
     }
 }
 
-#[derive(Debug, Eq, PartialEq, Hash, Clone, Ord, Serialize)]
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Ord, Serialize, Deserialize)]
 pub struct Asset {
     symbol: Symbol,
 }
@@ -154,3 +154,95 @@ impl std::error::Error for QueryError {
         "Invalid query"
     }
 }
+
+#[derive(Debug, Clone)]
+pub struct OrderError {
+    reason: String,
+}
+
+impl OrderError {
+    pub fn new(reason: String) -> Box<Self> {
+        Box::new(OrderError { reason })
+    }
+}
+
+impl fmt::Display for OrderError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(f, "OrderError: {}", self.reason)
+    }
+}
+
+impl std::error::Error for OrderError {
+    fn description(&self) -> &str {
+        "Invalid order"
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BrokerError {
+    reason: String,
+}
+
+impl BrokerError {
+    pub fn new(reason: String) -> Box<Self> {
+        Box::new(BrokerError { reason })
+    }
+}
+
+impl fmt::Display for BrokerError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(f, "BrokerError: {}", self.reason)
+    }
+}
+
+impl std::error::Error for BrokerError {
+    fn description(&self) -> &str {
+        "Broker request failed"
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CalendarError {
+    reason: String,
+}
+
+impl CalendarError {
+    pub fn new(reason: String) -> Box<Self> {
+        Box::new(CalendarError { reason })
+    }
+}
+
+impl fmt::Display for CalendarError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(f, "CalendarError: {}", self.reason)
+    }
+}
+
+impl std::error::Error for CalendarError {
+    fn description(&self) -> &str {
+        "Invalid trading calendar"
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FormatError {
+    reason: String,
+}
+
+impl FormatError {
+    pub fn new(reason: String) -> Box<Self> {
+        Box::new(FormatError { reason })
+    }
+}
+
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(f, "FormatError: {}", self.reason)
+    }
+}
+
+impl std::error::Error for FormatError {
+    fn description(&self) -> &str {
+        "Invalid file format"
+    }
+}
@@ -0,0 +1,366 @@
+use std::collections::BTreeSet;
+use std::convert::TryFrom;
+
+use serde::Serialize;
+
+use crate::errors::{GenError, GenResult, TimeSeriesError};
+use crate::time_series::{
+    DataPointValue, Index, InvalidValues, MissingData, TimeSeries1D, TimeStamp,
+};
+
+pub type ColumnName = String;
+
+/// Column of a `TimeSeriesFrame` converted from a `TimeSeries1D`
+pub const VALUE_COLUMN: &str = "value";
+
+/// Named columns sharing one index, eg. OHLCV bars or the outputs of an indicator.
+///
+/// Columns keep their insertion order.  Timestamps a column lacks hold NaN.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct TimeSeriesFrame {
+    index: Index,
+    columns: Vec<(ColumnName, Vec<DataPointValue>)>,
+}
+
+impl TimeSeriesFrame {
+    /// Create a `TimeSeriesFrame` without columns on a sorted `index`
+    pub fn new(index: Index) -> Self {
+        assert!(
+            index.windows(2).all(|pair| pair[0] < pair[1]),
+            "TimeSeriesFrame index must be sorted and unique"
+        );
+        TimeSeriesFrame {
+            index,
+            columns: vec![],
+        }
+    }
+    /// Align `columns` according to `missing_data`.
+    ///
+    /// `MissingData::Drop` keeps the timestamps of every column, other policies the timestamps of
+    /// any column.
+    /// # Example
+    /// ```
+    /// use luckless::frame::TimeSeriesFrame;
+    /// use luckless::time_series::{MissingData, TimeSeries1D};
+    /// let close = TimeSeries1D::from_values(vec![1., 2., 3.]);
+    /// let volume = TimeSeries1D::from_values(vec![10., 20.]);
+    /// let columns = vec![(String::from("close"), close), (String::from("volume"), volume)];
+    /// let frame = TimeSeriesFrame::from_columns(columns.clone(), MissingData::Drop).unwrap();
+    /// assert_eq!(frame.len(), 2);
+    /// let frame = TimeSeriesFrame::from_columns(columns, MissingData::Outer).unwrap();
+    /// assert_eq!(frame.len(), 3);
+    /// assert!(frame.column("volume").unwrap().values()[2].is_nan());
+    /// ```
+    pub fn from_columns(
+        columns: Vec<(ColumnName, TimeSeries1D)>,
+        missing_data: MissingData,
+    ) -> GenResult<Self> {
+        let mut indices = columns.iter().map(|(_, time_series)| {
+            time_series
+                .index_slice()
+                .iter()
+                .cloned()
+                .collect::<BTreeSet<_>>()
+        });
+        let first = indices.next().unwrap_or_default();
+        let index: Index = match missing_data {
+            MissingData::Drop => indices
+                .fold(first, |both, index| {
+                    both.intersection(&index).cloned().collect()
+                })
+                .into_iter()
+                .collect(),
+            _ => indices
+                .fold(first, |any, index| any.union(&index).cloned().collect())
+                .into_iter()
+                .collect(),
+        };
+        let mut frame = TimeSeriesFrame::new(index);
+        for (name, time_series) in columns {
+            frame.insert_with(name, &time_series, missing_data)?;
+        }
+        Ok(frame)
+    }
+    pub fn index(&self) -> &[TimeStamp] {
+        &self.index
+    }
+    pub fn column_names(&self) -> Vec<&str> {
+        self.columns.iter().map(|(name, _)| name.as_str()).collect()
+    }
+    pub fn len(&self) -> usize {
+        self.index.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.index.is_empty()
+    }
+    /// Number of columns
+    pub fn width(&self) -> usize {
+        self.columns.len()
+    }
+    /// Values of the `name` column, `None` when there is no such column
+    pub fn values(&self, name: &str) -> Option<&[DataPointValue]> {
+        self.columns
+            .iter()
+            .find(|(column_name, _)| column_name == name)
+            .map(|(_, values)| values.as_slice())
+    }
+    /// Copy of the `name` column, `None` when there is no such column
+    pub fn column(&self, name: &str) -> Option<TimeSeries1D> {
+        self.values(name)
+            .map(|values| TimeSeries1D::from_vec(self.index.clone(), values.to_vec()))
+    }
+    /// Copy of every column in order
+    pub fn columns(&self) -> Vec<(ColumnName, TimeSeries1D)> {
+        self.columns
+            .iter()
+            .map(|(name, values)| {
+                (
+                    name.clone(),
+                    TimeSeries1D::from_vec(self.index.clone(), values.clone()),
+                )
+            })
+            .collect()
+    }
+    /// Add the `name` column, NaN where `time_series` lacks a timestamp of the index.
+    pub fn insert(&mut self, name: ColumnName, time_series: &TimeSeries1D) -> GenResult<()> {
+        self.insert_with(name, time_series, MissingData::Outer)
+    }
+    /// Add the `name` column, filling the timestamps `time_series` lacks according to
+    /// `missing_data`.
+    pub fn insert_with(
+        &mut self,
+        name: ColumnName,
+        time_series: &TimeSeries1D,
+        missing_data: MissingData,
+    ) -> GenResult<()> {
+        if self.values(&name).is_some() {
+            return Err(TimeSeriesError::new(format!("duplicate column {}", name)));
+        }
+        let values = time_series
+            .reindex(&self.index, keep_rows(missing_data))
+            .values();
+        self.columns.push((name, values));
+        Ok(())
+    }
+    /// Copy of the `names` columns in the given order.
+    pub fn select(&self, names: &[&str]) -> GenResult<Self> {
+        let mut frame = TimeSeriesFrame::new(self.index.clone());
+        for name in names {
+            let values = self
+                .values(name)
+                .ok_or_else(|| TimeSeriesError::new(format!("unknown column {}", name)))?;
+            if frame.values(name).is_some() {
+                return Err(TimeSeriesError::new(format!("duplicate column {}", name)));
+            }
+            frame.columns.push((name.to_string(), values.to_vec()));
+        }
+        Ok(frame)
+    }
+    /// Place every column on `index`, filling the timestamps they lack according to
+    /// `missing_data`.
+    pub fn reindex(&self, index: &[TimeStamp], missing_data: MissingData) -> Self {
+        let mut frame = TimeSeriesFrame::new(index.to_vec());
+        for (name, time_series) in self.columns() {
+            frame.columns.push((
+                name,
+                time_series.reindex(index, keep_rows(missing_data)).values(),
+            ));
+        }
+        frame
+    }
+    /// Align the rows of 2 `TimeSeriesFrame`s according to `missing_data`, as
+    /// `TimeSeries1D::align` does.
+    pub fn align(&self, rhs: &TimeSeriesFrame, missing_data: MissingData) -> (Self, Self) {
+        let lhs_index: BTreeSet<&TimeStamp> = self.index.iter().collect();
+        let rhs_index: BTreeSet<&TimeStamp> = rhs.index.iter().collect();
+        let index: Index = match missing_data {
+            MissingData::Drop => lhs_index
+                .intersection(&rhs_index)
+                .cloned()
+                .cloned()
+                .collect(),
+            _ => lhs_index.union(&rhs_index).cloned().cloned().collect(),
+        };
+        (
+            self.reindex(&index, missing_data),
+            rhs.reindex(&index, missing_data),
+        )
+    }
+    /// Number of NaN and infinite values across all columns.
+    pub fn count_non_finite(&self) -> usize {
+        self.columns
+            .iter()
+            .flat_map(|(_, values)| values.iter())
+            .filter(|value| !value.is_finite())
+            .count()
+    }
+    /// Remove the rows holding a NaN or infinite value.
+    pub fn mask_invalid(&self) -> Self {
+        let valid: Vec<bool> = (0..self.len())
+            .map(|row| {
+                self.columns
+                    .iter()
+                    .all(|(_, values)| values[row].is_finite())
+            })
+            .collect();
+        TimeSeriesFrame {
+            index: filter_rows(&self.index, &valid),
+            columns: self
+                .columns
+                .iter()
+                .map(|(name, values)| (name.clone(), filter_rows(values, &valid)))
+                .collect(),
+        }
+    }
+    /// Apply the `invalid_values` policy to the NaN and infinite values of every column.
+    pub fn validate(&self, invalid_values: InvalidValues) -> GenResult<Self> {
+        match invalid_values {
+            InvalidValues::Keep => Ok(self.clone()),
+            InvalidValues::Mask => Ok(self.mask_invalid()),
+            InvalidValues::Error => {
+                for (name, time_series) in self.columns() {
+                    time_series.check_finite().map_err(|e| {
+                        TimeSeriesError::new(format!("column {}: {}", name, e)) as GenError
+                    })?;
+                }
+                Ok(self.clone())
+            }
+        }
+    }
+}
+
+fn filter_rows<T: Copy>(values: &[T], keep: &[bool]) -> Vec<T> {
+    values
+        .iter()
+        .zip(keep)
+        .filter(|(_, keep)| **keep)
+        .map(|(value, _)| *value)
+        .collect()
+}
+
+/// Columns are reindexed onto an index already aligned, `MissingData::Drop` must not drop rows.
+fn keep_rows(missing_data: MissingData) -> MissingData {
+    match missing_data {
+        MissingData::Drop => MissingData::Outer,
+        _ => missing_data,
+    }
+}
+
+impl From<TimeSeries1D> for TimeSeriesFrame {
+    /// Single `VALUE_COLUMN` frame
+    fn from(time_series: TimeSeries1D) -> Self {
+        TimeSeriesFrame {
+            index: time_series.index(),
+            columns: vec![(VALUE_COLUMN.to_string(), time_series.values())],
+        }
+    }
+}
+
+impl TryFrom<TimeSeriesFrame> for TimeSeries1D {
+    type Error = GenError;
+
+    /// The only column of a frame
+    fn try_from(frame: TimeSeriesFrame) -> GenResult<Self> {
+        match frame.columns.len() {
+            1 => Ok(TimeSeries1D::from_vec(
+                frame.index,
+                frame.columns.into_iter().next().expect("1 column").1,
+            )),
+            width => Err(TimeSeriesError::new(format!(
+                "a frame of {} columns is not a TimeSeries1D",
+                width
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use crate::frame::{TimeSeriesFrame, VALUE_COLUMN};
+    use crate::time_series::{InvalidValues, MissingData, TimeSeries1D};
+
+    fn ohlc() -> TimeSeriesFrame {
+        let column =
+            |name: &str, values: Vec<f64>| (name.to_string(), TimeSeries1D::from_values(values));
+        TimeSeriesFrame::from_columns(
+            vec![
+                column("open", vec![1., 2., 3.]),
+                column("high", vec![2., 3., 4.]),
+                column("low", vec![0.5, 1.5, 2.5]),
+                column("close", vec![1.5, 2.5]),
+            ],
+            MissingData::Outer,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn columns() {
+        let frame = ohlc();
+        assert_eq!(frame.len(), 3);
+        assert_eq!(frame.width(), 4);
+        assert_eq!(frame.column_names(), vec!["open", "high", "low", "close"]);
+        assert_eq!(frame.column("high").unwrap().values(), vec![2., 3., 4.]);
+        assert!(frame.values("close").unwrap()[2].is_nan());
+        assert_eq!(frame.column("volume"), None);
+        let selected = frame.select(&["close", "open"]).unwrap();
+        assert_eq!(selected.column_names(), vec!["close", "open"]);
+        assert!(frame.select(&["volume"]).is_err());
+        assert!(frame.select(&["open", "open"]).is_err());
+        let mut frame = frame;
+        assert!(frame
+            .insert(String::from("open"), &TimeSeries1D::from_values(vec![]))
+            .is_err());
+        frame
+            .insert(String::from("volume"), &TimeSeries1D::from_values(vec![7.]))
+            .unwrap();
+        assert_eq!(frame.values("volume").unwrap()[0], 7.);
+        assert!(frame.values("volume").unwrap()[1].is_nan());
+    }
+
+    #[test]
+    fn to_and_from_time_series() {
+        let time_series = TimeSeries1D::from_values(vec![1., 2.]);
+        let frame = TimeSeriesFrame::from(time_series.clone());
+        assert_eq!(frame.column_names(), vec![VALUE_COLUMN]);
+        assert_eq!(TimeSeries1D::try_from(frame).unwrap(), time_series);
+        assert!(TimeSeries1D::try_from(ohlc()).is_err());
+    }
+
+    #[test]
+    fn align_rows() {
+        let frame = ohlc();
+        let other = TimeSeriesFrame::from(TimeSeries1D::from_vec(
+            vec![
+                frame.index()[1],
+                frame.index()[2] + chrono::Duration::days(1),
+            ],
+            vec![1., 2.],
+        ));
+        let (lhs, rhs) = frame.align(&other, MissingData::Drop);
+        assert_eq!(lhs.index(), &[frame.index()[1]]);
+        assert_eq!(lhs.values("open").unwrap(), &[2.]);
+        assert_eq!(rhs.values(VALUE_COLUMN).unwrap(), &[1.]);
+        let (lhs, rhs) = frame.align(&other, MissingData::ForwardFill { limit: None });
+        assert_eq!(lhs.len(), 4);
+        assert_eq!(rhs.len(), 4);
+        assert_eq!(lhs.values("open").unwrap()[3], 3.);
+        assert!(rhs.values(VALUE_COLUMN).unwrap()[0].is_nan());
+        assert_eq!(rhs.values(VALUE_COLUMN).unwrap()[2], 1.);
+    }
+
+    #[test]
+    fn validate() {
+        let frame = ohlc();
+        let masked = frame.validate(InvalidValues::Mask).unwrap();
+        assert_eq!(masked.len(), 2);
+        assert_eq!(masked.values("close").unwrap(), &[1.5, 2.5]);
+        assert!(frame.validate(InvalidValues::Error).is_err());
+        assert_eq!(masked.validate(InvalidValues::Error).unwrap(), masked);
+        let kept = frame.validate(InvalidValues::Keep).unwrap();
+        assert_eq!(kept.len(), 3);
+        assert!(kept.values("close").unwrap()[2].is_nan());
+    }
+}
@@ -0,0 +1,598 @@
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt::Write as _;
+use std::io::Write;
+use std::path::Path;
+
+use chrono::SecondsFormat;
+
+use crate::bot::asset_score::{CalculationStatus, RunnableStrategy};
+use crate::data::{Asset, Symbol};
+use crate::dto::dag::Dag;
+use crate::dto::strategy::{
+    referenced_calc, OperandType, StrategyDto, TimeSeriesName, COLUMN_SEPARATOR,
+};
+use crate::errors::{FormatError, GenError, GenResult};
+use crate::time_series::TimeStamp;
+use crate::trace::{AssetTrace, CalcTrace};
+
+/// How a strategy graph is exported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphFormat {
+    /// Graphviz, eg. `dot -Tpng`
+    Dot,
+    /// Mermaid flowchart, rendered by GitHub and GitLab markdown
+    Mermaid,
+    Svg,
+    /// Self-contained page of the svg and a table of the calcs
+    Html,
+}
+
+impl GraphFormat {
+    /// Format of an output file by extension, `Dot` for unknown extensions.
+    pub fn from_path(path: &Path) -> Self {
+        path.extension()
+            .and_then(|extension| extension.to_str())
+            .and_then(|extension| GraphFormat::try_from(extension).ok())
+            .unwrap_or(GraphFormat::Dot)
+    }
+}
+
+impl TryFrom<&str> for GraphFormat {
+    type Error = GenError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.to_uppercase().as_str() {
+            "DOT" | "GV" => Ok(GraphFormat::Dot),
+            "MERMAID" | "MMD" => Ok(GraphFormat::Mermaid),
+            "SVG" => Ok(GraphFormat::Svg),
+            "HTML" | "HTM" => Ok(GraphFormat::Html),
+            _ => Err(FormatError::new(format!("unknown graph format {}", value))),
+        }
+    }
+}
+
+pub fn parse_graph_format(arg: &str) -> Result<GraphFormat, String> {
+    GraphFormat::try_from(arg).map_err(|e| e.to_string())
+}
+
+/// Calc of the graph with its operation and the operands not drawn as edges
+#[derive(Debug, Clone, PartialEq)]
+struct Node {
+    name: TimeSeriesName,
+    operation: String,
+    operands: Vec<String>,
+    run: Option<CalcTrace>,
+}
+
+/// Dependency of `to` on `from`, labeled by the operands of `to` referencing `from`
+#[derive(Debug, Clone, PartialEq)]
+struct Edge {
+    from: usize,
+    to: usize,
+    label: String,
+}
+
+/// Calcs of a strategy and their dependencies, optionally annotated by a run on an asset.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StrategyGraph {
+    strategy: String,
+    score: TimeSeriesName,
+    /// In execution order
+    nodes: Vec<Node>,
+    edges: Vec<Edge>,
+    run: Option<(Symbol, TimeStamp)>,
+}
+
+impl StrategyGraph {
+    pub fn new(strategy: &StrategyDto) -> GenResult<Self> {
+        let execution_order = Dag::new(strategy.clone())?.execution_order();
+        let position: HashMap<&str, usize> = execution_order
+            .iter()
+            .enumerate()
+            .map(|(i, name)| (name.as_str(), i))
+            .collect();
+        let calcs: HashMap<&str, _> = strategy
+            .calcs()
+            .iter()
+            .map(|calc| (calc.name(), calc))
+            .collect();
+        let mut nodes = vec![];
+        let mut edges: Vec<Edge> = vec![];
+        for (to, name) in execution_order.iter().enumerate() {
+            let calc = calcs[name.as_str()];
+            let mut operands = vec![];
+            for operand in calc.operands() {
+                let from = position.get(referenced_calc(operand.value()));
+                match (operand._type(), from) {
+                    (OperandType::Reference, Some(from)) => {
+                        // a frame column is noted next to the operand name
+                        let label = match operand.value().split_once(COLUMN_SEPARATOR) {
+                            Some((_, column)) => format!("{} ({})", operand.name(), column),
+                            None => operand.name().to_string(),
+                        };
+                        match edges
+                            .iter_mut()
+                            .find(|edge| edge.from == *from && edge.to == to)
+                        {
+                            Some(edge) => edge.label = format!("{}, {}", edge.label, label),
+                            None => edges.push(Edge {
+                                from: *from,
+                                to,
+                                label,
+                            }),
+                        }
+                    }
+                    _ => operands.push(format!("{}={}", operand.name(), operand.value())),
+                }
+            }
+            nodes.push(Node {
+                name: name.clone(),
+                operation: format!("{:?}", calc.operation()),
+                operands,
+                run: None,
+            });
+        }
+        Ok(StrategyGraph {
+            strategy: strategy.name().to_string(),
+            score: strategy.score().calc().to_string(),
+            nodes,
+            edges,
+            run: None,
+        })
+    }
+
+    /// Annotate the calcs by their runs on `asset` as of `timestamp`
+    pub fn with_run(
+        self,
+        runnable_strategy: &RunnableStrategy,
+        asset: Asset,
+        timestamp: TimeStamp,
+    ) -> Self {
+        self.with_trace(&runnable_strategy.trace_asset(asset, timestamp).1)
+    }
+
+    /// Annotate the calcs by their runs in the trace of an asset
+    pub fn with_trace(mut self, asset_trace: &AssetTrace) -> Self {
+        for node in &mut self.nodes {
+            node.run = asset_trace
+                .calcs
+                .iter()
+                .find(|calc_trace| calc_trace.calc == node.name)
+                .cloned();
+        }
+        self.run = Some((asset_trace.symbol.clone(), asset_trace.timestamp));
+        self
+    }
+
+    pub fn write<W: Write>(&self, mut writer: W, format: GraphFormat) -> GenResult<()> {
+        let text = match format {
+            GraphFormat::Dot => self.to_dot(),
+            GraphFormat::Mermaid => self.to_mermaid(),
+            GraphFormat::Svg => self.to_svg(),
+            GraphFormat::Html => self.to_html(),
+        };
+        writer.write_all(text.as_bytes())?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    fn title(&self) -> String {
+        match &self.run {
+            Some((symbol, timestamp)) => format!(
+                "{} on {} as of {}",
+                self.strategy,
+                symbol,
+                timestamp.to_rfc3339_opts(SecondsFormat::Secs, true)
+            ),
+            None => self.strategy.clone(),
+        }
+    }
+
+    /// Name, operation, operands and run of a node, one line each
+    fn label_lines(node: &Node) -> Vec<String> {
+        let mut lines = vec![node.name.clone(), node.operation.clone()];
+        if !node.operands.is_empty() {
+            lines.push(node.operands.join(", "));
+        }
+        if let Some(run) = &node.run {
+            lines.push(run_summary(run));
+        }
+        lines
+    }
+
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::new();
+        let _ = writeln!(dot, "digraph \"{}\" {{", dot_escape(&self.strategy));
+        let _ = writeln!(dot, "    label=\"{}\";", dot_escape(&self.title()));
+        dot.push_str("    labelloc=t;\n");
+        dot.push_str(
+            "    node [shape=box, style=\"rounded,filled\", fillcolor=\"#ffffff\", fontname=\"Helvetica\"];\n",
+        );
+        dot.push_str("    edge [fontname=\"Helvetica\", fontsize=10];\n");
+        for node in &self.nodes {
+            let label = StrategyGraph::label_lines(node)
+                .iter()
+                .map(|line| dot_escape(line))
+                .collect::<Vec<_>>()
+                .join("\\n");
+            let _ = write!(
+                dot,
+                "    \"{}\" [label=\"{}\"",
+                dot_escape(&node.name),
+                label
+            );
+            if let Some(run) = &node.run {
+                let (fill, stroke) = status_colors(&run.status);
+                let _ = write!(dot, ", fillcolor=\"{}\", color=\"{}\"", fill, stroke);
+            }
+            if node.name == self.score {
+                dot.push_str(", peripheries=2");
+            }
+            dot.push_str("];\n");
+        }
+        for edge in &self.edges {
+            let _ = writeln!(
+                dot,
+                "    \"{}\" -> \"{}\" [label=\"{}\"];",
+                dot_escape(&self.nodes[edge.from].name),
+                dot_escape(&self.nodes[edge.to].name),
+                dot_escape(&edge.label)
+            );
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    pub fn to_mermaid(&self) -> String {
+        let mut mermaid = String::new();
+        let _ = writeln!(
+            mermaid,
+            "---\ntitle: {}\n---",
+            mermaid_escape(&self.title())
+        );
+        mermaid.push_str("flowchart TD\n");
+        for (i, node) in self.nodes.iter().enumerate() {
+            let label = StrategyGraph::label_lines(node)
+                .iter()
+                .map(|line| mermaid_escape(line))
+                .collect::<Vec<_>>()
+                .join("<br/>");
+            // the score calc is drawn as a stadium
+            if node.name == self.score {
+                mermaid.push_str(&format!("    n{}([\"{}\"])\n", i, label));
+            } else {
+                mermaid.push_str(&format!("    n{}[\"{}\"]\n", i, label));
+            }
+        }
+        for edge in &self.edges {
+            let _ = writeln!(
+                mermaid,
+                "    n{} -->|\"{}\"| n{}",
+                edge.from,
+                mermaid_escape(&edge.label),
+                edge.to
+            );
+        }
+        for (i, node) in self.nodes.iter().enumerate() {
+            if let Some(run) = &node.run {
+                let (fill, stroke) = status_colors(&run.status);
+                let _ = writeln!(mermaid, "    style n{} fill:{},stroke:{}", i, fill, stroke);
+            }
+        }
+        mermaid
+    }
+
+    /// Layers of calcs from top to bottom, each calc one layer below its lowest dependency
+    fn layers(&self) -> Vec<Vec<usize>> {
+        let mut layer_of = vec![0usize; self.nodes.len()];
+        // edges point forward in execution order
+        for to in 0..self.nodes.len() {
+            layer_of[to] = self
+                .edges
+                .iter()
+                .filter(|edge| edge.to == to)
+                .map(|edge| layer_of[edge.from] + 1)
+                .max()
+                .unwrap_or(0);
+        }
+        let mut layers = vec![vec![]; layer_of.iter().max().map_or(0, |max| max + 1)];
+        for (node, layer) in layer_of.into_iter().enumerate() {
+            layers[layer].push(node);
+        }
+        layers
+    }
+
+    pub fn to_svg(&self) -> String {
+        const LINE_HEIGHT: usize = 16;
+        const CHAR_WIDTH: usize = 7;
+        const PADDING: usize = 10;
+        const H_GAP: usize = 40;
+        const V_GAP: usize = 60;
+        const TITLE_HEIGHT: usize = 40;
+        let labels: Vec<Vec<String>> = self.nodes.iter().map(StrategyGraph::label_lines).collect();
+        let node_width = labels
+            .iter()
+            .flatten()
+            .map(|line| line.chars().count() * CHAR_WIDTH + 2 * PADDING)
+            .max()
+            .unwrap_or(0);
+        let node_height =
+            labels.iter().map(|lines| lines.len()).max().unwrap_or(0) * LINE_HEIGHT + 2 * PADDING;
+        let layers = self.layers();
+        let widest = layers.iter().map(|layer| layer.len()).max().unwrap_or(0);
+        let width = (widest * (node_width + H_GAP) + H_GAP).max(self.title().len() * 8);
+        let height = TITLE_HEIGHT + layers.len() * (node_height + V_GAP);
+        // top left corner of every node, layers centered horizontally
+        let mut corners = vec![(0, 0); self.nodes.len()];
+        for (row, layer) in layers.iter().enumerate() {
+            let layer_width = layer.len() * (node_width + H_GAP) - H_GAP;
+            for (column, node) in layer.iter().enumerate() {
+                corners[*node] = (
+                    (width - layer_width) / 2 + column * (node_width + H_GAP),
+                    TITLE_HEIGHT + row * (node_height + V_GAP) + V_GAP / 2,
+                );
+            }
+        }
+        let mut svg = String::new();
+        let _ = writeln!(
+            svg,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\" font-family=\"Helvetica, Arial, sans-serif\" font-size=\"12\">",
+            w = width,
+            h = height
+        );
+        svg.push_str("  <defs><marker id=\"arrow\" viewBox=\"0 0 10 10\" refX=\"10\" refY=\"5\" markerWidth=\"8\" markerHeight=\"8\" orient=\"auto-start-reverse\"><path d=\"M 0 0 L 10 5 L 0 10 z\" fill=\"#555555\"/></marker></defs>\n");
+        let _ = writeln!(
+            svg,
+            "  <text x=\"{}\" y=\"24\" text-anchor=\"middle\" font-size=\"16\" font-weight=\"bold\">{}</text>",
+            width / 2,
+            xml_escape(&self.title())
+        );
+        for edge in &self.edges {
+            let (x1, y1) = corners[edge.from];
+            let (x2, y2) = corners[edge.to];
+            let (x1, y1) = (x1 + node_width / 2, y1 + node_height);
+            let x2 = x2 + node_width / 2;
+            let _ = writeln!(
+                svg,
+                "  <line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\" stroke=\"#555555\" marker-end=\"url(#arrow)\"/>",
+                x1, y1, x2, y2
+            );
+            let _ = writeln!(
+                svg,
+                "  <text x=\"{}\" y=\"{}\" text-anchor=\"middle\" font-size=\"10\" fill=\"#333333\">{}</text>",
+                (x1 + x2) / 2,
+                (y1 + y2) / 2,
+                xml_escape(&edge.label)
+            );
+        }
+        for (i, node) in self.nodes.iter().enumerate() {
+            let (x, y) = corners[i];
+            let (fill, stroke) = match &node.run {
+                Some(run) => status_colors(&run.status),
+                None => ("#ffffff", "#555555"),
+            };
+            let stroke_width = if node.name == self.score { 3 } else { 1 };
+            let _ = writeln!(
+                svg,
+                "  <rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" rx=\"6\" fill=\"{}\" stroke=\"{}\" stroke-width=\"{}\"/>",
+                x, y, node_width, node_height, fill, stroke, stroke_width
+            );
+            for (line_number, line) in labels[i].iter().enumerate() {
+                let weight = match line_number {
+                    0 => " font-weight=\"bold\"",
+                    _ => "",
+                };
+                let _ = writeln!(
+                    svg,
+                    "  <text x=\"{}\" y=\"{}\" text-anchor=\"middle\"{}>{}</text>",
+                    x + node_width / 2,
+                    y + PADDING + (line_number + 1) * LINE_HEIGHT - 4,
+                    weight,
+                    xml_escape(line)
+                );
+            }
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    pub fn to_html(&self) -> String {
+        let title = xml_escape(&self.title());
+        let mut html = String::new();
+        html.push_str("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n");
+        let _ = writeln!(html, "<title>{}</title>", title);
+        html.push_str("<style>body { font-family: Helvetica, Arial, sans-serif; } table { border-collapse: collapse; } th, td { border: 1px solid #cccccc; padding: 4px 8px; text-align: left; } td.number { text-align: right; }</style>\n");
+        html.push_str("</head>\n<body>\n");
+        let _ = writeln!(html, "<h1>{}</h1>", title);
+        html.push_str(&self.to_svg());
+        html.push_str("<table>\n<tr><th>calc</th><th>operation</th><th>operands</th><th>depends on</th><th>status</th><th>length</th><th>time</th></tr>\n");
+        for (i, node) in self.nodes.iter().enumerate() {
+            let depends_on: Vec<String> = self
+                .edges
+                .iter()
+                .filter(|edge| edge.to == i)
+                .map(|edge| format!("{} ({})", self.nodes[edge.from].name, edge.label))
+                .collect();
+            let (status, len, duration) = match &node.run {
+                Some(run) => (
+                    format!("{:?}", run.status),
+                    run.output_len
+                        .map(|len| len.to_string())
+                        .unwrap_or_default(),
+                    run.duration.map(format_duration).unwrap_or_default(),
+                ),
+                None => Default::default(),
+            };
+            let _ = writeln!(
+                html,
+                "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td class=\"number\">{}</td><td class=\"number\">{}</td></tr>",
+                xml_escape(&node.name),
+                xml_escape(&node.operation),
+                xml_escape(&node.operands.join(", ")),
+                xml_escape(&depends_on.join(", ")),
+                status,
+                len,
+                duration
+            );
+        }
+        html.push_str("</table>\n</body>\n</html>\n");
+        html
+    }
+}
+
+/// eg. `Complete, 850 values, 1.250 ms`
+fn run_summary(run: &CalcTrace) -> String {
+    let mut summary = format!("{:?}", run.status);
+    if let Some(len) = run.output_len {
+        summary.push_str(&format!(", {} values", len));
+    }
+    if let Some(duration) = run.duration {
+        summary.push_str(&format!(", {}", format_duration(duration)));
+    }
+    summary
+}
+
+fn format_duration(duration: std::time::Duration) -> String {
+    format!("{:.3} ms", duration.as_secs_f64() * 1000.)
+}
+
+/// Fill and stroke colors of a calc by status
+fn status_colors(status: &CalculationStatus) -> (&'static str, &'static str) {
+    match status {
+        CalculationStatus::Complete => ("#d4edda", "#28a745"),
+        CalculationStatus::Error => ("#f8d7da", "#dc3545"),
+        CalculationStatus::InProgress => ("#fff3cd", "#ffc107"),
+        CalculationStatus::NotStarted => ("#e9ecef", "#6c757d"),
+    }
+}
+
+fn dot_escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn mermaid_escape(text: &str) -> String {
+    text.replace('"', "#quot;")
+}
+
+fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use std::fs::read_to_string;
+    use std::path::Path;
+
+    use crate::bot::asset_score::{CalculationStatus, RunnableStrategy};
+    use crate::data::Asset;
+    use crate::dto::strategy::from_path;
+    use crate::errors::GenResult;
+    use crate::graph::{GraphFormat, StrategyGraph};
+    use crate::mock_client::{MockDataClient, DATA_SIZE};
+
+    fn graph_fixture() -> GenResult<StrategyGraph> {
+        let strategy = from_path(Path::new("strategy.yaml"))?;
+        StrategyGraph::new(&strategy)
+    }
+
+    fn run_graph_fixture(symbol: &str) -> GenResult<StrategyGraph> {
+        let strategy = from_path(Path::new("strategy.yaml"))?;
+        let runnable_strategy =
+            RunnableStrategy::new(strategy.clone(), Box::new(MockDataClient::new()))?;
+        let timestamp = MockDataClient::today();
+        let asset = Asset::new(symbol.to_string());
+        Ok(StrategyGraph::new(&strategy)?.with_run(&runnable_strategy, asset, timestamp))
+    }
+
+    #[test]
+    fn nodes_and_edges() -> GenResult<()> {
+        let graph = graph_fixture()?;
+        assert_eq!(graph.nodes.len(), 5);
+        assert_eq!(graph.edges.len(), 6);
+        let sma50 = graph
+            .nodes
+            .iter()
+            .find(|node| node.name == "sma50")
+            .expect("sma50");
+        assert_eq!(sma50.operation, "SMA");
+        assert_eq!(sma50.operands, vec!["window_size=50"]);
+        let dot = graph.to_dot();
+        assert!(dot.contains("\"price\" -> \"sma50\" [label=\"time_series\"];"));
+        assert!(dot.contains("\"sma50\" [label=\"sma50\\nSMA\\nwindow_size=50\"];"));
+        assert!(dot.contains("peripheries=2"));
+        let mermaid = graph.to_mermaid();
+        assert!(mermaid.contains("flowchart TD"));
+        assert!(mermaid.contains("-->|\"time_series\"|"));
+        Ok(())
+    }
+
+    #[test]
+    fn dot_file() -> GenResult<()> {
+        assert_eq!(
+            graph_fixture()?.to_dot(),
+            read_to_string("expected_output.dot")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn annotated_by_runs() -> GenResult<()> {
+        let graph = run_graph_fixture("A")?;
+        for node in &graph.nodes {
+            let run = node.run.as_ref().expect("run");
+            assert_eq!(run.status, CalculationStatus::Complete);
+            assert!(run.duration.is_some());
+        }
+        let price = &graph.nodes[0];
+        assert_eq!(
+            price.run.as_ref().and_then(|run| run.output_len),
+            Some(DATA_SIZE)
+        );
+        assert!(graph
+            .to_dot()
+            .contains(&format!("Complete, {} values", DATA_SIZE)));
+        let failed = run_graph_fixture("Z")?;
+        assert_eq!(
+            failed.nodes[0].run.as_ref().map(|run| &run.status),
+            Some(&CalculationStatus::Error)
+        );
+        assert_eq!(
+            failed.nodes[4].run.as_ref().map(|run| &run.status),
+            Some(&CalculationStatus::NotStarted)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn svg_and_html() -> GenResult<()> {
+        let graph = run_graph_fixture("A")?;
+        let svg = graph.to_svg();
+        assert!(svg.starts_with("<svg "));
+        assert!(svg.ends_with("</svg>\n"));
+        assert_eq!(svg.matches("<rect ").count(), 5);
+        assert_eq!(svg.matches("<line ").count(), 6);
+        let html = graph.to_html();
+        assert!(html.contains(&svg));
+        // header and one row per calc
+        assert_eq!(html.matches("<tr>").count(), 6);
+        Ok(())
+    }
+
+    #[test]
+    fn format_of_graph() {
+        assert_eq!(
+            GraphFormat::from_path(Path::new("strategy.mmd")),
+            GraphFormat::Mermaid
+        );
+        assert_eq!(
+            GraphFormat::from_path(Path::new("strategy")),
+            GraphFormat::Dot
+        );
+        assert!(GraphFormat::try_from("png").is_err());
+    }
+}
@@ -4,14 +4,35 @@
 #[macro_use]
 extern crate approx;
 
+pub mod bot_server;
+pub mod broker;
+pub mod broker_client;
+pub mod broker_server;
+pub mod broker_service;
+pub mod broker_service_grpc;
+pub mod calendar;
+pub mod composite;
 pub mod data;
 pub mod errors;
+pub mod frame;
+pub mod graph;
+pub mod library;
 pub mod mock_client;
+pub mod orders;
+pub mod paper_broker;
+pub mod persist;
 pub mod plot;
 pub mod query;
 pub mod query_client;
 pub mod query_grpc;
+pub mod query_server;
+pub mod report;
+pub mod scoring;
+pub mod scoring_grpc;
 pub mod time_series;
+pub mod trace;
+pub mod trader;
+pub mod universe;
 
 pub mod bot {
     pub mod asset_score {
@@ -21,20 +42,32 @@ pub mod bot {
         use std::collections::{BTreeMap, HashMap};
         use std::convert::TryInto;
         use std::fmt;
+        use std::time::Instant;
 
+        use chrono::Utc;
         use itertools::Itertools;
-        use serde::{Serialize, Serializer};
+        use log::{debug, trace, warn};
+        use serde::{Deserialize, Serialize, Serializer};
 
+        use crate::calendar::TradingCalendar;
         use crate::data::{Asset, DataClient, Query};
         use crate::dto::dag::Dag;
         use crate::dto::strategy::{
-            CalculationDto, DyadicScalarCalculationDto, DyadicTsCalculationDto, Operation,
-            QueryCalculationDto, SmaCalculationDto, StrategyDto, TimeSeriesName,
+            column_reference, referenced_calc, AsofCalculationDto, BbandsCalculationDto,
+            CalculationDto, DropNaCalculationDto, DyadicScalarCalculationDto,
+            DyadicTsCalculationDto, FillNaCalculationDto, FrameCalculationDto, OperandType,
+            Operation, QueryCalculationDto, ResampleCalculationDto, SmaCalculationDto, StrategyDto,
+            TimeSeriesName, TimeSeriesReference,
         };
-        use crate::errors::{GenResult, UpstreamNotFoundError};
-        use crate::time_series::{apply, Allocation, DataPointValue, TimeSeries1D, TimeStamp};
+        use crate::errors::{GenError, GenResult, TimeSeriesError, UpstreamNotFoundError};
+        use crate::frame::TimeSeriesFrame;
+        use crate::time_series::{
+            apply, Allocation, DataPointValue, Frequency, InvalidValues, MissingData, TimeSeries1D,
+            TimeStamp,
+        };
+        use crate::trace::{AssetTrace, CalcTrace, RunTrace};
 
-        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
         pub enum CalculationStatus {
             NotStarted,
             InProgress,
@@ -42,7 +75,31 @@ pub mod bot {
             Error,
         }
 
-        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        /// Intermediate calc series kept in each `AssetScore`, eg. to check each step of a
+        /// strategy.
+        #[derive(Debug, Clone, PartialEq, Default)]
+        pub enum RetainCalcs {
+            #[default]
+            None,
+            All,
+            /// Calcs by name, a frame calc keeps all its columns, eg. `bbands.upper`
+            Only(Vec<TimeSeriesName>),
+        }
+
+        impl RetainCalcs {
+            /// Whether the series of `reference`, a calc or a frame column, is kept
+            fn retains(&self, reference: &str) -> bool {
+                match self {
+                    RetainCalcs::None => false,
+                    RetainCalcs::All => true,
+                    RetainCalcs::Only(names) => names
+                        .iter()
+                        .any(|name| name == reference || name == referenced_calc(reference)),
+                }
+            }
+        }
+
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum AssetScoreStatus {
             NotStarted,
             InProgress,
@@ -57,10 +114,13 @@ pub mod bot {
             strategy: StrategyDto,
             dag: Dag,
             calcs: HashMap<TimeSeriesName, CalculationDto>,
+            calendar: TradingCalendar,
+            retain_calcs: RetainCalcs,
         }
 
         impl RunnableStrategy {
             pub fn new(strategy: StrategyDto, data_client: Box<dyn DataClient>) -> GenResult<Self> {
+                strategy.universe().check(strategy.name())?;
                 let dag = Dag::new(strategy.clone())?;
                 let calcs: HashMap<String, CalculationDto> = strategy
                     .calcs()
@@ -72,10 +132,49 @@ pub mod bot {
                     strategy,
                     dag,
                     calcs,
+                    calendar: TradingCalendar::default(),
+                    retain_calcs: RetainCalcs::default(),
                 })
             }
             pub fn duplicate(&self) -> GenResult<Self> {
-                RunnableStrategy::new(self.strategy.clone(), self.data_client.clone())
+                let mut runnable_strategy =
+                    RunnableStrategy::new(self.strategy.clone(), self.data_client.clone())?;
+                runnable_strategy.set_calendar(self.calendar.clone());
+                runnable_strategy.retain_calcs = self.retain_calcs.clone();
+                Ok(runnable_strategy)
+            }
+            pub fn strategy(&self) -> &StrategyDto {
+                &self.strategy
+            }
+            pub fn data_client(&self) -> &dyn DataClient {
+                self.data_client.as_ref()
+            }
+            /// Trading days stepped through by back tests and counted by windowed calcs
+            pub fn calendar(&self) -> &TradingCalendar {
+                &self.calendar
+            }
+            pub fn set_calendar(&mut self, calendar: TradingCalendar) {
+                self.calendar = calendar;
+            }
+            /// Intermediate calc series kept in each `AssetScore`, none by default
+            pub fn retain_calcs(&self) -> &RetainCalcs {
+                &self.retain_calcs
+            }
+            pub fn set_retain_calcs(&mut self, retain_calcs: RetainCalcs) -> GenResult<()> {
+                if let RetainCalcs::Only(names) = &retain_calcs {
+                    if let Some(unknown) = names
+                        .iter()
+                        .find(|name| !self.calcs.contains_key(referenced_calc(name)))
+                    {
+                        return Err(UpstreamNotFoundError::new(unknown.clone()));
+                    }
+                }
+                self.retain_calcs = retain_calcs;
+                Ok(())
+            }
+            /// Names of the calcs in the order they are executed
+            pub fn execution_order(&self) -> Vec<TimeSeriesName> {
+                self.dag.execution_order()
             }
             /// Computes the score of the given `Asset` at the given `TimeStamp`
             pub fn run_on_asset(
@@ -83,33 +182,158 @@ pub mod bot {
                 asset: Asset,
                 timestamp: TimeStamp,
             ) -> GenResult<AssetScore> {
-                let mut scorable_asset = ScorableAsset {
+                self.trace_asset(asset, timestamp).0
+            }
+            /// Computes the score of the given `Asset` at the given `TimeStamp`, tracing every
+            /// calc. Calcs after a failed calc are `NotStarted`.
+            pub fn trace_asset(
+                &self,
+                asset: Asset,
+                timestamp: TimeStamp,
+            ) -> (GenResult<AssetScore>, AssetTrace) {
+                let (asset_score, _, asset_trace) = self.trace_partial_asset(asset, timestamp);
+                (asset_score, asset_trace)
+            }
+            /// `trace_asset` also returning the `Error` score of a failed asset, holding the
+            /// retained calcs that completed before the failure
+            fn trace_partial_asset(
+                &self,
+                asset: Asset,
+                timestamp: TimeStamp,
+            ) -> (GenResult<AssetScore>, Option<AssetScore>, AssetTrace) {
+                let started_at = Utc::now();
+                let started = Instant::now();
+                let mut scorable_asset = self.scorable_asset(asset.clone(), timestamp);
+                let executed = scorable_asset.execute();
+                let calcs = scorable_asset.calc_traces();
+                let status = scorable_asset.overall_status();
+                let failed = executed
+                    .as_ref()
+                    .err()
+                    .map(|_| AssetScore::failed(&scorable_asset));
+                let asset_score = executed.and_then(|_| AssetScore::new(scorable_asset));
+                let asset_trace = AssetTrace {
+                    symbol: asset.symbol().to_string(),
+                    timestamp,
+                    status: if asset_score.is_ok() {
+                        status
+                    } else {
+                        AssetScoreStatus::Error
+                    },
+                    started_at,
+                    duration: started.elapsed(),
+                    error: asset_score.as_ref().err().map(|e| e.to_string()),
+                    calcs,
+                };
+                match &asset_trace.error {
+                    Some(error) => warn!("{} as of {} failed: {}", asset, timestamp, error),
+                    None => debug!(
+                        "{} as of {} scored in {:?}",
+                        asset, timestamp, asset_trace.duration
+                    ),
+                }
+                (asset_score, failed, asset_trace)
+            }
+            fn scorable_asset(&self, asset: Asset, timestamp: TimeStamp) -> ScorableAsset {
+                ScorableAsset {
                     asset,
                     timestamp,
                     execution_order: self.dag.execution_order().clone(),
                     calcs: self.calcs.clone(),
+                    score_calc: self.strategy.score().calc().to_string(),
+                    named_scores: self
+                        .strategy
+                        .scores()
+                        .iter()
+                        .filter_map(|score| {
+                            score
+                                .name()
+                                .map(|name| (name.to_string(), score.calc().to_string()))
+                        })
+                        .collect(),
                     data_client: self.data_client.clone(),
+                    calendar: self.calendar.clone(),
+                    missing_data: self.strategy.missing_data(),
+                    invalid_values: self.strategy.invalid_values(),
+                    retain_calcs: self.retain_calcs.clone(),
                     calc_status: self
                         .calcs
                         .keys()
                         .map(|c| (c.clone(), CalculationStatus::NotStarted))
                         .collect(),
                     calc_time_series: HashMap::new(),
-                };
-                scorable_asset.execute()?;
-                Ok(AssetScore::new(scorable_asset)?)
+                    calc_frames: HashMap::new(),
+                    calc_traces: HashMap::new(),
+                }
+            }
+            /// Builds the `Query` of every QUERY calc for each of the given `Asset`s
+            pub fn queries(
+                &self,
+                assets: &[Asset],
+                timestamp: &TimeStamp,
+            ) -> GenResult<Vec<Query>> {
+                let mut queries = vec![];
+                for calc in self
+                    .calcs
+                    .values()
+                    .filter(|calc| *calc.operation() == Operation::QUERY)
+                {
+                    let query_dto: QueryCalculationDto = calc.clone().try_into()?;
+                    for asset in assets {
+                        queries.push(query_dto.build_query(
+                            asset,
+                            query_dto.series().to_string(),
+                            timestamp,
+                        )?);
+                    }
+                }
+                Ok(queries)
             }
             pub fn run_on_assets(
                 &self,
                 assets: Vec<Asset>,
                 timestamp: TimeStamp,
             ) -> GenResult<BTreeMap<Asset, AssetScore>> {
-                Ok(assets
-                    .iter()
-                    .flat_map(|asset| self.run_on_asset(asset.clone(), timestamp))
-                    .map(|asset_score| (asset_score.asset().clone(), asset_score))
+                let (asset_scores, _) = self.trace_assets(assets, timestamp)?;
+                Ok(asset_scores
+                    .into_iter()
+                    .filter(|(_, asset_score)| asset_score.status != AssetScoreStatus::Error)
                     .collect())
             }
+            /// Computes the scores of the given `Asset`s, tracing every asset. A failed asset
+            /// scores as `AssetScoreStatus::Error` without score values, keeping the retained
+            /// calcs that completed before the failure.
+            pub fn trace_assets(
+                &self,
+                assets: Vec<Asset>,
+                timestamp: TimeStamp,
+            ) -> GenResult<(BTreeMap<Asset, AssetScore>, RunTrace)> {
+                if let Err(e) = self
+                    .data_client
+                    .prefetch(self.queries(&assets, &timestamp)?)
+                {
+                    warn!("prefetch failed, querying assets one at a time: {}", e);
+                }
+                let mut asset_scores = BTreeMap::new();
+                let mut run_trace = RunTrace::new(self.strategy.name());
+                for asset in assets {
+                    let (asset_score, failed, asset_trace) =
+                        self.trace_partial_asset(asset.clone(), timestamp);
+                    let asset_score = asset_score.unwrap_or_else(|_| {
+                        failed.unwrap_or_else(|| {
+                            AssetScore::from_parts(
+                                asset.clone(),
+                                timestamp,
+                                TimeSeries1D::new(BTreeMap::new()),
+                                AssetScoreStatus::Error,
+                            )
+                        })
+                    });
+                    asset_scores.insert(asset, asset_score);
+                    run_trace.assets.push(asset_trace);
+                }
+                Ok((asset_scores, run_trace))
+            }
             pub fn run_on_all_assets(
                 &self,
                 timestamp: TimeStamp,
@@ -119,6 +343,73 @@ pub mod bot {
                     timestamp,
                 )
             }
+            /// Assets of the strategy universe as of `timestamp`
+            pub fn universe(&self, timestamp: TimeStamp) -> GenResult<Vec<Asset>> {
+                self.strategy
+                    .universe()
+                    .resolve(self.data_client.as_ref(), timestamp)
+            }
+            /// Computes the score of every `Asset` in the strategy universe as of `timestamp`
+            pub fn run_on_universe(
+                &self,
+                timestamp: TimeStamp,
+            ) -> GenResult<BTreeMap<Asset, AssetScore>> {
+                self.run_on_assets(self.universe(timestamp)?, timestamp)
+            }
+            /// Replays the strategy on every trading day from `first` to `last` inclusive.
+            ///
+            /// The back test score of an `Asset` holds its latest score as of each step.
+            pub fn back_test(
+                &self,
+                assets: Vec<Asset>,
+                first: TimeStamp,
+                last: TimeStamp,
+            ) -> GenResult<BTreeMap<Asset, TimeSeries1D>> {
+                self.back_test_every(assets, first, last, Frequency::Daily)
+            }
+            /// Replays the strategy on the last trading day of every `frequency` period, eg. to
+            /// back test monthly rebalancing.
+            pub fn back_test_every(
+                &self,
+                assets: Vec<Asset>,
+                first: TimeStamp,
+                last: TimeStamp,
+                frequency: Frequency,
+            ) -> GenResult<BTreeMap<Asset, TimeSeries1D>> {
+                Ok(self.trace_back_test(assets, first, last, frequency)?.0)
+            }
+            /// Back test tracing every asset at every rebalance day
+            pub fn trace_back_test(
+                &self,
+                assets: Vec<Asset>,
+                first: TimeStamp,
+                last: TimeStamp,
+                frequency: Frequency,
+            ) -> GenResult<(BTreeMap<Asset, TimeSeries1D>, RunTrace)> {
+                let mut back_test: BTreeMap<Asset, BTreeMap<TimeStamp, DataPointValue>> = assets
+                    .iter()
+                    .map(|asset| (asset.clone(), BTreeMap::new()))
+                    .collect();
+                // queries start at the epoch, so the series as of `last` serve every rebalance day
+                if let Err(e) = self.data_client.prefetch(self.queries(&assets, &last)?) {
+                    warn!("prefetch failed, querying assets one at a time: {}", e);
+                }
+                let mut run_trace = RunTrace::new(self.strategy.name());
+                for today in self.calendar.rebalance_days(first, last, frequency) {
+                    let (asset_scores, day_trace) = self.trace_assets(assets.clone(), today)?;
+                    for (asset, asset_score) in asset_scores {
+                        if let Some(score) = asset_score.score().values_slice().last() {
+                            back_test.entry(asset).or_default().insert(today, *score);
+                        }
+                    }
+                    run_trace.assets.extend(day_trace.assets);
+                }
+                let back_test = back_test
+                    .into_iter()
+                    .map(|(asset, scores)| (asset, TimeSeries1D::new(scores)))
+                    .collect();
+                Ok((back_test, run_trace))
+            }
         }
 
         impl Clone for RunnableStrategy {
@@ -135,9 +426,24 @@ pub mod bot {
             timestamp: TimeStamp,
             execution_order: Vec<TimeSeriesName>,
             calcs: HashMap<TimeSeriesName, CalculationDto>,
+            score_calc: TimeSeriesName,
+            /// Calcs of the named scores by name
+            named_scores: Vec<(String, TimeSeriesName)>,
             data_client: Box<dyn DataClient>,
+            calendar: TradingCalendar,
+            missing_data: MissingData,
+            invalid_values: InvalidValues,
+            retain_calcs: RetainCalcs,
             calc_status: HashMap<TimeSeriesName, CalculationStatus>,
             calc_time_series: HashMap<TimeSeriesName, TimeSeries1D>,
+            calc_frames: HashMap<TimeSeriesName, TimeSeriesFrame>,
+            calc_traces: HashMap<TimeSeriesName, CalcTrace>,
+        }
+
+        /// Output of a calc, the columns of a frame are referenced as `calc.column`
+        enum CalcOutput {
+            Series(TimeSeries1D),
+            Frame(TimeSeriesFrame),
         }
 
         impl ScorableAsset {
@@ -186,48 +492,150 @@ pub mod bot {
             }
 
             pub(crate) fn score(&self) -> GenResult<&TimeSeries1D> {
-                Ok(self.upstream(self.execution_order.last().expect("impossible"))?)
+                self.score_output(&self.score_calc)
+            }
+
+            /// Output of the calc of a score, which must be a series
+            fn score_output(&self, score_calc: &str) -> GenResult<&TimeSeries1D> {
+                match self.calc_frames.get(score_calc) {
+                    Some(frame) => Err(TimeSeriesError::new(format!(
+                        "score calc {} outputs the frame {:?}, score one of its columns",
+                        score_calc,
+                        frame.column_names()
+                    ))),
+                    None => Ok(self.upstream(score_calc)?),
+                }
             }
 
             /// Traverse `Dag` executing each node for given `Asset` as of `Timestamp`
             fn execute(&mut self) -> GenResult<()> {
                 let calc_order = self.execution_order.clone();
                 for calc_name in calc_order {
-                    // println!("\nexecuting {}", calc_name);
+                    trace!("executing {} of {}", calc_name, self.asset);
                     self.status(&calc_name, CalculationStatus::InProgress);
                     let calc = self.calcs.get(&calc_name).ok_or("calc not found")?;
-                    let calc_time_series = match calc.operation() {
-                        Operation::QUERY => self.handle_query(calc),
-                        Operation::ADD => self.handle_add(calc),
-                        Operation::SUB => self.handle_sub(calc),
-                        Operation::MUL => self.handle_mul(calc),
-                        Operation::DIV => self.handle_div(calc),
-                        Operation::TS_ADD => self.handle_ts_add(calc),
-                        Operation::TS_SUB => self.handle_ts_sub(calc),
-                        Operation::TS_MUL => self.handle_ts_mul(calc),
-                        Operation::TS_DIV => self.handle_ts_div(calc),
-                        Operation::SMA => self.handle_sma(calc),
+                    let mut calc_trace =
+                        CalcTrace::not_started(&calc_name, &format!("{:?}", calc.operation()));
+                    calc_trace.input_lens = calc
+                        .operands()
+                        .iter()
+                        .filter(|operand| operand._type() == &OperandType::Reference)
+                        .flat_map(|operand| {
+                            self.calc_time_series
+                                .get(operand.value())
+                                .map(|time_series| (operand.value().to_string(), time_series.len()))
+                        })
+                        .collect();
+                    calc_trace.started_at = Some(Utc::now());
+                    let started = Instant::now();
+                    let calc_output = match calc.operation() {
+                        Operation::QUERY => self.handle_query(calc).map(CalcOutput::Series),
+                        Operation::ADD => self.handle_add(calc).map(CalcOutput::Series),
+                        Operation::SUB => self.handle_sub(calc).map(CalcOutput::Series),
+                        Operation::MUL => self.handle_mul(calc).map(CalcOutput::Series),
+                        Operation::DIV => self.handle_div(calc).map(CalcOutput::Series),
+                        Operation::TS_ADD => self.handle_ts_add(calc).map(CalcOutput::Series),
+                        Operation::TS_SUB => self.handle_ts_sub(calc).map(CalcOutput::Series),
+                        Operation::TS_MUL => self.handle_ts_mul(calc).map(CalcOutput::Series),
+                        Operation::TS_DIV => self.handle_ts_div(calc).map(CalcOutput::Series),
+                        Operation::SMA => self.handle_sma(calc).map(CalcOutput::Series),
+                        Operation::RESAMPLE => self.handle_resample(calc).map(CalcOutput::Series),
+                        Operation::ASOF => self.handle_asof(calc).map(CalcOutput::Series),
+                        Operation::FILLNA => self.handle_fillna(calc).map(CalcOutput::Series),
+                        Operation::DROPNA => self.handle_dropna(calc).map(CalcOutput::Series),
+                        Operation::FRAME => self.handle_frame(calc).map(CalcOutput::Frame),
+                        Operation::BBANDS => self.handle_bbands(calc).map(CalcOutput::Frame),
+                    }
+                    .and_then(|calc_output| self.validate(&calc_name, calc_output));
+                    let calc_status = match &calc_output {
+                        Ok(CalcOutput::Series(time_series)) => {
+                            calc_trace.series_output(time_series);
+                            CalculationStatus::Complete
+                        }
+                        Ok(CalcOutput::Frame(frame)) => {
+                            calc_trace.frame_output(frame);
+                            CalculationStatus::Complete
+                        }
+                        Err(e) => {
+                            calc_trace.error = Some(e.to_string());
+                            CalculationStatus::Error
+                        }
                     };
-                    self.status(
-                        &calc_name,
-                        match calc_time_series.is_ok() {
-                            true => CalculationStatus::Complete,
-                            false => CalculationStatus::Error,
-                        },
+                    let elapsed = started.elapsed();
+                    calc_trace.finish(calc_status.clone(), elapsed);
+                    trace!(
+                        "{} of {}: {:?}, {} values in {:?}",
+                        calc_name,
+                        self.asset,
+                        calc_status,
+                        calc_trace.output_len.unwrap_or(0),
+                        elapsed
                     );
+                    self.calc_traces.insert(calc_name.clone(), calc_trace);
+                    self.status(&calc_name, calc_status);
 
-                    self.calc_time_series
-                        .insert(calc_name.clone(), calc_time_series?);
+                    match calc_output? {
+                        CalcOutput::Series(time_series) => {
+                            self.calc_time_series.insert(calc_name.clone(), time_series);
+                        }
+                        CalcOutput::Frame(frame) => {
+                            // columns are referenced as `calc.column`
+                            for (column, time_series) in frame.columns() {
+                                self.calc_time_series
+                                    .insert(column_reference(&calc_name, &column), time_series);
+                            }
+                            self.calc_frames.insert(calc_name.clone(), frame);
+                        }
+                    }
                 }
                 Ok(())
             }
+            /// Traces of every calc in execution order
+            fn calc_traces(&self) -> Vec<CalcTrace> {
+                self.execution_order
+                    .iter()
+                    .map(|calc_name| match self.calc_traces.get(calc_name) {
+                        Some(calc_trace) => calc_trace.clone(),
+                        None => CalcTrace::not_started(
+                            calc_name,
+                            &format!("{:?}", self.calcs[calc_name].operation()),
+                        ),
+                    })
+                    .collect()
+            }
+            /// Apply the invalid values policy to the output of `calc_name`, warning of the NaN and
+            /// infinite values it keeps or masks
+            fn validate(&self, calc_name: &str, calc_output: CalcOutput) -> GenResult<CalcOutput> {
+                let non_finite = match &calc_output {
+                    CalcOutput::Series(time_series) => time_series.count_non_finite(),
+                    CalcOutput::Frame(frame) => frame.count_non_finite(),
+                };
+                if non_finite > 0 && self.invalid_values != InvalidValues::Error {
+                    warn!(
+                        "{} of {}: {} values are not finite, invalid values policy {:?}",
+                        calc_name, self.asset, non_finite, self.invalid_values
+                    );
+                }
+                match calc_output {
+                    CalcOutput::Series(time_series) => time_series
+                        .validate(self.invalid_values)
+                        .map(CalcOutput::Series),
+                    CalcOutput::Frame(frame) => {
+                        frame.validate(self.invalid_values).map(CalcOutput::Frame)
+                    }
+                }
+                .map_err(|e| {
+                    TimeSeriesError::new(format!("{} of {}: {}", calc_name, self.asset, e))
+                        as GenError
+                })
+            }
             // TODO parameterized query: generalize market data retrieval
             fn handle_query(&self, calculation_dto: &CalculationDto) -> GenResult<TimeSeries1D> {
                 assert_eq!(*calculation_dto.operation(), Operation::QUERY);
                 let query_dto: QueryCalculationDto = calculation_dto.clone().try_into()?;
                 let query = query_dto.build_query(
                     &self.asset,
-                    query_dto.name().to_string(),
+                    query_dto.series().to_string(),
                     &self.timestamp,
                 )?;
                 Ok(self.data_client.query(query)?.clone())
@@ -258,54 +666,136 @@ pub mod bot {
                 let dyadic_scalar_calc_dto: DyadicScalarCalculationDto =
                     calculation_dto.clone().try_into()?;
                 let time_series = self.upstream(dyadic_scalar_calc_dto.time_series())?;
-                Ok(time_series.div(dyadic_scalar_calc_dto.scalar()))
+                match self.invalid_values {
+                    InvalidValues::Error => {
+                        time_series.checked_div(dyadic_scalar_calc_dto.scalar())
+                    }
+                    _ => Ok(time_series.div(dyadic_scalar_calc_dto.scalar())),
+                }
+            }
+            /// Operands of a `TS_*` calc aligned by its own or the strategy missing data policy
+            fn aligned(
+                &self,
+                dyadic_ts_calc_dto: &DyadicTsCalculationDto,
+            ) -> GenResult<(TimeSeries1D, TimeSeries1D)> {
+                let left_value = self.upstream(dyadic_ts_calc_dto.left())?;
+                let right_value = self.upstream(dyadic_ts_calc_dto.right())?;
+                let missing_data = dyadic_ts_calc_dto
+                    .missing_data()
+                    .unwrap_or(self.missing_data);
+                Ok(left_value.align(right_value, missing_data))
             }
             fn handle_ts_add(&self, calculation_dto: &CalculationDto) -> GenResult<TimeSeries1D> {
                 assert_eq!(*calculation_dto.operation(), Operation::TS_ADD);
                 let dyadic_ts_calc_dto: DyadicTsCalculationDto =
                     calculation_dto.clone().try_into()?;
-                let left_value = self.upstream(dyadic_ts_calc_dto.left())?;
-                let right_value = self.upstream(dyadic_ts_calc_dto.right())?;
-                Ok(left_value.ts_add(right_value))
+                let (left_value, right_value) = self.aligned(&dyadic_ts_calc_dto)?;
+                Ok(left_value.ts_add(&right_value))
             }
             fn handle_ts_sub(&self, calculation_dto: &CalculationDto) -> GenResult<TimeSeries1D> {
                 assert_eq!(*calculation_dto.operation(), Operation::TS_SUB);
                 let dyadic_ts_calc_dto: DyadicTsCalculationDto =
                     calculation_dto.clone().try_into()?;
-                let left_value = self.upstream(dyadic_ts_calc_dto.left())?;
-                let right_value = self.upstream(dyadic_ts_calc_dto.right())?;
-                Ok(left_value.ts_sub(right_value))
+                let (left_value, right_value) = self.aligned(&dyadic_ts_calc_dto)?;
+                Ok(left_value.ts_sub(&right_value))
             }
             fn handle_ts_mul(&self, calculation_dto: &CalculationDto) -> GenResult<TimeSeries1D> {
                 assert_eq!(*calculation_dto.operation(), Operation::TS_MUL);
                 let dyadic_ts_calc_dto: DyadicTsCalculationDto =
                     calculation_dto.clone().try_into()?;
-                let left_value = self.upstream(dyadic_ts_calc_dto.left())?;
-                let right_value = self.upstream(dyadic_ts_calc_dto.right())?;
-                Ok(left_value.ts_mul(right_value))
+                let (left_value, right_value) = self.aligned(&dyadic_ts_calc_dto)?;
+                Ok(left_value.ts_mul(&right_value))
             }
             fn handle_ts_div(&self, calculation_dto: &CalculationDto) -> GenResult<TimeSeries1D> {
                 assert_eq!(*calculation_dto.operation(), Operation::TS_DIV);
                 let dyadic_ts_calc_dto: DyadicTsCalculationDto =
                     calculation_dto.clone().try_into()?;
-                let left_value = self.upstream(dyadic_ts_calc_dto.left())?;
-                let right_value = self.upstream(dyadic_ts_calc_dto.right())?;
-                Ok(left_value.ts_div(right_value))
+                let (left_value, right_value) = self.aligned(&dyadic_ts_calc_dto)?;
+                match self.invalid_values {
+                    InvalidValues::Error => left_value.checked_ts_div(&right_value),
+                    _ => Ok(left_value.ts_div(&right_value)),
+                }
             }
             fn handle_sma(&self, calculation_dto: &CalculationDto) -> GenResult<TimeSeries1D> {
                 assert_eq!(*calculation_dto.operation(), Operation::SMA);
                 let sma_dto: SmaCalculationDto = calculation_dto.clone().try_into()?;
                 let time_series = self.upstream(sma_dto.time_series())?;
-                Ok(time_series.sma(sma_dto.window_size()))
+                // windows span trading days, not calendar days
+                let trading_days = self.calendar.trading_days_only(time_series);
+                match self.invalid_values {
+                    InvalidValues::Error => trading_days.checked_sma(sma_dto.window_size()),
+                    _ => Ok(trading_days.sma(sma_dto.window_size())),
+                }
+            }
+            fn handle_resample(&self, calculation_dto: &CalculationDto) -> GenResult<TimeSeries1D> {
+                assert_eq!(*calculation_dto.operation(), Operation::RESAMPLE);
+                let resample_dto: ResampleCalculationDto = calculation_dto.clone().try_into()?;
+                let time_series = self.upstream(resample_dto.time_series())?;
+                Ok(time_series.resample(resample_dto.frequency(), resample_dto.aggregation()))
+            }
+            fn handle_asof(&self, calculation_dto: &CalculationDto) -> GenResult<TimeSeries1D> {
+                assert_eq!(*calculation_dto.operation(), Operation::ASOF);
+                let asof_dto: AsofCalculationDto = calculation_dto.clone().try_into()?;
+                let time_series = self.upstream(asof_dto.time_series())?;
+                let index = self.upstream(asof_dto.index())?;
+                Ok(time_series.asof(index.index_slice()))
+            }
+            fn handle_frame(&self, calculation_dto: &CalculationDto) -> GenResult<TimeSeriesFrame> {
+                assert_eq!(*calculation_dto.operation(), Operation::FRAME);
+                let frame_dto: FrameCalculationDto = calculation_dto.clone().try_into()?;
+                let mut columns = vec![];
+                for (column, time_series) in frame_dto.columns() {
+                    columns.push((column.clone(), self.upstream(time_series)?.clone()));
+                }
+                TimeSeriesFrame::from_columns(
+                    columns,
+                    frame_dto.missing_data().unwrap_or(self.missing_data),
+                )
+            }
+            fn handle_bbands(
+                &self,
+                calculation_dto: &CalculationDto,
+            ) -> GenResult<TimeSeriesFrame> {
+                assert_eq!(*calculation_dto.operation(), Operation::BBANDS);
+                let bbands_dto: BbandsCalculationDto = calculation_dto.clone().try_into()?;
+                let time_series = self.upstream(bbands_dto.time_series())?;
+                // windows span trading days, not calendar days
+                let trading_days = self.calendar.trading_days_only(time_series);
+                if self.invalid_values == InvalidValues::Error {
+                    trading_days.checked_sma(bbands_dto.window_size())?;
+                }
+                Ok(trading_days.bollinger_bands(bbands_dto.window_size(), bbands_dto.num_std()))
+            }
+            fn handle_fillna(&self, calculation_dto: &CalculationDto) -> GenResult<TimeSeries1D> {
+                assert_eq!(*calculation_dto.operation(), Operation::FILLNA);
+                let fillna_dto: FillNaCalculationDto = calculation_dto.clone().try_into()?;
+                let time_series = self.upstream(fillna_dto.time_series())?;
+                Ok(match fillna_dto.value() {
+                    Some(value) => time_series.fillna_value(value),
+                    None => time_series.fillna(fillna_dto.missing_data()),
+                })
+            }
+            fn handle_dropna(&self, calculation_dto: &CalculationDto) -> GenResult<TimeSeries1D> {
+                assert_eq!(*calculation_dto.operation(), Operation::DROPNA);
+                let dropna_dto: DropNaCalculationDto = calculation_dto.clone().try_into()?;
+                let time_series = self.upstream(dropna_dto.time_series())?;
+                Ok(time_series.dropna())
             }
         }
 
-        #[derive(Debug)]
+        /// Score of an asset, serializable to save the scores of one run for another.
+        #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct AssetScore {
             asset: Asset,
             timestamp: TimeStamp,
             score: TimeSeries1D,
             status: AssetScoreStatus,
+            /// Named scores by name
+            #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+            scores: BTreeMap<String, TimeSeries1D>,
+            /// Intermediate series by calc, or `calc.column` for frames
+            #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+            calcs: BTreeMap<TimeSeriesReference, TimeSeries1D>,
         }
 
         impl AssetScore {
@@ -313,25 +803,79 @@ pub mod bot {
                 // TODO warn when overall_status is not Complete
                 let status = scorable_asset.overall_status();
                 let score = scorable_asset.score()?.clone();
+                let mut scores = BTreeMap::new();
+                for (name, score_calc) in &scorable_asset.named_scores {
+                    scores.insert(
+                        name.clone(),
+                        scorable_asset.score_output(score_calc)?.clone(),
+                    );
+                }
+                let retain_calcs = scorable_asset.retain_calcs;
                 Ok(AssetScore {
                     asset: scorable_asset.asset,
                     timestamp: scorable_asset.timestamp,
                     score,
                     status,
+                    scores,
+                    calcs: scorable_asset
+                        .calc_time_series
+                        .into_iter()
+                        .filter(|(reference, _)| retain_calcs.retains(reference))
+                        .collect(),
                 })
             }
+            /// Score of an asset whose calcs failed, keeping the retained calcs that completed
+            fn failed(scorable_asset: &ScorableAsset) -> AssetScore {
+                AssetScore {
+                    asset: scorable_asset.asset.clone(),
+                    timestamp: scorable_asset.timestamp,
+                    score: TimeSeries1D::new(BTreeMap::new()),
+                    status: AssetScoreStatus::Error,
+                    scores: BTreeMap::new(),
+                    calcs: scorable_asset
+                        .calc_time_series
+                        .iter()
+                        .filter(|(reference, _)| scorable_asset.retain_calcs.retains(reference))
+                        .map(|(reference, time_series)| (reference.clone(), time_series.clone()))
+                        .collect(),
+                }
+            }
+            /// Score loaded from elsewhere, eg. the output of a previous run
+            pub fn from_parts(
+                asset: Asset,
+                timestamp: TimeStamp,
+                score: TimeSeries1D,
+                status: AssetScoreStatus,
+            ) -> Self {
+                AssetScore {
+                    asset,
+                    timestamp,
+                    score,
+                    status,
+                    scores: BTreeMap::new(),
+                    calcs: BTreeMap::new(),
+                }
+            }
             pub fn asset(&self) -> &Asset {
                 &self.asset
             }
-            fn timestamp(&self) -> &TimeStamp {
+            pub fn timestamp(&self) -> &TimeStamp {
                 &self.timestamp
             }
             pub fn score(&self) -> &TimeSeries1D {
                 &self.score
             }
-            fn status(&self) -> &AssetScoreStatus {
+            pub fn status(&self) -> &AssetScoreStatus {
                 &self.status
             }
+            /// Named scores of the strategy, by name
+            pub fn scores(&self) -> &BTreeMap<String, TimeSeries1D> {
+                &self.scores
+            }
+            /// Intermediate series kept by `RunnableStrategy::set_retain_calcs`
+            pub fn calcs(&self) -> &BTreeMap<TimeSeriesReference, TimeSeries1D> {
+                &self.calcs
+            }
         }
 
         #[cfg(test)]
@@ -339,8 +883,10 @@ pub mod bot {
             use std::collections::{BTreeMap, HashMap};
             use std::path::Path;
 
+            use chrono::Duration;
+
             use crate::bot::asset_score::{
-                AssetScore, AssetScoreStatus, CalculationStatus, RunnableStrategy,
+                AssetScore, AssetScoreStatus, CalculationStatus, RetainCalcs, RunnableStrategy,
             };
             use crate::data::{Asset, DataClient};
             use crate::dto::strategy::{
@@ -348,8 +894,11 @@ pub mod bot {
                 StrategyDto,
             };
             use crate::errors::GenResult;
-            use crate::mock_client::MockDataClient;
-            use crate::time_series::DataPointValue;
+            use crate::mock_client::{MockDataClient, DATA_SIZE};
+            use crate::time_series::{
+                DataPointValue, Frequency, InvalidValues, MissingData, TimeSeries1D,
+            };
+            use crate::universe::{Universe, VolumeFilter};
 
             fn data_client_fixture() -> Box<dyn DataClient> {
                 Box::new(MockDataClient::new())
@@ -400,6 +949,393 @@ pub mod bot {
                 Ok(())
             }
 
+            #[test]
+            fn queries() -> GenResult<()> {
+                let runnable_strategy = compiled_strategy_fixture()?;
+                let assets = vec![Asset::new(String::from("A")), Asset::new(String::from("B"))];
+                let timestamp = MockDataClient::today();
+                let queries = runnable_strategy.queries(&assets, &timestamp)?;
+                // strategy.yaml has a single QUERY calc
+                assert_eq!(queries.len(), assets.len());
+                assert_eq!(queries[0].symbol(), "A");
+                assert_eq!(queries[1].symbol(), "B");
+                assert!(queries.iter().all(|q| q.last() == timestamp));
+                Ok(())
+            }
+
+            #[test]
+            fn back_test() -> GenResult<()> {
+                let runnable_strategy = compiled_strategy_fixture()?;
+                let assets = vec![Asset::new(String::from("A")), Asset::new(String::from("B"))];
+                let last = MockDataClient::today();
+                let first = last - TimeSeries1D::index_unit() * 2;
+                let back_test = runnable_strategy.back_test(assets, first, last)?;
+                assert_eq!(back_test.len(), 2);
+                back_test.values().for_each(|scores| {
+                    assert_eq!(scores.len(), 3);
+                    assert_eq!(scores.index().first(), Some(&first));
+                    assert_eq!(scores.index().last(), Some(&last));
+                });
+                Ok(())
+            }
+
+            #[test]
+            fn back_test_skips_weekends() -> GenResult<()> {
+                let runnable_strategy = compiled_strategy_fixture()?;
+                let assets = vec![Asset::new(String::from("C"))];
+                let last = MockDataClient::today();
+                let first = last - Duration::days(7);
+                let back_test = runnable_strategy.back_test(assets, first, last)?;
+                let scores = &back_test[&Asset::new(String::from("C"))];
+                // a week and a day holds 6 trading days
+                assert_eq!(scores.len(), 6);
+                let calendar = runnable_strategy.calendar();
+                assert!(scores
+                    .index()
+                    .iter()
+                    .all(|today| calendar.is_trading_day(calendar.local_date(today))));
+                Ok(())
+            }
+
+            #[test]
+            fn back_test_monthly() -> GenResult<()> {
+                let runnable_strategy = compiled_strategy_fixture()?;
+                let assets = vec![Asset::new(String::from("C"))];
+                let last = MockDataClient::today();
+                let first = last - Duration::days(90);
+                let back_test =
+                    runnable_strategy.back_test_every(assets, first, last, Frequency::Monthly)?;
+                let scores = &back_test[&Asset::new(String::from("C"))];
+                // 3 month ends and today
+                assert_eq!(scores.len(), 4);
+                assert_eq!(scores.index().last(), Some(&last));
+                Ok(())
+            }
+
+            #[test]
+            fn resample_then_asof() -> GenResult<()> {
+                let reference = |name: &str, value: &str| {
+                    OperandDto::new(
+                        String::from(name),
+                        OperandType::Reference,
+                        String::from(value),
+                    )
+                };
+                let text = |name: &str, value: &str| {
+                    OperandDto::new(String::from(name), OperandType::Text, String::from(value))
+                };
+                let strategy = StrategyDto::new(
+                    String::from("Weekly Strategy Document"),
+                    ScoreDto::new(String::from("weekly_on_daily")),
+                    vec![
+                        CalculationDto::new(
+                            String::from("price"),
+                            Operation::QUERY,
+                            vec![text("field", "close")],
+                        ),
+                        CalculationDto::new(
+                            String::from("weekly"),
+                            Operation::RESAMPLE,
+                            vec![
+                                reference("time_series", "price"),
+                                text("frequency", "WEEKLY"),
+                                text("aggregation", "MEAN"),
+                            ],
+                        ),
+                        CalculationDto::new(
+                            String::from("weekly_on_daily"),
+                            Operation::ASOF,
+                            vec![
+                                reference("time_series", "weekly"),
+                                reference("index", "price"),
+                            ],
+                        ),
+                    ],
+                );
+                let runnable_strategy = RunnableStrategy::new(strategy, data_client_fixture())?;
+                let asset_score = runnable_strategy
+                    .run_on_asset(Asset::new(String::from("C")), MockDataClient::today())?;
+                assert_eq!(asset_score.status, AssetScoreStatus::Complete);
+                // the first week of mock data is a lone Friday
+                assert_eq!(asset_score.score().len(), DATA_SIZE);
+                let values = asset_score.score().values();
+                // a weekly mean is only known from the Friday closing its week
+                assert_eq!(values[0], values[4]);
+                assert_ne!(values[4], values[5]);
+                Ok(())
+            }
+
+            fn weekly_gap_strategy(
+                gap_operands: Vec<OperandDto>,
+                score: CalculationDto,
+            ) -> StrategyDto {
+                let reference = |name: &str, value: &str| {
+                    OperandDto::new(
+                        String::from(name),
+                        OperandType::Reference,
+                        String::from(value),
+                    )
+                };
+                let text = |name: &str, value: &str| {
+                    OperandDto::new(String::from(name), OperandType::Text, String::from(value))
+                };
+                let mut gap_operands = gap_operands;
+                gap_operands.push(reference("left", "price"));
+                gap_operands.push(reference("right", "weekly"));
+                StrategyDto::new(
+                    String::from("Weekly Gap Strategy Document"),
+                    ScoreDto::new(score.name().to_string()),
+                    vec![
+                        CalculationDto::new(
+                            String::from("price"),
+                            Operation::QUERY,
+                            vec![text("field", "close")],
+                        ),
+                        CalculationDto::new(
+                            String::from("weekly"),
+                            Operation::RESAMPLE,
+                            vec![
+                                reference("time_series", "price"),
+                                text("frequency", "WEEKLY"),
+                            ],
+                        ),
+                        CalculationDto::new(String::from("gap"), Operation::TS_SUB, gap_operands),
+                        score,
+                    ],
+                )
+            }
+
+            fn score_of(strategy: StrategyDto) -> GenResult<TimeSeries1D> {
+                let runnable_strategy = RunnableStrategy::new(strategy, data_client_fixture())?;
+                let asset_score = runnable_strategy
+                    .run_on_asset(Asset::new(String::from("C")), MockDataClient::today())?;
+                assert_eq!(asset_score.status, AssetScoreStatus::Complete);
+                Ok(asset_score.score().clone())
+            }
+
+            #[test]
+            fn missing_data_policies() -> GenResult<()> {
+                let text = |name: &str, value: &str| {
+                    OperandDto::new(String::from(name), OperandType::Text, String::from(value))
+                };
+                let identity = |policy: Vec<OperandDto>| {
+                    let mut operands = vec![OperandDto::new(
+                        String::from("time_series"),
+                        OperandType::Reference,
+                        String::from("gap"),
+                    )];
+                    operands.extend(policy);
+                    CalculationDto::new(String::from("score"), Operation::FILLNA, operands)
+                };
+                let keep = || vec![text("missing_data", "OUTER")];
+                // weekly values only line up with the daily price on the last day of each week
+                let dropped = score_of(weekly_gap_strategy(vec![], identity(keep())))?;
+                let weeks = dropped.len();
+                assert!(weeks < DATA_SIZE);
+                assert!(dropped.values().iter().all(|gap| *gap == 0.));
+                // the strategy wide policy applies to TS_* calcs without their own
+                let mut strategy = weekly_gap_strategy(vec![], identity(keep()));
+                strategy.set_missing_data(MissingData::ForwardFill { limit: None });
+                let filled = score_of(strategy.clone())?;
+                assert_eq!(filled.len(), DATA_SIZE);
+                assert!(filled.values().iter().all(|gap| gap.is_finite()));
+                let outer = score_of(weekly_gap_strategy(keep(), identity(keep())))?;
+                assert_eq!(outer.len(), DATA_SIZE);
+                assert_eq!(
+                    outer.values().iter().filter(|gap| gap.is_nan()).count(),
+                    DATA_SIZE - weeks
+                );
+                // at most 2 days carried forward
+                let limited = score_of(weekly_gap_strategy(
+                    vec![
+                        text("missing_data", "FORWARD_FILL"),
+                        OperandDto::new(
+                            String::from("limit"),
+                            OperandType::Integer,
+                            String::from("2"),
+                        ),
+                    ],
+                    identity(keep()),
+                ))?;
+                assert!(limited.values().iter().any(|gap| gap.is_nan()));
+                assert!(
+                    limited.values().iter().filter(|gap| gap.is_nan()).count() < DATA_SIZE - weeks
+                );
+                // FILLNA and DROPNA repair the outer join
+                let zeroed = score_of(weekly_gap_strategy(
+                    keep(),
+                    identity(vec![OperandDto::new(
+                        String::from("value"),
+                        OperandType::Decimal,
+                        String::from("0"),
+                    )]),
+                ))?;
+                assert_eq!(zeroed.len(), DATA_SIZE);
+                assert!(zeroed.values().iter().all(|gap| *gap == 0.));
+                let dropna = CalculationDto::new(
+                    String::from("score"),
+                    Operation::DROPNA,
+                    vec![OperandDto::new(
+                        String::from("time_series"),
+                        OperandType::Reference,
+                        String::from("gap"),
+                    )],
+                );
+                assert_eq!(score_of(weekly_gap_strategy(keep(), dropna))?, dropped);
+                let invalid = weekly_gap_strategy(keep(), identity(vec![]));
+                let runnable_strategy = RunnableStrategy::new(invalid, data_client_fixture())?;
+                assert!(runnable_strategy
+                    .run_on_asset(Asset::new(String::from("C")), MockDataClient::today())
+                    .is_err());
+                Ok(())
+            }
+
+            #[test]
+            fn frame_columns() -> GenResult<()> {
+                let reference = |name: &str, value: &str| {
+                    OperandDto::new(
+                        String::from(name),
+                        OperandType::Reference,
+                        String::from(value),
+                    )
+                };
+                let bands_strategy = |score: Option<CalculationDto>| {
+                    let mut calcs = vec![
+                        CalculationDto::new(
+                            String::from("price"),
+                            Operation::QUERY,
+                            vec![OperandDto::new(
+                                String::from("field"),
+                                OperandType::Text,
+                                String::from("close"),
+                            )],
+                        ),
+                        CalculationDto::new(
+                            String::from("bbands"),
+                            Operation::BBANDS,
+                            vec![
+                                reference("time_series", "price"),
+                                OperandDto::new(
+                                    String::from("window_size"),
+                                    OperandType::Integer,
+                                    String::from("20"),
+                                ),
+                            ],
+                        ),
+                        CalculationDto::new(
+                            String::from("bar"),
+                            Operation::FRAME,
+                            vec![
+                                reference("close", "price"),
+                                reference("upper", "bbands.upper"),
+                                reference("lower", "bbands.lower"),
+                            ],
+                        ),
+                    ];
+                    calcs.extend(score);
+                    let score_calc = calcs.last().expect("calcs").name().to_string();
+                    StrategyDto::new(
+                        String::from("Bollinger Strategy Document"),
+                        ScoreDto::new(score_calc),
+                        calcs,
+                    )
+                };
+                let band_width = |lower: &str| {
+                    Some(CalculationDto::new(
+                        String::from("band_width"),
+                        Operation::TS_SUB,
+                        vec![reference("left", "bar.upper"), reference("right", lower)],
+                    ))
+                };
+                let runnable_strategy = RunnableStrategy::new(
+                    bands_strategy(band_width("bar.lower")),
+                    data_client_fixture(),
+                )?;
+                let execution_order = runnable_strategy.execution_order();
+                assert_eq!(execution_order[1..], ["bbands", "bar", "band_width"]);
+                let asset_score = runnable_strategy
+                    .run_on_asset(Asset::new(String::from("C")), MockDataClient::today())?;
+                assert_eq!(asset_score.status, AssetScoreStatus::Complete);
+                assert_eq!(asset_score.score().len(), DATA_SIZE - 19);
+                assert!(asset_score.score().values().iter().all(|width| *width > 0.));
+                // a frame is not a score
+                let runnable_strategy =
+                    RunnableStrategy::new(bands_strategy(None), data_client_fixture())?;
+                assert!(runnable_strategy
+                    .run_on_asset(Asset::new(String::from("C")), MockDataClient::today())
+                    .is_err());
+                // unknown columns are missing upstream series
+                let runnable_strategy = RunnableStrategy::new(
+                    bands_strategy(band_width("bar.open")),
+                    data_client_fixture(),
+                )?;
+                assert!(runnable_strategy
+                    .run_on_asset(Asset::new(String::from("C")), MockDataClient::today())
+                    .is_err());
+                Ok(())
+            }
+
+            #[test]
+            fn invalid_values_policies() -> GenResult<()> {
+                let divided_by_zero = |invalid_values: InvalidValues| {
+                    let mut strategy = StrategyDto::new(
+                        String::from("Zero Division Strategy Document"),
+                        ScoreDto::new(String::from("ratio")),
+                        vec![
+                            CalculationDto::new(
+                                String::from("price"),
+                                Operation::QUERY,
+                                vec![OperandDto::new(
+                                    String::from("field"),
+                                    OperandType::Text,
+                                    String::from("close"),
+                                )],
+                            ),
+                            CalculationDto::new(
+                                String::from("ratio"),
+                                Operation::DIV,
+                                vec![
+                                    OperandDto::new(
+                                        String::from("time_series"),
+                                        OperandType::Reference,
+                                        String::from("price"),
+                                    ),
+                                    OperandDto::new(
+                                        String::from("scalar"),
+                                        OperandType::Decimal,
+                                        String::from("0"),
+                                    ),
+                                ],
+                            ),
+                        ],
+                    );
+                    strategy.set_invalid_values(invalid_values);
+                    RunnableStrategy::new(strategy, data_client_fixture())
+                };
+                let asset = Asset::new(String::from("C"));
+                let timestamp = MockDataClient::today();
+                let kept =
+                    divided_by_zero(InvalidValues::Keep)?.run_on_asset(asset.clone(), timestamp)?;
+                assert_eq!(kept.score().count_non_finite(), DATA_SIZE);
+                assert!(divided_by_zero(InvalidValues::Mask)?
+                    .run_on_asset(asset.clone(), timestamp)?
+                    .score()
+                    .is_empty());
+                let mut runnable_strategy = divided_by_zero(InvalidValues::Error)?;
+                assert!(runnable_strategy
+                    .run_on_asset(asset.clone(), timestamp)
+                    .is_err());
+                // the calcs completed before the failure are kept
+                runnable_strategy.set_retain_calcs(RetainCalcs::All)?;
+                let (asset_scores, _) =
+                    runnable_strategy.trace_assets(vec![asset.clone()], timestamp)?;
+                let failed = &asset_scores[&asset];
+                assert_eq!(failed.status(), &AssetScoreStatus::Error);
+                assert!(failed.score().is_empty());
+                assert_eq!(failed.calcs().keys().collect::<Vec<_>>(), vec!["price"]);
+                Ok(())
+            }
+
             #[test]
             fn run_on_all_assets() -> GenResult<()> {
                 let runnable_strategy = compiled_strategy_fixture()?;
@@ -411,6 +1347,79 @@ pub mod bot {
                 });
                 Ok(())
             }
+
+            #[test]
+            fn retain_calcs() -> GenResult<()> {
+                let mut runnable_strategy = compiled_strategy_fixture()?;
+                let asset = Asset::new(String::from("A"));
+                let timestamp = MockDataClient::today();
+                let asset_score = runnable_strategy.run_on_asset(asset.clone(), timestamp)?;
+                assert!(asset_score.calcs().is_empty());
+
+                runnable_strategy.set_retain_calcs(RetainCalcs::All)?;
+                let asset_score = runnable_strategy.run_on_asset(asset.clone(), timestamp)?;
+                assert_eq!(asset_score.calcs().len(), 5);
+                assert_eq!(asset_score.calcs()["price"].len(), DATA_SIZE);
+                assert_eq!(asset_score.calcs()["sma_gap"], *asset_score.score());
+
+                runnable_strategy.set_retain_calcs(RetainCalcs::Only(vec![
+                    String::from("sma50"),
+                    String::from("sma200"),
+                ]))?;
+                let asset_score = runnable_strategy.run_on_asset(asset, timestamp)?;
+                let retained: Vec<&String> = asset_score.calcs().keys().collect();
+                assert_eq!(retained, vec!["sma200", "sma50"]);
+                assert_eq!(asset_score.calcs()["sma50"].len(), DATA_SIZE - 49);
+                assert!(runnable_strategy
+                    .set_retain_calcs(RetainCalcs::Only(vec![String::from("sma20")]))
+                    .is_err());
+                Ok(())
+            }
+
+            #[test]
+            fn named_scores() -> GenResult<()> {
+                let mut strategy = from_path(Path::new("strategy.yaml"))?;
+                strategy.scores_mut().push(ScoreDto::named(
+                    String::from("spread"),
+                    String::from("sma_diff"),
+                ));
+                let runnable_strategy =
+                    RunnableStrategy::new(strategy, Box::new(MockDataClient::new()))?;
+                let asset_score = runnable_strategy
+                    .run_on_asset(Asset::new(String::from("A")), MockDataClient::today())?;
+                assert_eq!(asset_score.scores().len(), 1);
+                assert_eq!(asset_score.scores()["spread"].len(), DATA_SIZE - 199);
+                // the primary score is still sma_gap
+                assert_ne!(asset_score.scores()["spread"], *asset_score.score());
+                Ok(())
+            }
+
+            #[test]
+            fn run_on_universe() -> GenResult<()> {
+                let mut strategy = strategy_fixture();
+                strategy.set_universe(Universe {
+                    exclude: vec![String::from("B")],
+                    ..Universe::default()
+                });
+                let runnable_strategy = RunnableStrategy::new(strategy, data_client_fixture())?;
+                let asset_scores = runnable_strategy.run_on_universe(MockDataClient::today())?;
+                let symbols: Vec<&str> = asset_scores.keys().map(|asset| asset.symbol()).collect();
+                assert_eq!(symbols, vec!["A", "C"]);
+                Ok(())
+            }
+
+            #[test]
+            fn zero_volume_window() {
+                let mut strategy = strategy_fixture();
+                strategy.set_universe(Universe {
+                    min_average_volume: Some(VolumeFilter {
+                        window: 0,
+                        ..VolumeFilter::default()
+                    }),
+                    ..Universe::default()
+                });
+                assert!(RunnableStrategy::new(strategy, data_client_fixture()).is_err());
+            }
         }
     }
 }
@@ -422,17 +1431,13 @@ pub mod dto {
         use core::fmt;
         use std::collections::HashMap;
         use std::convert::{TryFrom, TryInto};
-        use std::env::current_dir;
         use std::fmt::Formatter;
-        use std::fs::File;
-        use std::io::Write;
 
         use petgraph::algo::{connected_components, is_cyclic_directed, toposort};
-        use petgraph::dot::{Config, Dot};
         use petgraph::graph::{DiGraph, NodeIndex};
         use petgraph::Direction;
 
-        use crate::dto::strategy::{OperandType, StrategyDto};
+        use crate::dto::strategy::{referenced_calc, OperandType, StrategyDto, COLUMN_SEPARATOR};
         use crate::errors::{GenError, GenResult, InvalidStrategyError};
 
         /// Directed acyclic graph where vertices/nodes represent calculations and edges represent dependencies.
@@ -470,45 +1475,58 @@ pub mod dto {
                     .map(|x| self.dag_dto.node_weight(x).expect("node not found").clone())
                     .collect()
             }
-            fn save_dot_file(&self) {
-                let mut output_file = File::create(
-                    current_dir()
-                        .expect("unable to find current_dir")
-                        .join("output.dot"),
-                )
-                .expect("unable to open output file");
-                let dot_text = format!(
-                    "{:?}",
-                    Dot::with_config(&self.dag_dto, &[Config::EdgeNoLabel])
-                );
-                output_file
-                    .write_all(dot_text.as_bytes())
-                    .expect("unable to write file");
-                output_file.sync_all().unwrap();
-            }
         }
 
         impl TryFrom<StrategyDto> for DiGraph<String, String> {
             type Error = GenError;
             fn try_from(strategy: StrategyDto) -> GenResult<Self> {
+                if !strategy.imports().is_empty() || !strategy.composite().is_empty() {
+                    return Err(InvalidStrategyError::new(
+                        strategy.name().to_string(),
+                        String::from("imports and composite are resolved by from_path"),
+                    ));
+                }
+                if !strategy.has_score() {
+                    return Err(InvalidStrategyError::new(
+                        strategy.name().to_string(),
+                        String::from("no score, add score or scores"),
+                    ));
+                }
                 let mut dag: DiGraph<String, String> = DiGraph::new();
                 let mut node_lookup = HashMap::new();
 
                 // add nodes
                 for calc in strategy.calcs() {
                     // println!("{}", calc.name());
+                    if calc.name().contains(COLUMN_SEPARATOR) {
+                        return Err(InvalidStrategyError::new(
+                            strategy.name().to_string(),
+                            format!(
+                                "calc name {} contains the column separator {}",
+                                calc.name(),
+                                COLUMN_SEPARATOR
+                            ),
+                        ));
+                    }
                     let index = dag.add_node(calc.name().to_string());
-                    node_lookup.insert(calc.name(), index);
+                    if node_lookup.insert(calc.name(), index).is_some() {
+                        return Err(InvalidStrategyError::new(
+                            strategy.name().to_string(),
+                            format!("calc {} is defined more than once", calc.name()),
+                        ));
+                    }
                 }
-                // add edges
+                // add edges, a frame column reference depends on the calc outputting the frame
                 for calc in strategy.calcs() {
                     for op in calc.operands() {
-                        if node_lookup.contains_key(op.value())
+                        let referenced_calc = referenced_calc(op.value());
+                        if node_lookup.contains_key(referenced_calc)
                             && op._type() == &OperandType::Reference
                         {
-                            let operand = node_lookup.get(op.value()).expect("operand not found");
+                            let operand =
+                                node_lookup.get(referenced_calc).expect("operand not found");
                             let calc = node_lookup.get(calc.name()).expect("calc not found");
-                            dag.add_edge(*operand, *calc, String::new());
+                            dag.update_edge(*operand, *calc, String::new());
                         }
                     }
                 }
@@ -536,7 +1554,6 @@ pub mod dto {
         mod tests {
             use std::collections::HashMap;
             use std::convert::TryInto;
-            use std::fs::read_to_string;
             use std::path::Path;
 
             use petgraph::algo::toposort;
@@ -558,7 +1575,6 @@ pub mod dto {
             fn strategy_to_dag() -> GenResult<()> {
                 let strategy = strategy_fixture();
                 let dag = Dag::new(strategy)?;
-                dag.save_dot_file();
                 let dag_dto = dag.dag_dto;
                 assert_eq!(dag_dto.node_count(), 5);
                 assert_eq!(dag_dto.edge_count(), 6);
@@ -600,14 +1616,44 @@ pub mod dto {
             }
 
             #[test]
-            fn dag_to_dot_file() -> GenResult<()> {
-                let strategy = strategy_fixture();
-                let dag = Dag::new(strategy)?;
-                dag.save_dot_file();
-                assert_eq!(
-                    read_to_string("output.dot").expect("output.dot not found."),
-                    read_to_string("expected_output.dot").expect("expected_output.dot not found.")
-                );
+            fn frame_column_edges() -> GenResult<()> {
+                let strategy_yaml = r#"
+name: Bollinger Strategy Document
+score:
+  calc: band_width
+calcs:
+  - name: price
+    operation: QUERY
+    operands:
+      - name: field
+        type: Text
+        value: close
+  - name: bbands
+    operation: BBANDS
+    operands:
+      - name: time_series
+        type: Reference
+        value: price
+      - name: window_size
+        type: Integer
+        value: "20"
+  - name: band_width
+    operation: TS_SUB
+    operands:
+      - name: left
+        type: Reference
+        value: bbands.upper
+      - name: right
+        type: Reference
+        value: bbands.lower"#;
+                let strategy: StrategyDto = serde_yaml::from_str(strategy_yaml)?;
+                let dag = Dag::new(strategy)?;
+                assert_eq!(dag.execution_order(), vec!["price", "bbands", "band_width"]);
+                assert_eq!(dag.upstream(&String::from("band_width")), vec!["bbands"]);
+                let strategy: StrategyDto = serde_yaml::from_str(
+                    &strategy_yaml.replace("name: price", "name: price.close"),
+                )?;
+                assert!(Dag::new(strategy).is_err());
                 Ok(())
             }
 
@@ -697,26 +1743,97 @@ pub mod dto {
     }
 
     pub mod strategy {
-        use std::borrow::BorrowMut;
+        use std::collections::HashSet;
         use std::convert::TryFrom;
-        use std::fs::File;
-        use std::io::Read;
-        use std::path::Path;
+        use std::fs;
+        use std::path::{Path, PathBuf};
 
         use serde::{Deserialize, Serialize};
 
+        use crate::composite::{compose, ComponentDto};
         use crate::data::{epoch, Asset, Query, Series};
-        use crate::errors::{GenError, GenResult};
-        use crate::time_series::{DataPointValue, TimeStamp};
+        use crate::dto::dag::Dag;
+        use crate::errors::{GenError, GenResult, InvalidStrategyError};
+        use crate::library::{import_calcs, ImportDto};
+        use crate::time_series::{
+            Aggregation, DataPointValue, Frequency, InvalidValues, MissingData, TimeStamp,
+        };
+        use crate::universe::Universe;
 
         pub type TimeSeriesReference = String;
         pub type TimeSeriesName = String;
 
+        /// Separates the calc from the column in a reference to a frame column, eg. `bbands.upper`
+        pub const COLUMN_SEPARATOR: char = '.';
+
+        /// Reference to the `column` of the frame output by `calc_name`
+        pub fn column_reference(calc_name: &str, column: &str) -> TimeSeriesReference {
+            format!("{}{}{}", calc_name, COLUMN_SEPARATOR, column)
+        }
+
+        /// Name of the calc a reference depends on, `bbands` for `bbands.upper`
+        pub fn referenced_calc(reference: &str) -> &str {
+            reference
+                .split(COLUMN_SEPARATOR)
+                .next()
+                .expect("split yields at least 1 item")
+        }
+
+        /// Separates a namespace from the calcs within it, eg. `trend/sma50`
+        pub const NAMESPACE_SEPARATOR: char = '/';
+
+        /// `reference` within `namespace`, `trend/bbands.upper` for `bbands.upper`
+        pub fn namespaced(namespace: &str, reference: &str) -> TimeSeriesReference {
+            format!("{}{}{}", namespace, NAMESPACE_SEPARATOR, reference)
+        }
+
+        /// `calc` moved into `namespace`, along with the calcs it references
+        pub fn namespace_calc(namespace: &str, calc: &CalculationDto) -> CalculationDto {
+            let operands = calc
+                .operands()
+                .iter()
+                .map(|operand| match operand._type() {
+                    OperandType::Reference => OperandDto::new(
+                        operand.name().to_string(),
+                        OperandType::Reference,
+                        namespaced(namespace, operand.value()),
+                    ),
+                    _ => operand.clone(),
+                })
+                .collect();
+            CalculationDto::new(
+                namespaced(namespace, calc.name()),
+                calc.operation().clone(),
+                operands,
+            )
+        }
+
         #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
         pub struct StrategyDto {
             name: String,
-            score: ScoreDto,
+            /// Primary score, the first of `scores` when left out
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            score: Option<ScoreDto>,
+            /// Named scores output along with the primary score
+            #[serde(default, skip_serializing_if = "Vec::is_empty")]
+            scores: Vec<ScoreDto>,
+            /// Strategies blended into this one by `from_path`
+            #[serde(default, skip_serializing_if = "Vec::is_empty")]
+            composite: Vec<ComponentDto>,
+            /// Calc libraries the calcs reference, resolved by `from_path`
+            #[serde(default, skip_serializing_if = "Vec::is_empty")]
+            imports: Vec<ImportDto>,
+            #[serde(default)]
             calcs: Vec<CalculationDto>,
+            /// Alignment of `TS_*` operands lacking a `missing_data` operand
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            missing_data: Option<MissingData>,
+            /// Treatment of NaN and infinite values in the output of every calc
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            invalid_values: Option<InvalidValues>,
+            /// Assets scored, every asset by default
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            universe: Option<Universe>,
         }
 
         impl StrategyDto {
@@ -725,26 +1842,217 @@ pub mod dto {
                     .iter()
                     .find(|c| c.name == score.calc)
                     .expect("Invalid strategy, score calc not found");
-                StrategyDto { name, score, calcs }
+                StrategyDto {
+                    name,
+                    score: Some(score),
+                    scores: vec![],
+                    composite: vec![],
+                    imports: vec![],
+                    calcs,
+                    missing_data: None,
+                    invalid_values: None,
+                    universe: None,
+                }
             }
-            pub(crate) fn name(&self) -> &str {
+            pub fn name(&self) -> &str {
                 &self.name
             }
+            /// Primary score, checked to exist by `Dag::new`
             pub(crate) fn score(&self) -> &ScoreDto {
-                &self.score
+                self.score
+                    .as_ref()
+                    .or_else(|| self.scores.first())
+                    .expect("strategy without score")
+            }
+            pub(crate) fn has_score(&self) -> bool {
+                self.score.is_some() || !self.scores.is_empty()
+            }
+            pub(crate) fn set_score(&mut self, score: ScoreDto) {
+                self.score = Some(score);
             }
-            pub(crate) fn calcs(&self) -> &Vec<CalculationDto> {
+            /// Named scores, the primary score is among them when `score` is left out
+            pub(crate) fn scores(&self) -> &Vec<ScoreDto> {
+                &self.scores
+            }
+            pub(crate) fn scores_mut(&mut self) -> &mut Vec<ScoreDto> {
+                &mut self.scores
+            }
+            /// Names of the named scores
+            pub fn score_names(&self) -> Vec<&str> {
+                self.scores
+                    .iter()
+                    .filter_map(|score| score.name())
+                    .collect()
+            }
+            pub fn composite(&self) -> &Vec<ComponentDto> {
+                &self.composite
+            }
+            pub(crate) fn take_composite(&mut self) -> Vec<ComponentDto> {
+                std::mem::take(&mut self.composite)
+            }
+            pub fn imports(&self) -> &Vec<ImportDto> {
+                &self.imports
+            }
+            pub(crate) fn take_imports(&mut self) -> Vec<ImportDto> {
+                std::mem::take(&mut self.imports)
+            }
+            pub fn calcs(&self) -> &Vec<CalculationDto> {
                 &self.calcs
             }
+            pub(crate) fn calcs_mut(&mut self) -> &mut Vec<CalculationDto> {
+                &mut self.calcs
+            }
+            /// Strategy wide missing data policy, `MissingData::Drop` by default
+            pub fn missing_data(&self) -> MissingData {
+                self.missing_data.unwrap_or_default()
+            }
+            pub fn set_missing_data(&mut self, missing_data: MissingData) {
+                self.missing_data = Some(missing_data);
+            }
+            /// Strategy wide invalid values policy, `InvalidValues::Keep` by default
+            pub fn invalid_values(&self) -> InvalidValues {
+                self.invalid_values.unwrap_or_default()
+            }
+            pub fn set_invalid_values(&mut self, invalid_values: InvalidValues) {
+                self.invalid_values = Some(invalid_values);
+            }
+            /// Assets the strategy is scored on, every asset by default
+            pub fn universe(&self) -> Universe {
+                self.universe.clone().unwrap_or_default()
+            }
+            pub fn set_universe(&mut self, universe: Universe) {
+                self.universe = Some(universe);
+            }
         }
 
-        pub fn from_path(file_path: &Path) -> Result<StrategyDto, serde_yaml::Error> {
-            let mut strategy_file: File = File::open(file_path).expect("unable to open file");
-            let mut strategy_yaml = String::new();
-            strategy_file
-                .read_to_string(strategy_yaml.borrow_mut())
-                .expect("unable to read strategy file");
-            serde_yaml::from_str(&strategy_yaml)
+        /// Parse a strategy yaml file, importing the calcs of its `imports` and composing it when it
+        /// has a `composite` section
+        pub fn from_path(file_path: &Path) -> GenResult<StrategyDto> {
+            load(file_path, &mut vec![])
+        }
+
+        /// Parse a strategy yaml file, `loading` are the strategy and library files being loaded,
+        /// a file among them is a cycle
+        pub(crate) fn load(file_path: &Path, loading: &mut Vec<PathBuf>) -> GenResult<StrategyDto> {
+            let strategy_yaml = fs::read_to_string(file_path)
+                .map_err(|e| format!("unable to read {}: {}", file_path.display(), e))?;
+            let strategy: StrategyDto = serde_yaml::from_str(&strategy_yaml)?;
+            if strategy.imports().is_empty() && strategy.composite().is_empty() {
+                return Ok(strategy);
+            }
+            let canonical_path = file_path.canonicalize()?;
+            if loading.contains(&canonical_path) {
+                return Err(InvalidStrategyError::new(
+                    strategy.name().to_string(),
+                    format!("composite cycle {}", cycle(loading, &canonical_path)),
+                ));
+            }
+            loading.push(canonical_path);
+            let strategy_dir = file_path.parent().unwrap_or_else(|| Path::new(""));
+            let resolved = resolve(strategy, strategy_dir, loading);
+            loading.pop();
+            resolved
+        }
+
+        /// Import the calcs of `strategy`, then compose it
+        fn resolve(
+            mut strategy: StrategyDto,
+            strategy_dir: &Path,
+            loading: &mut Vec<PathBuf>,
+        ) -> GenResult<StrategyDto> {
+            import_calcs(&mut strategy, strategy_dir, loading)?;
+            if strategy.composite().is_empty() {
+                Ok(strategy)
+            } else {
+                compose(strategy, strategy_dir, loading)
+            }
+        }
+
+        /// Files from `path` on in `loading`, then `path` again
+        pub(crate) fn cycle(loading: &[PathBuf], path: &Path) -> String {
+            let cycle: Vec<String> = loading
+                .iter()
+                .skip_while(|loading_path| *loading_path != path)
+                .map(|loading_path| loading_path.as_path())
+                .chain(std::iter::once(path))
+                .map(|cycle_path| cycle_path.display().to_string())
+                .collect();
+            cycle.join(" -> ")
+        }
+
+        /// Check the dependencies between calcs and the operands of every calc, without running
+        /// the strategy.
+        pub fn validate(strategy: &StrategyDto) -> GenResult<()> {
+            let invalid = |reason: String| -> GenError {
+                InvalidStrategyError::new(strategy.name().to_string(), reason)
+            };
+            Dag::new(strategy.clone())?;
+            let names: HashSet<&str> = strategy.calcs().iter().map(|calc| calc.name()).collect();
+            if !names.contains(strategy.score().calc()) {
+                return Err(invalid(format!(
+                    "score calc {} not found",
+                    strategy.score().calc()
+                )));
+            }
+            let mut score_names: HashSet<&str> = HashSet::new();
+            for score in strategy.scores() {
+                let score_name = score.name().ok_or_else(|| {
+                    invalid(format!(
+                        "score of calc {} in scores has no name",
+                        score.calc()
+                    ))
+                })?;
+                if !score_names.insert(score_name) {
+                    return Err(invalid(format!(
+                        "score {} is defined more than once",
+                        score_name
+                    )));
+                }
+                if !names.contains(score.calc()) {
+                    return Err(invalid(format!(
+                        "calc {} of score {} not found",
+                        score.calc(),
+                        score_name
+                    )));
+                }
+            }
+            for calc in strategy.calcs() {
+                for operand in calc.operands() {
+                    if operand._type() == &OperandType::Reference
+                        && !names.contains(referenced_calc(operand.value()))
+                    {
+                        return Err(invalid(format!(
+                            "calc {} references unknown calc {}",
+                            calc.name(),
+                            operand.value()
+                        )));
+                    }
+                }
+                check_operands(calc)
+                    .map_err(|e| invalid(format!("calc {}: {}", calc.name(), e)))?;
+            }
+            strategy.universe().check(strategy.name())
+        }
+
+        /// Convert `calc` into the Dto of its operation
+        fn check_operands(calc: &CalculationDto) -> GenResult<()> {
+            let calc = calc.clone();
+            match calc.operation() {
+                Operation::QUERY => QueryCalculationDto::try_from(calc).map(|_| ()),
+                Operation::ADD | Operation::SUB | Operation::MUL | Operation::DIV => {
+                    DyadicScalarCalculationDto::try_from(calc).map(|_| ())
+                }
+                Operation::TS_ADD | Operation::TS_SUB | Operation::TS_MUL | Operation::TS_DIV => {
+                    DyadicTsCalculationDto::try_from(calc).map(|_| ())
+                }
+                Operation::SMA => SmaCalculationDto::try_from(calc).map(|_| ()),
+                Operation::RESAMPLE => ResampleCalculationDto::try_from(calc).map(|_| ()),
+                Operation::ASOF => AsofCalculationDto::try_from(calc).map(|_| ()),
+                Operation::FILLNA => FillNaCalculationDto::try_from(calc).map(|_| ()),
+                Operation::DROPNA => DropNaCalculationDto::try_from(calc).map(|_| ()),
+                Operation::FRAME => FrameCalculationDto::try_from(calc).map(|_| ()),
+                Operation::BBANDS => BbandsCalculationDto::try_from(calc).map(|_| ()),
+            }
         }
 
         #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
@@ -760,6 +2068,12 @@ pub mod dto {
             TS_MUL,
             TS_DIV,
             SMA,
+            RESAMPLE,
+            ASOF,
+            FILLNA,
+            DROPNA,
+            FRAME,
+            BBANDS,
         }
 
         const DYADIC_TIME_SERIES_OPERATIONS: &[Operation] = &[
@@ -786,12 +2100,24 @@ pub mod dto {
 
         #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
         pub(crate) struct ScoreDto {
+            /// Required in `scores`
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            name: Option<String>,
             calc: String,
         }
 
         impl ScoreDto {
             pub(crate) fn new(calc: String) -> Self {
-                ScoreDto { calc }
+                ScoreDto { name: None, calc }
+            }
+            pub(crate) fn named(name: String, calc: String) -> Self {
+                ScoreDto {
+                    name: Some(name),
+                    calc,
+                }
+            }
+            pub(crate) fn name(&self) -> Option<&str> {
+                self.name.as_deref()
             }
             pub(crate) fn calc(&self) -> &str {
                 &self.calc
@@ -903,6 +2229,7 @@ pub mod dto {
             name: String,
             left: TimeSeriesReference,
             right: TimeSeriesReference,
+            missing_data: Option<MissingData>,
         }
 
         impl DyadicTsCalculationDto {
@@ -915,6 +2242,32 @@ pub mod dto {
             pub fn right(&self) -> &TimeSeriesReference {
                 &self.right
             }
+            /// Overrides the strategy wide missing data policy
+            pub fn missing_data(&self) -> Option<MissingData> {
+                self.missing_data
+            }
+        }
+
+        /// Parse the optional `missing_data` operand and its `limit`, eg. `FORWARD_FILL` 5 times.
+        fn missing_data_operand(
+            calculation_dto: &CalculationDto,
+        ) -> GenResult<Option<MissingData>> {
+            let operand = |name: &str| calculation_dto.operands.iter().find(|o| o.name == name);
+            let missing_data = match operand("missing_data") {
+                Some(operand) => MissingData::try_from(operand.value.as_str())?,
+                None => return Ok(None),
+            };
+            match (missing_data, operand("limit")) {
+                (MissingData::ForwardFill { .. }, Some(limit)) => {
+                    Ok(Some(MissingData::ForwardFill {
+                        limit: Some(limit.value.parse()?),
+                    }))
+                }
+                (_, Some(_)) => Err(GenError::from(
+                    "limit only applies to missing_data FORWARD_FILL",
+                )),
+                (missing_data, None) => Ok(Some(missing_data)),
+            }
         }
 
         impl TryFrom<CalculationDto> for DyadicTsCalculationDto {
@@ -941,7 +2294,13 @@ pub mod dto {
                         .ok_or("Conversion into DyadicTsCalculationDto failed: right is required")?
                         .value
                         .clone();
-                    Ok(Self { name, left, right })
+                    let missing_data = missing_data_operand(&calculation_dto)?;
+                    Ok(Self {
+                        name,
+                        left,
+                        right,
+                        missing_data,
+                    })
                 }
             }
         }
@@ -1049,6 +2408,347 @@ pub mod dto {
             }
         }
 
+        pub struct ResampleCalculationDto {
+            name: String,
+            time_series: TimeSeriesReference,
+            frequency: Frequency,
+            aggregation: Aggregation,
+        }
+
+        impl ResampleCalculationDto {
+            pub fn name(&self) -> &str {
+                &self.name
+            }
+            pub fn time_series(&self) -> &TimeSeriesReference {
+                &self.time_series
+            }
+            pub fn frequency(&self) -> Frequency {
+                self.frequency
+            }
+            pub fn aggregation(&self) -> Aggregation {
+                self.aggregation
+            }
+        }
+
+        impl TryFrom<CalculationDto> for ResampleCalculationDto {
+            type Error = GenError;
+            fn try_from(calculation_dto: CalculationDto) -> GenResult<Self> {
+                if calculation_dto.operation != Operation::RESAMPLE {
+                    Err(GenError::from(
+                        "Conversion into ResampleCalculationDto failed",
+                    ))
+                } else {
+                    let name: String = calculation_dto.name.clone();
+                    let time_series: String = calculation_dto
+                        .operands
+                        .iter()
+                        .find(|o| o.name == "time_series")
+                        .ok_or(
+                            "Conversion into ResampleCalculationDto failed: time_series is required",
+                        )?
+                        .value
+                        .clone();
+                    let frequency = Frequency::try_from(
+                        calculation_dto
+                            .operands
+                            .iter()
+                            .find(|o| o.name == "frequency")
+                            .ok_or(
+                                "Conversion into ResampleCalculationDto failed: frequency is required",
+                            )?
+                            .value
+                            .as_str(),
+                    )?;
+                    // the latest value of each period, like a closing price, by default
+                    let aggregation = match calculation_dto
+                        .operands
+                        .iter()
+                        .find(|o| o.name == "aggregation")
+                    {
+                        Some(operand) => Aggregation::try_from(operand.value.as_str())?,
+                        None => Aggregation::Last,
+                    };
+                    Ok(Self {
+                        name,
+                        time_series,
+                        frequency,
+                        aggregation,
+                    })
+                }
+            }
+        }
+
+        pub struct FrameCalculationDto {
+            name: String,
+            columns: Vec<(String, TimeSeriesReference)>,
+            missing_data: Option<MissingData>,
+        }
+
+        impl FrameCalculationDto {
+            pub fn name(&self) -> &str {
+                &self.name
+            }
+            /// Column names and the series they hold, in operand order
+            pub fn columns(&self) -> &Vec<(String, TimeSeriesReference)> {
+                &self.columns
+            }
+            /// Overrides the strategy wide missing data policy
+            pub fn missing_data(&self) -> Option<MissingData> {
+                self.missing_data
+            }
+        }
+
+        impl TryFrom<CalculationDto> for FrameCalculationDto {
+            type Error = GenError;
+            fn try_from(calculation_dto: CalculationDto) -> GenResult<Self> {
+                if calculation_dto.operation != Operation::FRAME {
+                    Err(GenError::from("Conversion into FrameCalculationDto failed"))
+                } else {
+                    let name: String = calculation_dto.name.clone();
+                    // every Reference operand is a column named after the operand
+                    let columns: Vec<(String, TimeSeriesReference)> = calculation_dto
+                        .operands
+                        .iter()
+                        .filter(|o| o._type == OperandType::Reference)
+                        .map(|o| (o.name.clone(), o.value.clone()))
+                        .collect();
+                    if columns.is_empty() {
+                        return Err(GenError::from(
+                            "Conversion into FrameCalculationDto failed: a Reference column is required",
+                        ));
+                    }
+                    let missing_data = missing_data_operand(&calculation_dto)?;
+                    Ok(Self {
+                        name,
+                        columns,
+                        missing_data,
+                    })
+                }
+            }
+        }
+
+        pub struct BbandsCalculationDto {
+            name: String,
+            time_series: TimeSeriesReference,
+            window_size: usize,
+            num_std: DataPointValue,
+        }
+
+        impl BbandsCalculationDto {
+            pub fn name(&self) -> &str {
+                &self.name
+            }
+            pub fn time_series(&self) -> &TimeSeriesReference {
+                &self.time_series
+            }
+            pub fn window_size(&self) -> usize {
+                self.window_size
+            }
+            /// Distance of the bands from the sma in standard deviations, 2 by default
+            pub fn num_std(&self) -> DataPointValue {
+                self.num_std
+            }
+        }
+
+        impl TryFrom<CalculationDto> for BbandsCalculationDto {
+            type Error = GenError;
+            fn try_from(calculation_dto: CalculationDto) -> GenResult<Self> {
+                if calculation_dto.operation != Operation::BBANDS {
+                    Err(GenError::from(
+                        "Conversion into BbandsCalculationDto failed",
+                    ))
+                } else {
+                    let name: String = calculation_dto.name.clone();
+                    let time_series: TimeSeriesReference = calculation_dto
+                        .operands
+                        .iter()
+                        .find(|o| o.name == "time_series")
+                        .ok_or(
+                            "Conversion into BbandsCalculationDto failed: time_series is required",
+                        )?
+                        .value
+                        .clone();
+                    let window_size: usize = calculation_dto
+                        .operands
+                        .iter()
+                        .find(|o| o.name == "window_size")
+                        .ok_or(
+                            "Conversion into BbandsCalculationDto failed: window_size is required",
+                        )?
+                        .value
+                        .parse()?;
+                    let num_std: DataPointValue = match calculation_dto
+                        .operands
+                        .iter()
+                        .find(|o| o.name == "num_std")
+                    {
+                        Some(operand) => operand.value.parse()?,
+                        None => 2.,
+                    };
+                    Ok(Self {
+                        name,
+                        time_series,
+                        window_size,
+                        num_std,
+                    })
+                }
+            }
+        }
+
+        pub struct AsofCalculationDto {
+            name: String,
+            time_series: TimeSeriesReference,
+            index: TimeSeriesReference,
+        }
+
+        impl AsofCalculationDto {
+            pub fn name(&self) -> &str {
+                &self.name
+            }
+            /// Series forward filled onto `index`
+            pub fn time_series(&self) -> &TimeSeriesReference {
+                &self.time_series
+            }
+            /// Series whose index the result takes
+            pub fn index(&self) -> &TimeSeriesReference {
+                &self.index
+            }
+        }
+
+        impl TryFrom<CalculationDto> for AsofCalculationDto {
+            type Error = GenError;
+            fn try_from(calculation_dto: CalculationDto) -> GenResult<Self> {
+                if calculation_dto.operation != Operation::ASOF {
+                    Err(GenError::from("Conversion into AsofCalculationDto failed"))
+                } else {
+                    let name: String = calculation_dto.name.clone();
+                    let time_series: TimeSeriesReference = calculation_dto
+                        .operands
+                        .iter()
+                        .find(|o| o.name == "time_series")
+                        .ok_or(
+                            "Conversion into AsofCalculationDto failed: time_series is required",
+                        )?
+                        .value
+                        .clone();
+                    let index: TimeSeriesReference = calculation_dto
+                        .operands
+                        .iter()
+                        .find(|o| o.name == "index")
+                        .ok_or("Conversion into AsofCalculationDto failed: index is required")?
+                        .value
+                        .clone();
+                    Ok(Self {
+                        name,
+                        time_series,
+                        index,
+                    })
+                }
+            }
+        }
+
+        pub struct FillNaCalculationDto {
+            name: String,
+            time_series: TimeSeriesReference,
+            missing_data: MissingData,
+            value: Option<DataPointValue>,
+        }
+
+        impl FillNaCalculationDto {
+            pub fn name(&self) -> &str {
+                &self.name
+            }
+            pub fn time_series(&self) -> &TimeSeriesReference {
+                &self.time_series
+            }
+            pub fn missing_data(&self) -> MissingData {
+                self.missing_data
+            }
+            /// Constant replacing NaN, takes precedence over `missing_data`
+            pub fn value(&self) -> Option<DataPointValue> {
+                self.value
+            }
+        }
+
+        impl TryFrom<CalculationDto> for FillNaCalculationDto {
+            type Error = GenError;
+            fn try_from(calculation_dto: CalculationDto) -> GenResult<Self> {
+                if calculation_dto.operation != Operation::FILLNA {
+                    Err(GenError::from(
+                        "Conversion into FillNaCalculationDto failed",
+                    ))
+                } else {
+                    let name: String = calculation_dto.name.clone();
+                    let time_series: TimeSeriesReference = calculation_dto
+                        .operands
+                        .iter()
+                        .find(|o| o.name == "time_series")
+                        .ok_or(
+                            "Conversion into FillNaCalculationDto failed: time_series is required",
+                        )?
+                        .value
+                        .clone();
+                    let value: Option<DataPointValue> =
+                        match calculation_dto.operands.iter().find(|o| o.name == "value") {
+                            Some(operand) => Some(operand.value.parse()?),
+                            None => None,
+                        };
+                    let missing_data = match (missing_data_operand(&calculation_dto)?, value) {
+                        (Some(missing_data), _) => missing_data,
+                        (None, Some(_)) => MissingData::Outer,
+                        (None, None) => {
+                            return Err(GenError::from(
+                                "Conversion into FillNaCalculationDto failed: missing_data or value is required",
+                            ))
+                        }
+                    };
+                    Ok(Self {
+                        name,
+                        time_series,
+                        missing_data,
+                        value,
+                    })
+                }
+            }
+        }
+
+        pub struct DropNaCalculationDto {
+            name: String,
+            time_series: TimeSeriesReference,
+        }
+
+        impl DropNaCalculationDto {
+            pub fn name(&self) -> &str {
+                &self.name
+            }
+            pub fn time_series(&self) -> &TimeSeriesReference {
+                &self.time_series
+            }
+        }
+
+        impl TryFrom<CalculationDto> for DropNaCalculationDto {
+            type Error = GenError;
+            fn try_from(calculation_dto: CalculationDto) -> GenResult<Self> {
+                if calculation_dto.operation != Operation::DROPNA {
+                    Err(GenError::from(
+                        "Conversion into DropNaCalculationDto failed",
+                    ))
+                } else {
+                    let name: String = calculation_dto.name.clone();
+                    let time_series: TimeSeriesReference = calculation_dto
+                        .operands
+                        .iter()
+                        .find(|o| o.name == "time_series")
+                        .ok_or(
+                            "Conversion into DropNaCalculationDto failed: time_series is required",
+                        )?
+                        .value
+                        .clone();
+                    Ok(Self { name, time_series })
+                }
+            }
+        }
+
         #[cfg(test)]
         mod tests {
             use std::convert::TryInto;
@@ -1061,9 +2761,13 @@ pub mod dto {
             pub fn get_strategy() -> StrategyDto {
                 StrategyDto {
                     name: String::from("Example Strategy Document"),
-                    score: ScoreDto {
+                    score: Some(ScoreDto {
+                        name: None,
                         calc: String::from("sma_gap"),
-                    },
+                    }),
+                    scores: vec![],
+                    composite: vec![],
+                    imports: vec![],
                     calcs: vec![
                         CalculationDto {
                             name: String::from("sma_gap"),
@@ -1139,6 +2843,9 @@ pub mod dto {
                             }],
                         },
                     ],
+                    missing_data: None,
+                    invalid_values: None,
+                    universe: None,
                 }
             }
 
@@ -1199,7 +2906,7 @@ calcs:
             fn constructors() {
                 let s = get_strategy();
                 assert_eq!(s.name, "Example Strategy Document");
-                assert_eq!(s.score.calc, "sma_gap");
+                assert_eq!(s.score().calc(), "sma_gap");
                 assert_eq!(s.calcs[0].name, "sma_gap");
                 assert_eq!(s.calcs[0].operation, Operation::TS_DIV);
                 assert_eq!(s.calcs[0].operands[0].name, "left");
@@ -1271,8 +2978,110 @@ operands:
                 assert_eq!(sma.name, "sma200");
                 assert_eq!(sma.left, "foo");
                 assert_eq!(sma.right, "bar");
+                assert_eq!(sma.missing_data, None);
                 Ok(())
             }
+
+            #[test]
+            fn test_missing_data() -> GenResult<()> {
+                let x = r#"
+name: gap
+operation: TS_SUB
+operands:
+  - name: left
+    type: Reference
+    value: foo
+  - name: right
+    type: Reference
+    value: bar
+  - name: missing_data
+    type: Text
+    value: FORWARD_FILL
+  - name: limit
+    type: Integer
+    value: '3'"#;
+                let calc_dto: CalculationDto = serde_yaml::from_str(x)?;
+                let gap: DyadicTsCalculationDto = calc_dto.try_into()?;
+                assert_eq!(
+                    gap.missing_data,
+                    Some(MissingData::ForwardFill { limit: Some(3) })
+                );
+                let mut strategy = get_strategy();
+                assert_eq!(strategy.missing_data(), MissingData::Drop);
+                assert!(!serde_yaml::to_string(&strategy)?.contains("missing_data"));
+                strategy.set_missing_data(MissingData::Interpolate);
+                let yaml = serde_yaml::to_string(&strategy)?;
+                assert!(yaml.contains("missing_data:\n  policy: INTERPOLATE"));
+                let parsed: StrategyDto = serde_yaml::from_str(&yaml)?;
+                assert_eq!(parsed, strategy);
+                Ok(())
+            }
+
+            #[test]
+            fn test_to_fillna_dto() -> GenResult<()> {
+                let x = r#"
+name: filled
+operation: FILLNA
+operands:
+  - name: time_series
+    type: Reference
+    value: foo
+  - name: missing_data
+    type: Text
+    value: INTERPOLATE
+  - name: limit
+    type: Integer
+    value: '3'"#;
+                let calc_dto: CalculationDto = serde_yaml::from_str(x)?;
+                let fillna: GenResult<FillNaCalculationDto> = calc_dto.clone().try_into();
+                // limit only bounds forward fills
+                assert!(fillna.is_err());
+                let calc_dto = CalculationDto::new(
+                    calc_dto.name.clone(),
+                    Operation::FILLNA,
+                    calc_dto.operands[..2].to_vec(),
+                );
+                let fillna: FillNaCalculationDto = calc_dto.try_into()?;
+                assert_eq!(fillna.missing_data, MissingData::Interpolate);
+                assert_eq!(fillna.value, None);
+                Ok(())
+            }
+
+            #[test]
+            fn test_validate() {
+                let strategy = get_strategy();
+                assert!(validate(&strategy).is_ok());
+                let mut unknown_score = strategy.clone();
+                unknown_score.score = Some(ScoreDto::new(String::from("missing")));
+                assert!(validate(&unknown_score).is_err());
+                let mut named_scores = strategy.clone();
+                named_scores.scores = vec![
+                    ScoreDto::named(String::from("diff"), String::from("sma_diff")),
+                    ScoreDto::named(String::from("sma50"), String::from("sma50")),
+                ];
+                assert!(validate(&named_scores).is_ok());
+                named_scores.scores[1].name = Some(String::from("diff"));
+                let error = validate(&named_scores).unwrap_err().to_string();
+                assert!(
+                    error.contains("score diff is defined more than once"),
+                    "{}",
+                    error
+                );
+                named_scores.scores[1].name = None;
+                assert!(validate(&named_scores).is_err());
+                let mut duplicate_calc = strategy.clone();
+                duplicate_calc.calcs.push(strategy.calcs[1].clone());
+                let error = validate(&duplicate_calc).unwrap_err().to_string();
+                assert!(error.contains("defined more than once"), "{}", error);
+                let mut unknown_reference = strategy.clone();
+                unknown_reference.calcs[0].operands[1].value = String::from("sma51");
+                let error = validate(&unknown_reference).unwrap_err().to_string();
+                assert!(error.contains("unknown calc sma51"), "{}", error);
+                let mut bad_operand = strategy;
+                bad_operand.calcs[0].operands[1].name = String::from("denominator");
+                let error = validate(&bad_operand).unwrap_err().to_string();
+                assert!(error.contains("calc sma_gap"), "{}", error);
+            }
         }
     }
 }
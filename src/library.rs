@@ -0,0 +1,407 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use log::warn;
+use serde::{Deserialize, Serialize};
+
+use crate::dto::strategy::{
+    cycle, namespace_calc, referenced_calc, CalculationDto, OperandType, StrategyDto,
+    TimeSeriesName, COLUMN_SEPARATOR, NAMESPACE_SEPARATOR,
+};
+use crate::errors::{GenError, GenResult, InvalidStrategyError};
+
+/// Calc library imported by a strategy, an item of the `imports` section of a strategy yaml.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ImportDto {
+    /// Yaml file of calcs, relative to the importing yaml file
+    pub path: PathBuf,
+    /// Namespace of the imported calcs, the file stem of `path` by default
+    #[serde(default, rename = "as", skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+}
+
+impl ImportDto {
+    pub fn new(path: PathBuf) -> Self {
+        ImportDto {
+            path,
+            namespace: None,
+        }
+    }
+
+    pub fn namespace(&self) -> String {
+        match &self.namespace {
+            Some(namespace) => namespace.clone(),
+            None => self
+                .path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().to_string())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// Yaml file of calcs shared by strategies, which may import other libraries in turn. The calcs
+/// of a strategy yaml may be imported as well.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CalcLibraryDto {
+    #[serde(default)]
+    pub imports: Vec<ImportDto>,
+    #[serde(default)]
+    pub calcs: Vec<CalculationDto>,
+    /// Other top level keys, eg. the `score` of a strategy yaml
+    #[serde(flatten)]
+    pub other: BTreeMap<String, serde_yaml::Value>,
+}
+
+/// Keys of a strategy yaml ignored when importing its calcs
+const STRATEGY_KEYS: [&str; 6] = [
+    "name",
+    "score",
+    "scores",
+    "missing_data",
+    "invalid_values",
+    "universe",
+];
+
+/// Adds the imported calcs `strategy` depends on, each within the namespace of its import, eg.
+/// `indicators/sma50`. Imported calcs nothing depends on are left out.
+pub(crate) fn import_calcs(
+    strategy: &mut StrategyDto,
+    strategy_dir: &Path,
+    loading: &mut Vec<PathBuf>,
+) -> GenResult<()> {
+    let imports = strategy.take_imports();
+    if imports.is_empty() {
+        return Ok(());
+    }
+    let strategy_name = strategy.name().to_string();
+    let invalid =
+        |reason: String| -> GenError { InvalidStrategyError::new(strategy_name.clone(), reason) };
+    let imported =
+        imported_calcs(&imports, strategy_dir, loading).map_err(|e| invalid(e.to_string()))?;
+    let namespaces: HashSet<String> = imports.iter().map(|import| import.namespace()).collect();
+    let namespace_of = |name: &str| -> Option<String> {
+        let (namespace, _) = name.split_once(NAMESPACE_SEPARATOR)?;
+        namespaces.get(namespace).cloned()
+    };
+    for calc in strategy.calcs() {
+        if let Some(namespace) = namespace_of(calc.name()) {
+            return Err(invalid(format!(
+                "calc {} conflicts with the calcs imported as {}",
+                calc.name(),
+                namespace
+            )));
+        }
+    }
+    let by_name: HashMap<&str, &CalculationDto> =
+        imported.iter().map(|calc| (calc.name(), calc)).collect();
+    let mut needed: Vec<TimeSeriesName> = strategy
+        .calcs()
+        .iter()
+        .flat_map(references)
+        .chain(if strategy.has_score() {
+            Some(strategy.score().calc().to_string())
+        } else {
+            None
+        })
+        .chain(
+            strategy
+                .scores()
+                .iter()
+                .map(|score| score.calc().to_string()),
+        )
+        .collect();
+    let mut included: HashSet<TimeSeriesName> = HashSet::new();
+    while let Some(name) = needed.pop() {
+        let namespace = match namespace_of(&name) {
+            Some(namespace) => namespace,
+            None => continue,
+        };
+        if included.contains(&name) {
+            continue;
+        }
+        let calc = by_name.get(name.as_str()).ok_or_else(|| {
+            invalid(format!(
+                "import {} has no calc {}",
+                namespace,
+                &name[namespace.len() + 1..]
+            ))
+        })?;
+        needed.extend(references(calc));
+        included.insert(name);
+    }
+    strategy.calcs_mut().extend(
+        imported
+            .iter()
+            .filter(|calc| included.contains(calc.name()))
+            .cloned(),
+    );
+    Ok(())
+}
+
+/// Names of the calcs `calc` references
+fn references(calc: &CalculationDto) -> Vec<TimeSeriesName> {
+    calc.operands()
+        .iter()
+        .filter(|operand| operand._type() == &OperandType::Reference)
+        .map(|operand| referenced_calc(operand.value()).to_string())
+        .collect()
+}
+
+/// Calcs of every library, within the namespace of its import
+fn imported_calcs(
+    imports: &[ImportDto],
+    dir: &Path,
+    loading: &mut Vec<PathBuf>,
+) -> GenResult<Vec<CalculationDto>> {
+    let mut namespaces = HashSet::new();
+    let mut calcs = vec![];
+    for import in imports {
+        let namespace = import.namespace();
+        if namespace.is_empty()
+            || namespace.contains(COLUMN_SEPARATOR)
+            || namespace.contains(NAMESPACE_SEPARATOR)
+        {
+            return Err(GenError::from(format!(
+                "import {} needs a namespace without {} or {}",
+                import.path.display(),
+                COLUMN_SEPARATOR,
+                NAMESPACE_SEPARATOR
+            )));
+        }
+        if !namespaces.insert(namespace.clone()) {
+            return Err(GenError::from(format!(
+                "namespace {} is imported more than once, import each library as a distinct namespace",
+                namespace
+            )));
+        }
+        let library_calcs = load_library(&dir.join(&import.path), loading)
+            .map_err(|e| format!("import {}: {}", namespace, e))?;
+        calcs.extend(
+            library_calcs
+                .iter()
+                .map(|calc| namespace_calc(&namespace, calc)),
+        );
+    }
+    Ok(calcs)
+}
+
+/// Calcs of a library yaml file, along with the calcs it imports
+fn load_library(path: &Path, loading: &mut Vec<PathBuf>) -> GenResult<Vec<CalculationDto>> {
+    let canonical_path = path
+        .canonicalize()
+        .map_err(|e| format!("unable to read {}: {}", path.display(), e))?;
+    if loading.contains(&canonical_path) {
+        return Err(GenError::from(format!(
+            "import cycle {}",
+            cycle(loading, &canonical_path)
+        )));
+    }
+    let library: CalcLibraryDto = serde_yaml::from_str(&fs::read_to_string(path)?)?;
+    if library.other.contains_key("composite") {
+        return Err(GenError::from(format!(
+            "{} is a composite strategy, import its components instead",
+            path.display()
+        )));
+    }
+    for key in library.other.keys() {
+        if !STRATEGY_KEYS.contains(&key.as_str()) {
+            warn!("{}: unknown key {} ignored", path.display(), key);
+        }
+    }
+    let mut names = HashSet::new();
+    if let Some(calc) = library.calcs.iter().find(|calc| !names.insert(calc.name())) {
+        return Err(GenError::from(format!(
+            "calc {} is defined more than once",
+            calc.name()
+        )));
+    }
+    loading.push(canonical_path);
+    let library_dir = path.parent().unwrap_or_else(|| Path::new(""));
+    let imported = imported_calcs(&library.imports, library_dir, loading);
+    loading.pop();
+    let mut calcs = library.calcs;
+    calcs.extend(imported?);
+    Ok(calcs)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    use crate::bot::asset_score::RunnableStrategy;
+    use crate::data::Asset;
+    use crate::dto::strategy::from_path;
+    use crate::errors::GenResult;
+    use crate::mock_client::MockDataClient;
+
+    const PRICES_YAML: &str = r#"
+calcs:
+  - name: close
+    operation: QUERY
+    operands:
+      - name: field
+        type: Text
+        value: close
+"#;
+
+    const INDICATORS_YAML: &str = r#"
+imports:
+  - path: prices.yaml
+    as: prices
+calcs:
+  - name: sma20
+    operation: SMA
+    operands:
+      - name: window_size
+        type: Integer
+        value: "20"
+      - name: time_series
+        type: Reference
+        value: prices/close
+  - name: sma50
+    operation: SMA
+    operands:
+      - name: window_size
+        type: Integer
+        value: "50"
+      - name: time_series
+        type: Reference
+        value: prices/close
+  - name: sma200
+    operation: SMA
+    operands:
+      - name: window_size
+        type: Integer
+        value: "200"
+      - name: time_series
+        type: Reference
+        value: prices/close
+"#;
+
+    /// strategy.yaml with its sma and price calcs imported
+    const STRATEGY_YAML: &str = r#"
+name: Imported Strategy
+imports:
+  - path: lib/indicators.yaml
+score:
+  calc: sma_gap
+calcs:
+  - name: sma_gap
+    operation: TS_DIV
+    operands:
+      - name: left
+        type: Reference
+        value: sma_diff
+      - name: right
+        type: Reference
+        value: indicators/sma50
+  - name: sma_diff
+    operation: TS_SUB
+    operands:
+      - name: left
+        type: Reference
+        value: indicators/sma50
+      - name: right
+        type: Reference
+        value: indicators/sma200
+"#;
+
+    /// Directory of strategy files, with the indicators library in `lib`
+    fn strategy_dir(test: &str, files: &[(&str, &str)]) -> GenResult<PathBuf> {
+        let dir = env::temp_dir().join(format!("library_{}_{}", test, std::process::id()));
+        fs::create_dir_all(dir.join("lib"))?;
+        fs::write(dir.join("lib").join("prices.yaml"), PRICES_YAML)?;
+        fs::write(dir.join("lib").join("indicators.yaml"), INDICATORS_YAML)?;
+        for (file_name, yaml) in files {
+            fs::write(dir.join(file_name), yaml)?;
+        }
+        Ok(dir)
+    }
+
+    fn latest_score(path: &Path) -> GenResult<f64> {
+        let runnable_strategy =
+            RunnableStrategy::new(from_path(path)?, Box::new(MockDataClient::new()))?;
+        let asset_score = runnable_strategy
+            .run_on_asset(Asset::new(String::from("A")), MockDataClient::today())?;
+        Ok(*asset_score.score().values_slice().last().expect("score"))
+    }
+
+    #[test]
+    fn import_calcs() -> GenResult<()> {
+        let dir = strategy_dir("import", &[("strategy.yaml", STRATEGY_YAML)])?;
+        let strategy = from_path(&dir.join("strategy.yaml"))?;
+        assert!(strategy.imports().is_empty());
+        let names: Vec<&str> = strategy.calcs().iter().map(|calc| calc.name()).collect();
+        // sma20 is not referenced
+        assert_eq!(
+            names,
+            vec![
+                "sma_gap",
+                "sma_diff",
+                "indicators/sma50",
+                "indicators/sma200",
+                "indicators/prices/close"
+            ]
+        );
+        assert_eq!(
+            latest_score(&dir.join("strategy.yaml"))?,
+            latest_score(Path::new("strategy.yaml"))?
+        );
+        fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn import_cycle() -> GenResult<()> {
+        let cyclic = STRATEGY_YAML.replace("lib/indicators.yaml", "a.yaml");
+        let dir = strategy_dir(
+            "cycle",
+            &[
+                ("strategy.yaml", &cyclic),
+                ("a.yaml", "imports:\n  - path: b.yaml\n"),
+                ("b.yaml", "imports:\n  - path: a.yaml\n"),
+            ],
+        )?;
+        let error = from_path(&dir.join("strategy.yaml"))
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("import cycle"), "{}", error);
+        fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn import_conflicts() -> GenResult<()> {
+        let twice = STRATEGY_YAML.replace(
+            "  - path: lib/indicators.yaml\n",
+            "  - path: lib/indicators.yaml\n  - path: lib/prices.yaml\n    as: indicators\n",
+        );
+        let defined = STRATEGY_YAML.replace("name: sma_diff", "name: indicators/sma_diff");
+        let unknown = STRATEGY_YAML.replace("indicators/sma200", "indicators/sma100");
+        let composite = STRATEGY_YAML.replace("lib/indicators.yaml", "blend.yaml");
+        let dir = strategy_dir(
+            "conflicts",
+            &[
+                ("twice.yaml", &twice),
+                ("defined.yaml", &defined),
+                ("unknown.yaml", &unknown),
+                ("composite.yaml", &composite),
+                (
+                    "blend.yaml",
+                    "name: Blend\ncomposite:\n  - strategy: lib/indicators.yaml\n    weight: 1\n",
+                ),
+            ],
+        )?;
+        let error = |file_name: &str| from_path(&dir.join(file_name)).unwrap_err().to_string();
+        assert!(error("twice.yaml").contains("namespace indicators is imported more than once"));
+        assert!(error("defined.yaml").contains("conflicts with the calcs imported as indicators"));
+        assert!(error("unknown.yaml").contains("import indicators has no calc sma100"));
+        assert!(error("composite.yaml").contains("is a composite strategy"));
+        fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+}
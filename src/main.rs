@@ -1,18 +1,58 @@
 // cli library
+use std::collections::BTreeMap;
+use std::convert::{TryFrom, TryInto};
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::Arc;
+use std::thread;
+
 use chrono::{DateTime, Utc};
+use structopt::clap::ErrorKind;
 use structopt::StructOpt;
 
-use luckless::bot::asset_score::RunnableStrategy;
-use luckless::data::DataClient;
-use luckless::dto::strategy::StrategyDto;
-use luckless::errors::{CliArgError, GenResult};
+use luckless::bot::asset_score::{AssetScore, AssetScoreStatus, RetainCalcs, RunnableStrategy};
+use luckless::bot_server::{serve, BotService};
+use luckless::broker::BrokerClient;
+use luckless::broker_client::{BrokerClientConfig, GrpcBrokerClient};
+use luckless::calendar::TradingCalendar;
+use luckless::data::{Asset, DataClient, Query};
+use luckless::dto::strategy::{from_path, validate, Operation, QueryCalculationDto, StrategyDto};
+use luckless::errors::{
+    AssetNotFoundError, CliArgError, GenError, GenResult, InvalidStrategyError, QueryError,
+    UpstreamNotFoundError,
+};
+use luckless::graph::{parse_graph_format, GraphFormat, StrategyGraph};
 use luckless::mock_client::MockDataClient;
-pub use luckless::query_client::{parse_date, parse_strategy_yaml, QueryClient};
-use luckless::time_series::TimeStamp;
+use luckless::orders::OrderConfig;
+use luckless::paper_broker::{PaperBroker, PaperBrokerConfig};
+use luckless::persist::{save_calcs, write_time_series, Format};
+pub use luckless::query_client::{parse_date, parse_strategy_path, QueryClient, QueryClientConfig};
+use luckless::report::{parse_output_format, OutputFormat, RunMetadata, RunReport};
+use luckless::time_series::{Frequency, TimeStamp};
+use luckless::trace::RunTrace;
+use luckless::trader::{AlwaysOpen, MarketCalendar, SystemClock, Trader, TraderConfig};
+use luckless::universe::read_watchlist;
+
+/// Invalid command line arguments
+const EXIT_USAGE: i32 = 2;
+/// Unexpected failure
+const EXIT_FAILURE: i32 = 1;
+/// The strategy failed to parse or validate
+const EXIT_INVALID_STRATEGY: i32 = 3;
+/// An asset or its data is unavailable
+const EXIT_DATA_UNAVAILABLE: i32 = 4;
+/// Some assets were not scored
+const EXIT_INCOMPLETE: i32 = 5;
+
+/// Rows of each --profile table
+const PROFILE_LIMIT: usize = 10;
 
 #[derive(Debug, StructOpt)]
 #[structopt(
-    about = "Execute given strategy to compute non-negative score of the given assets over the given time range."
+    about = "Execute given strategy to compute non-negative score of the given assets over the given time range.",
+    after_help = "EXIT CODES:\n    0  success\n    1  unexpected failure\n    2  invalid arguments\n    3  invalid strategy\n    4  asset or data unavailable\n    5  some assets were not scored"
 )]
 struct Opt {
     /// Use Query GRPC service instead of mock data
@@ -26,38 +66,734 @@ struct Opt {
     #[structopt(short = "e", long = "end", parse(try_from_str = parse_date), default_value = "2012-01-01T00:00:00+00:00")]
     end: DateTime<Utc>,
     /// path to strategy yaml file
-    #[structopt(short = "f", long = "file", parse(try_from_str = parse_strategy_yaml), default_value = "./strategy.yaml")]
-    pub(crate) strategy: StrategyDto,
-    // TODO accept list of symbols
+    #[structopt(short = "f", long = "file", parse(try_from_str = parse_strategy_path), default_value = "./strategy.yaml")]
+    strategy_path: PathBuf,
+    /// path to Query client yaml config file, overridden by QUERY_* environment variables
+    #[structopt(long = "query-config", parse(from_os_str))]
+    query_config: Option<PathBuf>,
+    /// Query service host
+    #[structopt(long = "query-host")]
+    query_host: Option<String>,
+    /// Query service port
+    #[structopt(long = "query-port")]
+    query_port: Option<u16>,
+    /// Connect to the Query service over TLS, true or false
+    #[structopt(long = "query-tls")]
+    query_tls: Option<bool>,
+    /// Query request deadline in milliseconds
+    #[structopt(long = "query-timeout-ms")]
+    query_timeout_ms: Option<u64>,
+    /// Number of retries when the Query service is unavailable
+    #[structopt(long = "query-retries")]
+    query_retries: Option<u32>,
+    /// Only score symbols listed on this exchange
+    #[structopt(long = "exchange")]
+    exchange: Option<String>,
+    /// Only score symbols having all of these comma separated tags
+    #[structopt(long = "tags", use_delimiter = true)]
+    tags: Vec<String>,
+    /// Serve the Bot GRPC service instead of scoring once
+    #[structopt(long)]
+    serve: bool,
+    /// Bot GRPC service port
+    #[structopt(long, default_value = "50053")]
+    port: u16,
+    /// Directory of strategy yaml files served by strategy id
+    #[structopt(long = "strategy-dir", parse(from_os_str), default_value = ".")]
+    strategy_dir: PathBuf,
+    /// Trading calendar yaml file, eg. calendars/NYSE.yaml.  Weekdays without holidays by default
+    #[structopt(long = "calendar", parse(from_os_str))]
+    calendar: Option<PathBuf>,
+    #[structopt(flatten)]
+    output: OutputOpt,
+    #[structopt(flatten)]
+    trace: TraceOpt,
+    #[structopt(flatten)]
+    dump: DumpOpt,
+    /// Scores the strategy universe at --end without a subcommand
+    #[structopt(subcommand)]
+    command: Option<Command>,
 }
 
-fn parse_args() -> GenResult<(RunnableStrategy, TimeStamp)> {
-    let opt: Opt = Opt::from_args();
-    // println!("strategy: {:?}", opt.strategy);
-    // println!("start: {:?}", opt.start);
-    // println!("end: {:?}", opt.end);
-    if !(opt.start < opt.end) {
-        return Err(CliArgError::new("!(start < end)".to_string()));
+#[derive(Debug, StructOpt)]
+enum Command {
+    /// Score assets as of a timestamp
+    Score(ScoreOpt),
+    /// Replay the strategy over a time range, scoring assets on every rebalance day
+    Backtest(BacktestOpt),
+    /// Check strategy files without running them
+    Validate(ValidateOpt),
+    /// Export the calcs of the strategy and their dependencies
+    Graph(GraphOpt),
+    /// Inspect the data the strategy runs on
+    Data(DataCommand),
+    /// Trade the strategy on a paper account, rebalancing daily
+    Run(RunOpt),
+}
+
+#[derive(Debug, StructOpt)]
+struct OutputOpt {
+    /// Write the scores to this file instead of stdout
+    #[structopt(short = "o", long = "output", parse(from_os_str))]
+    output: Option<PathBuf>,
+    /// json, csv, yaml or table.  Defaults to the output file extension, or table
+    #[structopt(long = "format", parse(try_from_str = parse_output_format))]
+    format: Option<OutputFormat>,
+    /// Include the full score series of every asset, not only the latest score
+    #[structopt(long = "full-series")]
+    full_series: bool,
+}
+
+#[derive(Debug, StructOpt)]
+struct TraceOpt {
+    /// Write a JSON trace of every calc run on every asset to this file
+    #[structopt(long = "trace", parse(from_os_str))]
+    trace: Option<PathBuf>,
+    /// Print the slowest and most failing calcs to stderr
+    #[structopt(long = "profile")]
+    profile: bool,
+}
+
+#[derive(Debug, StructOpt)]
+struct DumpOpt {
+    /// Write the intermediate series of every calc to <dir>/<symbol>/<calc>.csv
+    #[structopt(long = "dump-calcs", parse(from_os_str))]
+    dump_calcs: Option<PathBuf>,
+    /// comma separated calcs to dump, all calcs by default
+    #[structopt(long = "calcs", use_delimiter = true)]
+    calcs: Vec<String>,
+}
+
+#[derive(Debug, StructOpt)]
+struct UniverseOpt {
+    /// comma separated symbols, the strategy universe by default
+    #[structopt(long = "symbols", use_delimiter = true)]
+    symbols: Vec<String>,
+    /// file of symbols separated by commas or whitespace, `#` starts a comment
+    #[structopt(long = "watchlist", parse(from_os_str))]
+    watchlist: Option<PathBuf>,
+}
+
+#[derive(Debug, StructOpt)]
+struct ScoreOpt {
+    /// timestamp of the scores in RFC3339 format, now by default
+    #[structopt(long = "at", parse(try_from_str = parse_date))]
+    at: Option<DateTime<Utc>>,
+    #[structopt(flatten)]
+    universe: UniverseOpt,
+    #[structopt(flatten)]
+    output: OutputOpt,
+    #[structopt(flatten)]
+    trace: TraceOpt,
+    #[structopt(flatten)]
+    dump: DumpOpt,
+}
+
+// intermediate calcs are not dumped, `score --at <day> --dump-calcs <dir>` replays a rebalance day
+#[derive(Debug, StructOpt)]
+struct BacktestOpt {
+    /// first date in back test in RFC3339 format.
+    #[structopt(short = "s", long = "start", parse(try_from_str = parse_date), default_value = "2011-12-01T00:00:00+00:00")]
+    start: DateTime<Utc>,
+    /// last date in back test in RFC3339 format.
+    #[structopt(short = "e", long = "end", parse(try_from_str = parse_date), default_value = "2012-01-01T00:00:00+00:00")]
+    end: DateTime<Utc>,
+    /// rebalance daily, weekly, monthly, quarterly or yearly
+    #[structopt(long = "frequency", parse(try_from_str = parse_frequency), default_value = "daily")]
+    frequency: Frequency,
+    #[structopt(flatten)]
+    universe: UniverseOpt,
+    #[structopt(flatten)]
+    output: OutputOpt,
+    #[structopt(flatten)]
+    trace: TraceOpt,
+}
+
+#[derive(Debug, StructOpt)]
+struct ValidateOpt {
+    /// strategy yaml files, the --file strategy by default
+    #[structopt(parse(from_os_str))]
+    files: Vec<PathBuf>,
+}
+
+#[derive(Debug, StructOpt)]
+struct GraphOpt {
+    /// write the graph to this file instead of stdout
+    #[structopt(short = "o", long = "output", parse(from_os_str))]
+    output: Option<PathBuf>,
+    /// dot, mermaid, svg or html.  Defaults to the output file extension, or dot
+    #[structopt(long = "format", parse(try_from_str = parse_graph_format))]
+    format: Option<GraphFormat>,
+    /// run the calcs on this symbol, annotating each with its status, output length and time
+    #[structopt(long = "symbol")]
+    symbol: Option<String>,
+    /// timestamp of the run in RFC3339 format, now by default
+    #[structopt(long = "at", parse(try_from_str = parse_date))]
+    at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, StructOpt)]
+enum DataCommand {
+    /// List the symbols of every asset
+    Assets,
+    /// List the series queried by the QUERY calcs of the strategy
+    Series,
+    /// Dump the series of a symbol
+    Query(QueryOpt),
+}
+
+#[derive(Debug, StructOpt)]
+struct QueryOpt {
+    /// symbol of the asset
+    symbol: String,
+    /// series to query
+    #[structopt(long = "series", default_value = "close")]
+    series: String,
+    /// first timestamp in RFC3339 format, the first value by default
+    #[structopt(short = "s", long = "start", parse(try_from_str = parse_date))]
+    start: Option<DateTime<Utc>>,
+    /// last timestamp in RFC3339 format, the last value by default
+    #[structopt(short = "e", long = "end", parse(try_from_str = parse_date))]
+    end: Option<DateTime<Utc>>,
+    /// write the series to this file instead of stdout
+    #[structopt(short = "o", long = "output", parse(from_os_str))]
+    output: Option<PathBuf>,
+    /// csv, jsonl or bin.  Defaults to the output file extension, or csv
+    #[structopt(long = "format", parse(try_from_str = parse_format))]
+    format: Option<Format>,
+}
+
+fn parse_frequency(arg: &str) -> Result<Frequency, String> {
+    Frequency::try_from(arg).map_err(|e| e.to_string())
+}
+
+fn parse_format(arg: &str) -> Result<Format, String> {
+    Format::try_from(arg).map_err(|e| e.to_string())
+}
+
+/// Open `path`, or stdout when there is none
+fn output_writer(path: &Option<PathBuf>) -> GenResult<Box<dyn Write>> {
+    match path {
+        Some(path) => Ok(Box::new(BufWriter::new(File::create(path)?))),
+        None => Ok(Box::new(io::stdout())),
+    }
+}
+
+impl OutputOpt {
+    /// Whether any output flag was given
+    fn is_set(&self) -> bool {
+        self.output.is_some() || self.format.is_some() || self.full_series
+    }
+
+    fn output_format(&self) -> OutputFormat {
+        match (self.format, &self.output) {
+            (Some(format), _) => format,
+            (None, Some(path)) => OutputFormat::from_path(path),
+            (None, None) => OutputFormat::Table,
+        }
     }
-    let data_client: Box<dyn DataClient>;
-    if opt.grpc {
-        println!("Attempting GRPC");
-        data_client = Box::new(QueryClient::new())
+
+    /// Write a report of `asset_scores` to the output file, or stdout
+    fn write_report(
+        &self,
+        metadata: RunMetadata,
+        asset_scores: &BTreeMap<Asset, AssetScore>,
+        full_series: bool,
+    ) -> GenResult<()> {
+        let report = RunReport::new(metadata, asset_scores, full_series || self.full_series);
+        report.write(output_writer(&self.output)?, self.output_format())
+    }
+}
+
+/// Fail with `EXIT_INCOMPLETE` unless every asset was scored
+fn check_complete(asset_scores: &BTreeMap<Asset, AssetScore>) -> GenResult<i32> {
+    if asset_scores
+        .values()
+        .all(|asset_score| asset_score.status() == &AssetScoreStatus::Complete)
+    {
+        Ok(0)
     } else {
-        data_client = Box::new(MockDataClient::new());
+        Ok(EXIT_INCOMPLETE)
     }
-    Ok((RunnableStrategy::new(opt.strategy, data_client)?, opt.end))
 }
 
-fn main() -> GenResult<()> {
-    let parse_result = parse_args();
-    if parse_result.is_err() {
-        println!("{:?}", parse_result.err().expect("Unknown Error"))
+impl TraceOpt {
+    /// Whether any trace flag was given
+    fn is_set(&self) -> bool {
+        self.trace.is_some() || self.profile
+    }
+
+    /// Write the trace file and print the profile when requested
+    fn write(&self, run_trace: &RunTrace) -> GenResult<()> {
+        if let Some(path) = &self.trace {
+            run_trace.write_json(BufWriter::new(File::create(path)?))?;
+        }
+        if self.profile {
+            run_trace.write_profile(io::stderr(), PROFILE_LIMIT)?;
+        }
+        Ok(())
+    }
+}
+
+impl DumpOpt {
+    /// Whether any dump flag was given
+    fn is_set(&self) -> bool {
+        self.dump_calcs.is_some() || !self.calcs.is_empty()
+    }
+
+    /// Keep the series to dump in the scores of `runnable_strategy`
+    fn retain_calcs(&self, runnable_strategy: &mut RunnableStrategy) -> GenResult<()> {
+        let retain_calcs = match (&self.dump_calcs, self.calcs.is_empty()) {
+            (None, _) => RetainCalcs::None,
+            (Some(_), true) => RetainCalcs::All,
+            (Some(_), false) => RetainCalcs::Only(self.calcs.clone()),
+        };
+        runnable_strategy
+            .set_retain_calcs(retain_calcs)
+            .map_err(|e| CliArgError::new(format!("calcs: {}", e)) as GenError)
+    }
+
+    fn dump(&self, asset_scores: &BTreeMap<Asset, AssetScore>) -> GenResult<()> {
+        if let Some(dir) = &self.dump_calcs {
+            for asset_score in asset_scores.values() {
+                save_calcs(dir, asset_score)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl UniverseOpt {
+    /// The strategy universe as of `timestamp`, the given symbols replace its included symbols.
+    /// Excluded symbols and filters still apply.
+    fn assets(
+        &self,
+        runnable_strategy: &RunnableStrategy,
+        timestamp: TimeStamp,
+    ) -> GenResult<Vec<Asset>> {
+        let mut symbols = self.symbols.clone();
+        if let Some(watchlist) = &self.watchlist {
+            let listed = read_watchlist(watchlist).map_err(|e| {
+                CliArgError::new(format!("watchlist {}: {}", watchlist.display(), e)) as GenError
+            })?;
+            symbols.extend(listed);
+        }
+        runnable_strategy
+            .strategy()
+            .universe()
+            .with_include(symbols)
+            .resolve(runnable_strategy.data_client(), timestamp)
+    }
+}
+
+impl ScoreOpt {
+    fn score(&self, opt: &Opt, mut runnable_strategy: RunnableStrategy) -> GenResult<i32> {
+        let at = self.at.unwrap_or_else(Utc::now);
+        self.dump.retain_calcs(&mut runnable_strategy)?;
+        let metadata =
+            RunMetadata::new(runnable_strategy.strategy().name(), at, &opt.data_source()?);
+        let (asset_scores, run_trace) =
+            runnable_strategy.trace_assets(self.universe.assets(&runnable_strategy, at)?, at)?;
+        self.trace.write(&run_trace)?;
+        self.dump.dump(&asset_scores)?;
+        self.output.write_report(metadata, &asset_scores, false)?;
+        check_complete(&asset_scores)
+    }
+}
+
+impl BacktestOpt {
+    fn back_test(&self, opt: &Opt, runnable_strategy: &RunnableStrategy) -> GenResult<i32> {
+        if self.start >= self.end {
+            return Err(CliArgError::new("!(start < end)".to_string()));
+        }
+        let metadata = RunMetadata::new(
+            runnable_strategy.strategy().name(),
+            self.end,
+            &opt.data_source()?,
+        );
+        let (back_test, run_trace) = runnable_strategy.trace_back_test(
+            self.universe.assets(runnable_strategy, self.start)?,
+            self.start,
+            self.end,
+            self.frequency,
+        )?;
+        self.trace.write(&run_trace)?;
+        // an asset failing on any rebalance day is missing scores
+        let failed = run_trace.failed_symbols();
+        let asset_scores: BTreeMap<Asset, AssetScore> = back_test
+            .into_iter()
+            .map(|(asset, scores)| {
+                let status = if failed.contains(asset.symbol()) {
+                    AssetScoreStatus::Error
+                } else {
+                    AssetScoreStatus::Complete
+                };
+                let asset_score = AssetScore::from_parts(asset.clone(), self.end, scores, status);
+                (asset, asset_score)
+            })
+            .collect();
+        self.output.write_report(metadata, &asset_scores, true)?;
+        check_complete(&asset_scores)
+    }
+}
+
+impl ValidateOpt {
+    /// Report every strategy file, failing when any is invalid
+    fn validate(&self, opt: &Opt) -> GenResult<i32> {
+        let files = if self.files.is_empty() {
+            vec![opt.strategy_path.clone()]
+        } else {
+            self.files.clone()
+        };
+        let mut exit_code = 0;
+        for file in files {
+            match load_strategy(&file).and_then(|strategy| validate(&strategy)) {
+                Ok(()) => println!("{}: ok", file.display()),
+                Err(e) => {
+                    println!("{}: {}", file.display(), e);
+                    exit_code = EXIT_INVALID_STRATEGY;
+                }
+            }
+        }
+        Ok(exit_code)
+    }
+}
+
+impl GraphOpt {
+    fn graph_format(&self) -> GraphFormat {
+        match (self.format, &self.output) {
+            (Some(format), _) => format,
+            (None, Some(path)) => GraphFormat::from_path(path),
+            (None, None) => GraphFormat::Dot,
+        }
+    }
+
+    fn graph(&self, opt: &Opt, data_client: &Arc<dyn DataClient + Send + Sync>) -> GenResult<i32> {
+        let strategy = opt.strategy()?;
+        let mut graph = StrategyGraph::new(&strategy)?;
+        if let Some(symbol) = &self.symbol {
+            let runnable_strategy = opt.runnable_strategy(data_client)?;
+            let asset = data_client.asset(symbol)?.clone();
+            let at = self.at.unwrap_or_else(Utc::now);
+            graph = graph.with_run(&runnable_strategy, asset, at);
+        }
+        graph.write(output_writer(&self.output)?, self.graph_format())?;
+        Ok(0)
+    }
+}
+
+impl DataCommand {
+    fn data(&self, opt: &Opt, data_client: &dyn DataClient) -> GenResult<i32> {
+        match self {
+            DataCommand::Assets => {
+                let mut symbols: Vec<&String> = data_client.assets().keys().collect();
+                symbols.sort();
+                symbols.iter().for_each(|symbol| println!("{}", symbol));
+            }
+            DataCommand::Series => {
+                for calc in opt.strategy()?.calcs() {
+                    if calc.operation() == &Operation::QUERY {
+                        let query_dto: QueryCalculationDto = calc.clone().try_into()?;
+                        println!("{}\t{}", query_dto.name(), query_dto.series());
+                    }
+                }
+            }
+            DataCommand::Query(query_opt) => {
+                let asset = data_client.asset(&query_opt.symbol)?;
+                let query = Query::complete(asset.symbol().to_string(), query_opt.series.clone());
+                let mut time_series = data_client.query(query)?;
+                if let Some(start) = query_opt.start {
+                    time_series = time_series.filter_ge(&start);
+                }
+                if let Some(end) = query_opt.end {
+                    time_series = time_series.filter_le(&end);
+                }
+                let format = match (query_opt.format, &query_opt.output) {
+                    (Some(format), _) => format,
+                    (None, Some(path)) => Format::from_path(path).unwrap_or(Format::Csv),
+                    (None, None) => Format::Csv,
+                };
+                write_time_series(output_writer(&query_opt.output)?, &time_series, format)?;
+            }
+        }
+        Ok(0)
+    }
+}
+
+#[derive(Debug, StructOpt)]
+struct RunOpt {
+    /// trading loop checkpoint file
+    #[structopt(
+        long = "state",
+        parse(from_os_str),
+        default_value = "./trader_state.json"
+    )]
+    state: PathBuf,
+    /// paper account file
+    #[structopt(
+        long = "account",
+        parse(from_os_str),
+        default_value = "./paper_account.json"
+    )]
+    account: PathBuf,
+    /// cash of a new paper account
+    #[structopt(long = "initial-cash", default_value = "100000")]
+    initial_cash: f64,
+    /// fraction of the price lost to slippage on every fill
+    #[structopt(long = "slippage", default_value = "0")]
+    slippage: f64,
+    /// order quantities are multiples of lot size
+    #[structopt(long = "lot-size", default_value = "1")]
+    lot_size: f64,
+    /// skip trades worth less than this
+    #[structopt(long = "min-trade-value", default_value = "0")]
+    min_trade_value: f64,
+    /// fraction of equity kept in cash
+    #[structopt(long = "cash-buffer", default_value = "0")]
+    cash_buffer: f64,
+    /// seconds between polls
+    #[structopt(long = "poll-seconds", default_value = "60")]
+    poll_seconds: i64,
+    /// ignore market hours, submitting orders as soon as they are computed
+    #[structopt(long = "always-open")]
+    always_open: bool,
+    /// stop after this many steps instead of running forever
+    #[structopt(long = "max-steps")]
+    max_steps: Option<usize>,
+    /// trade through the broker GRPC service instead of a paper account
+    #[structopt(long = "broker-grpc")]
+    broker_grpc: bool,
+    /// broker service host
+    #[structopt(long = "broker-host", default_value = "localhost")]
+    broker_host: String,
+    /// broker service port
+    #[structopt(long = "broker-port", default_value = "50054")]
+    broker_port: u16,
+    /// connect to the broker service over TLS
+    #[structopt(long = "broker-tls")]
+    broker_tls: bool,
+    /// broker account traded by the bot
+    #[structopt(long = "account-id", default_value = "paper")]
+    account_id: String,
+}
+
+impl RunOpt {
+    fn run(
+        &self,
+        strategy: StrategyDto,
+        data_client: Arc<dyn DataClient + Send + Sync>,
+        trading_calendar: TradingCalendar,
+    ) -> GenResult<()> {
+        let broker_config = PaperBrokerConfig {
+            initial_cash: self.initial_cash,
+            slippage: self.slippage,
+        };
+        let broker: Box<dyn BrokerClient> = if self.broker_grpc {
+            Box::new(GrpcBrokerClient::new(BrokerClientConfig {
+                host: self.broker_host.clone(),
+                port: self.broker_port,
+                tls: self.broker_tls,
+                account_id: self.account_id.clone(),
+                ..BrokerClientConfig::default()
+            })?)
+        } else {
+            Box::new(PaperBroker::open(
+                &self.account,
+                data_client.duplicate(),
+                broker_config,
+            )?)
+        };
+        let calendar: Box<dyn MarketCalendar> = if self.always_open {
+            Box::new(AlwaysOpen)
+        } else {
+            Box::new(trading_calendar.clone())
+        };
+        let config = TraderConfig {
+            poll_interval: chrono::Duration::seconds(self.poll_seconds),
+            order_config: OrderConfig {
+                lot_size: self.lot_size,
+                min_trade_value: self.min_trade_value,
+                cash_buffer: self.cash_buffer,
+            },
+            state_path: Some(self.state.clone()),
+        };
+        let mut runnable_strategy = RunnableStrategy::new(strategy, data_client.duplicate())?;
+        runnable_strategy.set_calendar(trading_calendar);
+        let mut trader = Trader::new(
+            runnable_strategy,
+            broker,
+            Box::new(SystemClock),
+            calendar,
+            config,
+        )?;
+        trader.run(self.max_steps)
+    }
+}
+
+impl Opt {
+    fn trading_calendar(&self) -> GenResult<TradingCalendar> {
+        match &self.calendar {
+            Some(path) => TradingCalendar::from_path(path),
+            None => Ok(TradingCalendar::default()),
+        }
+    }
+
+    fn strategy(&self) -> GenResult<StrategyDto> {
+        load_strategy(&self.strategy_path)
+    }
+
+    fn runnable_strategy(
+        &self,
+        data_client: &Arc<dyn DataClient + Send + Sync>,
+    ) -> GenResult<RunnableStrategy> {
+        let mut runnable_strategy =
+            RunnableStrategy::new(self.strategy()?, data_client.duplicate())?;
+        runnable_strategy.set_calendar(self.trading_calendar()?);
+        Ok(runnable_strategy)
+    }
+
+    /// Where the scores come from, recorded in the run metadata
+    fn data_source(&self) -> GenResult<String> {
+        if self.grpc {
+            let config = self.query_client_config()?;
+            Ok(format!("query {}:{}", config.host, config.port))
+        } else {
+            Ok(String::from("mock"))
+        }
+    }
+
+    /// Execute the subcommand, scoring the strategy universe at --end without one
+    fn execute(&self, data_client: Arc<dyn DataClient + Send + Sync>) -> GenResult<i32> {
+        match &self.command {
+            Some(Command::Score(score_opt)) => {
+                score_opt.score(self, self.runnable_strategy(&data_client)?)
+            }
+            Some(Command::Backtest(backtest_opt)) => {
+                backtest_opt.back_test(self, &self.runnable_strategy(&data_client)?)
+            }
+            Some(Command::Validate(validate_opt)) => validate_opt.validate(self),
+            Some(Command::Graph(graph_opt)) => graph_opt.graph(self, &data_client),
+            Some(Command::Data(data_command)) => data_command.data(self, &*data_client),
+            Some(Command::Run(run_opt)) => {
+                run_opt.run(self.strategy()?, data_client, self.trading_calendar()?)?;
+                Ok(0)
+            }
+            None if self.serve => {
+                let mut bot_service = BotService::new(data_client, self.strategy_dir.clone());
+                bot_service.set_calendar(self.trading_calendar()?);
+                let _server = serve(bot_service, self.port)?;
+                loop {
+                    thread::park();
+                }
+            }
+            None => {
+                let mut runnable_strategy = self.runnable_strategy(&data_client)?;
+                self.dump.retain_calcs(&mut runnable_strategy)?;
+                let metadata = RunMetadata::new(
+                    runnable_strategy.strategy().name(),
+                    self.end,
+                    &self.data_source()?,
+                );
+                let (asset_scores, run_trace) = runnable_strategy
+                    .trace_assets(runnable_strategy.universe(self.end)?, self.end)?;
+                self.trace.write(&run_trace)?;
+                self.dump.dump(&asset_scores)?;
+                self.output.write_report(metadata, &asset_scores, false)?;
+                check_complete(&asset_scores)
+            }
+        }
+    }
+
+    /// Layer CLI flags over the config file and environment
+    fn query_client_config(&self) -> GenResult<QueryClientConfig> {
+        let mut config = QueryClientConfig::load(self.query_config.as_deref())?;
+        if let Some(host) = &self.query_host {
+            config.host = host.clone();
+        }
+        if let Some(port) = self.query_port {
+            config.port = port;
+        }
+        if let Some(tls) = self.query_tls {
+            config.tls = tls;
+        }
+        if let Some(timeout_ms) = self.query_timeout_ms {
+            config.timeout_ms = timeout_ms;
+        }
+        if let Some(max_retries) = self.query_retries {
+            config.max_retries = max_retries;
+        }
+        if let Some(exchange) = &self.exchange {
+            config.exchange = Some(exchange.clone());
+        }
+        if !self.tags.is_empty() {
+            config.tags = self.tags.clone();
+        }
+        Ok(config)
+    }
+}
+
+/// Load a strategy yaml file, parse failures are invalid strategies
+fn load_strategy(path: &Path) -> GenResult<StrategyDto> {
+    parse_strategy_path(&path.to_string_lossy())
+        .map_err(|e| InvalidStrategyError::new(path.display().to_string(), e) as GenError)?;
+    from_path(path).map_err(|e| {
+        InvalidStrategyError::new(path.display().to_string(), e.to_string()) as GenError
+    })
+}
+
+/// Exit code scripts can rely on for `error`
+fn exit_code(error: &GenError) -> i32 {
+    if error.is::<CliArgError>() {
+        EXIT_USAGE
+    } else if error.is::<InvalidStrategyError>() {
+        EXIT_INVALID_STRATEGY
+    } else if error.is::<AssetNotFoundError>()
+        || error.is::<QueryError>()
+        || error.is::<UpstreamNotFoundError>()
+    {
+        EXIT_DATA_UNAVAILABLE
+    } else {
+        EXIT_FAILURE
+    }
+}
+
+fn parse_args() -> GenResult<(Opt, Arc<dyn DataClient + Send + Sync>)> {
+    let opt = match Opt::from_iter_safe(std::env::args_os()) {
+        Ok(opt) => opt,
+        Err(e) => match e.kind {
+            ErrorKind::HelpDisplayed | ErrorKind::VersionDisplayed => e.exit(),
+            _ => {
+                eprintln!("{}", e.message);
+                process::exit(EXIT_USAGE);
+            }
+        },
+    };
+    if !(opt.start < opt.end) {
+        return Err(CliArgError::new("!(start < end)".to_string()));
+    }
+    if opt.command.is_some() && (opt.output.is_set() || opt.trace.is_set() || opt.dump.is_set()) {
+        return Err(CliArgError::new(
+            "output, trace and dump options go after the subcommand".to_string(),
+        ));
+    }
+    let data_client: Arc<dyn DataClient + Send + Sync> = if opt.grpc {
+        eprintln!("Attempting GRPC");
+        Arc::new(QueryClient::new(opt.query_client_config()?)?)
     } else {
-        let (runnable_strategy, time_stamp) = parse_result.unwrap();
-        println!("runnable_strategy: {:?}\n", runnable_strategy);
-        let asset_scores = runnable_strategy.run_on_all_assets(time_stamp)?;
-        println!("asset_scores: {:?}\n", asset_scores);
+        Arc::new(MockDataClient::new())
+    };
+    Ok((opt, data_client))
+}
+
+fn main() {
+    // eg. RUST_LOG=luckless=debug
+    env_logger::init().expect("unable to initialize the logger");
+    let result = parse_args().and_then(|(opt, data_client)| opt.execute(data_client));
+    match result {
+        Ok(exit_code) => process::exit(exit_code),
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(exit_code(&e));
+        }
     }
-    Ok(())
 }
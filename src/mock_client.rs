@@ -4,10 +4,11 @@ use std::io::{Error, ErrorKind};
 
 use chrono::prelude::*;
 
+use crate::calendar::TradingCalendar;
 use crate::data::{Asset, DataClient, Query, Symbol};
 use crate::dto::strategy::QueryCalculationDto;
 use crate::errors::{AssetNotFoundError, GenResult};
-use crate::time_series::{TimeSeries1D, TimeStamp};
+use crate::time_series::{DataPointValue, Index, TimeSeries1D, TimeStamp};
 
 pub static DATA_SIZE: usize = 900;
 
@@ -15,12 +16,34 @@ pub static DATA_SIZE: usize = 900;
 pub struct MockDataClient {
     assets: HashMap<Symbol, Asset>,
     data: HashMap<Symbol, TimeSeries1D>,
+    /// Traded volumes, every other series is served the prices of `data`
+    volumes: HashMap<Symbol, TimeSeries1D>,
 }
 
 impl MockDataClient {
+    /// Last day of mock data
     pub fn today() -> DateTime<Utc> {
-        TimeSeries1D::epoch() + TimeSeries1D::index_unit() * (DATA_SIZE - 1) as i32
+        *MockDataClient::index(DATA_SIZE)
+            .last()
+            .expect("DATA_SIZE > 0")
     }
+    /// Mock markets trade on weekdays, without holidays
+    pub fn calendar() -> TradingCalendar {
+        TradingCalendar::default()
+    }
+    /// `len` trading days from `TimeSeries1D::epoch()`, at the close
+    fn index(len: usize) -> Index {
+        let calendar = MockDataClient::calendar();
+        let (_, close) = calendar
+            .session(TimeSeries1D::epoch().date_naive())
+            .expect("mock data starts on a trading day");
+        calendar.trading_index(close, len)
+    }
+}
+
+/// Create new `TimeSeries` with given `values` on consecutive trading days
+fn on_trading_days(values: Vec<DataPointValue>) -> TimeSeries1D {
+    TimeSeries1D::from_vec(MockDataClient::index(values.len()), values)
 }
 
 fn simulate_time_series(n: usize) -> TimeSeries1D {
@@ -49,7 +72,12 @@ impl DataClient for MockDataClient {
 
     #[allow(unused_variables)]
     fn query(&self, query: Query) -> GenResult<TimeSeries1D> {
-        let absolute_prices: GenResult<TimeSeries1D> = match self.data.get(query.symbol().clone()) {
+        let data = if query.series() == "volume" {
+            &self.volumes
+        } else {
+            &self.data
+        };
+        let absolute_prices: GenResult<TimeSeries1D> = match data.get(query.symbol()) {
             Some(ts) => Ok(ts.filter_le(&query.last())),
             None => Err(Box::new(Error::new(ErrorKind::NotFound, "Asset not found"))),
         };
@@ -86,12 +114,27 @@ impl MockDataClient {
         ]
         .into_iter()
         .collect();
+        // A trades 300K a day, B 2M, C rises from 1M to 3M
+        let volumes: HashMap<Symbol, TimeSeries1D> = vec![
+            (Symbol::from("A"), on_trading_days(vec![3e5; DATA_SIZE])),
+            (Symbol::from("B"), on_trading_days(vec![2e6; DATA_SIZE])),
+            (
+                Symbol::from("C"),
+                on_trading_days(linspace(DATA_SIZE, 1e6, 3e6)),
+            ),
+        ]
+        .into_iter()
+        .collect();
         let assets: HashMap<Symbol, Asset> = data
             .keys()
             .map(|x| (x.clone(), Asset::new(x.clone())))
             .into_iter()
             .collect();
-        MockDataClient { assets, data }
+        MockDataClient {
+            assets,
+            data,
+            volumes,
+        }
     }
     pub fn assets(&self) -> &HashMap<Symbol, Asset> {
         &self.assets
@@ -137,7 +180,7 @@ impl TimeSeriesGenerators for TimeSeries1D {
             }
             sum
         }
-        TimeSeries1D::from_values(
+        on_trading_days(
             x.iter()
                 .map(|x| _polynomial(&x, coefficients.as_slice()))
                 .collect(),
@@ -145,12 +188,12 @@ impl TimeSeriesGenerators for TimeSeries1D {
     }
     /// Create new `TimeSeries` with exponential values.
     fn exp(x: &Vec<f64>) -> Self {
-        TimeSeries1D::from_values(x.iter().map(|x| x.exp()).collect())
+        on_trading_days(x.iter().map(|x| x.exp()).collect())
     }
 
     /// Create new `TimeSeries` with `sin` values.
     fn sin(x: &Vec<f64>, amplitude: f64) -> Self {
-        TimeSeries1D::from_values(x.iter().map(|x| x.sin() * amplitude).collect())
+        on_trading_days(x.iter().map(|x| x.sin() * amplitude).collect())
     }
 
     /// Transform `TimeSeries` by adding `sin` values.
@@ -332,4 +375,18 @@ mod tests {
         assert_eq!(ts.index().last().unwrap(), &yesterday);
         Ok(())
     }
+
+    #[test]
+    fn mock_data_on_trading_days() -> GenResult<()> {
+        let client: Box<dyn DataClient> = Box::new(MockDataClient::new());
+        let calendar = MockDataClient::calendar();
+        for asset in client.assets().values() {
+            let ts = client.query(asset.clone().try_into()?)?;
+            assert!(ts
+                .index()
+                .iter()
+                .all(|timestamp| calendar.is_trading_day(calendar.local_date(timestamp))));
+        }
+        Ok(())
+    }
 }
@@ -0,0 +1,358 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::{Deserialize, Serialize};
+
+use crate::bot::asset_score::AssetScore;
+use crate::data::{epoch, Asset, DataClient, Query, Symbol, DEFAULT_SERIES};
+use crate::errors::{GenResult, OrderError};
+use crate::time_series::{DataPointValue, TimeStamp};
+
+pub type Quantity = f64;
+pub type Price = f64;
+/// Fraction of total equity, in `[0, 1]`
+pub type Weight = f64;
+
+/// Holdings of an account at a point in time.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Positions {
+    pub quantities: BTreeMap<Symbol, Quantity>,
+    pub cash: f64,
+}
+
+impl Positions {
+    pub fn new(quantities: BTreeMap<Symbol, Quantity>, cash: f64) -> Self {
+        Positions { quantities, cash }
+    }
+    pub fn quantity(&self, symbol: &str) -> Quantity {
+        self.quantities.get(symbol).cloned().unwrap_or(0.)
+    }
+    /// Cash plus the market value of every position.
+    pub fn equity(&self, prices: &BTreeMap<Symbol, Price>) -> GenResult<f64> {
+        let mut equity = self.cash;
+        for (symbol, quantity) in self.quantities.iter() {
+            match prices.get(symbol) {
+                Some(price) => equity += quantity * price,
+                None => return Err(OrderError::new(format!("no price for {}", symbol))),
+            }
+        }
+        Ok(equity)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Side {
+    Buy,
+    Sell,
+}
+
+/// Instruction to trade `quantity` of `symbol`, `price` is the reference price it was sized at.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Order {
+    pub symbol: Symbol,
+    pub side: Side,
+    pub quantity: Quantity,
+    pub price: Price,
+}
+
+impl Order {
+    pub fn new(symbol: Symbol, side: Side, quantity: Quantity, price: Price) -> Self {
+        Order {
+            symbol,
+            side,
+            quantity,
+            price,
+        }
+    }
+    pub fn value(&self) -> f64 {
+        self.quantity * self.price
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OrderConfig {
+    /// Order quantities are multiples of `lot_size`
+    pub lot_size: Quantity,
+    /// Trades worth less than `min_trade_value` are skipped
+    pub min_trade_value: f64,
+    /// Fraction of equity kept in cash
+    pub cash_buffer: Weight,
+}
+
+impl Default for OrderConfig {
+    fn default() -> Self {
+        OrderConfig {
+            lot_size: 1.,
+            min_trade_value: 0.,
+            cash_buffer: 0.,
+        }
+    }
+}
+
+impl OrderConfig {
+    fn round_down(&self, quantity: Quantity) -> Quantity {
+        (quantity / self.lot_size).floor() * self.lot_size
+    }
+}
+
+/// Allocate equity in proportion to the latest score of each asset.
+///
+/// Scores are non-negative, so are the weights.  All zero scores allocate nothing, nor do NaN or
+/// infinite scores.
+pub fn target_weights(asset_scores: &BTreeMap<Asset, AssetScore>) -> BTreeMap<Symbol, Weight> {
+    let latest: BTreeMap<Symbol, DataPointValue> = asset_scores
+        .iter()
+        .map(|(asset, asset_score)| {
+            let score = asset_score.score().values_slice().last().cloned();
+            let score = score.filter(|score| score.is_finite()).unwrap_or(0.);
+            (asset.symbol().to_string(), score.max(0.))
+        })
+        .collect();
+    let total: DataPointValue = latest.values().sum();
+    latest
+        .into_iter()
+        .map(|(symbol, score)| {
+            if total > 0. {
+                (symbol, score / total)
+            } else {
+                (symbol, 0.)
+            }
+        })
+        .collect()
+}
+
+/// Latest price of each symbol as of `timestamp`.
+pub fn latest_prices(
+    symbols: &BTreeSet<Symbol>,
+    data_client: &dyn DataClient,
+    timestamp: &TimeStamp,
+) -> GenResult<BTreeMap<Symbol, Price>> {
+    let mut prices = BTreeMap::new();
+    for symbol in symbols.iter() {
+        let query = Query::new(
+            symbol.clone(),
+            DEFAULT_SERIES.to_string(),
+            epoch(),
+            *timestamp,
+        );
+        match data_client.query(query)?.values_slice().last() {
+            Some(price) if price.is_finite() && *price > 0. => {
+                prices.insert(symbol.clone(), *price);
+            }
+            _ => {
+                return Err(OrderError::new(format!(
+                    "no valid price for {} as of {}",
+                    symbol, timestamp
+                )))
+            }
+        }
+    }
+    Ok(prices)
+}
+
+/// Orders rebalancing `positions` to `targets`, priced by `data_client` as of `timestamp`.
+///
+/// Symbols held but absent from `targets` are sold.  Sells precede buys and buys are scaled down
+/// to the cash available, so the account never goes short or overdrawn.
+pub fn generate_orders(
+    targets: &BTreeMap<Symbol, Weight>,
+    positions: &Positions,
+    data_client: &dyn DataClient,
+    timestamp: &TimeStamp,
+    config: &OrderConfig,
+) -> GenResult<Vec<Order>> {
+    validate(targets, positions, config)?;
+    let symbols: BTreeSet<Symbol> = targets
+        .keys()
+        .chain(positions.quantities.keys())
+        .cloned()
+        .collect();
+    let prices = latest_prices(&symbols, data_client, timestamp)?;
+    let equity = positions.equity(&prices)?;
+    let investable = equity * (1. - config.cash_buffer);
+
+    let mut sells: Vec<Order> = vec![];
+    let mut buys: Vec<Order> = vec![];
+    for symbol in symbols.iter() {
+        let price = prices[symbol];
+        let weight = targets.get(symbol).cloned().unwrap_or(0.);
+        let held = positions.quantity(symbol);
+        let target = config.round_down(investable * weight / price);
+        if target < held {
+            // liquidate odd lots entirely, otherwise never sell more than is held
+            let quantity = if target == 0. {
+                held
+            } else {
+                config.round_down(held - target)
+            };
+            sells.push(Order::new(symbol.clone(), Side::Sell, quantity, price));
+        } else if target > held {
+            let quantity = config.round_down(target - held);
+            buys.push(Order::new(symbol.clone(), Side::Buy, quantity, price));
+        }
+    }
+
+    let mut cash = positions.cash - equity * config.cash_buffer;
+    let mut orders: Vec<Order> = vec![];
+    for order in sells.into_iter() {
+        if order.quantity > 0. && order.value() >= config.min_trade_value {
+            cash += order.value();
+            orders.push(order);
+        }
+    }
+    for mut order in buys.into_iter() {
+        let affordable = config.round_down(cash.max(0.) / order.price);
+        order.quantity = order.quantity.min(affordable);
+        if order.quantity > 0. && order.value() >= config.min_trade_value {
+            cash -= order.value();
+            orders.push(order);
+        }
+    }
+    Ok(orders)
+}
+
+fn validate(
+    targets: &BTreeMap<Symbol, Weight>,
+    positions: &Positions,
+    config: &OrderConfig,
+) -> GenResult<()> {
+    if config.lot_size.is_nan() || config.lot_size <= 0. {
+        return Err(OrderError::new(String::from("lot_size must be positive")));
+    }
+    if !(0. ..1.).contains(&config.cash_buffer) {
+        return Err(OrderError::new(String::from(
+            "cash_buffer must be in [0, 1)",
+        )));
+    }
+    // no shorting
+    if let Some((symbol, weight)) = targets.iter().find(|(_, w)| w.is_nan() || **w < 0.) {
+        return Err(OrderError::new(format!(
+            "negative target weight {} for {}",
+            weight, symbol
+        )));
+    }
+    let total: Weight = targets.values().sum();
+    if total > 1. + 1e-9 {
+        return Err(OrderError::new(format!("target weights sum to {}", total)));
+    }
+    if let Some((symbol, quantity)) = positions.quantities.iter().find(|(_, q)| **q < 0.) {
+        return Err(OrderError::new(format!(
+            "short position {} of {}",
+            quantity, symbol
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, BTreeSet};
+
+    use crate::data::Symbol;
+    use crate::errors::GenResult;
+    use crate::mock_client::MockDataClient;
+    use crate::orders::{
+        generate_orders, latest_prices, Order, OrderConfig, Positions, Side, Weight,
+    };
+
+    fn targets(weights: Vec<(&str, Weight)>) -> BTreeMap<Symbol, Weight> {
+        weights
+            .into_iter()
+            .map(|(symbol, weight)| (symbol.to_string(), weight))
+            .collect()
+    }
+
+    fn price(symbol: &str) -> f64 {
+        let symbols: BTreeSet<Symbol> = vec![symbol.to_string()].into_iter().collect();
+        latest_prices(&symbols, &MockDataClient::new(), &MockDataClient::today()).expect("price")
+            [symbol]
+    }
+
+    #[test]
+    fn buy_from_cash() -> GenResult<()> {
+        let positions = Positions::new(BTreeMap::new(), 1000.);
+        let orders = generate_orders(
+            &targets(vec![("A", 0.5), ("B", 0.5)]),
+            &positions,
+            &MockDataClient::new(),
+            &MockDataClient::today(),
+            &OrderConfig::default(),
+        )?;
+        assert_eq!(orders.len(), 2);
+        assert!(orders.iter().all(|order| order.side == Side::Buy));
+        assert_eq!(orders[0].quantity, (500. / price("A")).floor());
+        let spent: f64 = orders.iter().map(Order::value).sum();
+        assert!(spent <= 1000.);
+        Ok(())
+    }
+
+    #[test]
+    fn sell_dropped_and_rebalance() -> GenResult<()> {
+        let quantities = vec![(String::from("A"), 50.), (String::from("C"), 7.)]
+            .into_iter()
+            .collect();
+        let positions = Positions::new(quantities, 0.);
+        let config = OrderConfig {
+            lot_size: 10.,
+            ..OrderConfig::default()
+        };
+        let orders = generate_orders(
+            &targets(vec![("A", 0.5), ("B", 0.5)]),
+            &positions,
+            &MockDataClient::new(),
+            &MockDataClient::today(),
+            &config,
+        )?;
+        // sells first, the odd lot of C is liquidated entirely
+        let sell_c = orders.iter().find(|order| order.symbol == "C").expect("C");
+        assert_eq!((sell_c.side, sell_c.quantity), (Side::Sell, 7.));
+        assert_eq!(orders[0].side, Side::Sell);
+        orders
+            .iter()
+            .filter(|order| order.symbol != "C")
+            .for_each(|order| assert_eq!(order.quantity % 10., 0.));
+        Ok(())
+    }
+
+    #[test]
+    fn cash_buffer_and_min_trade_value() -> GenResult<()> {
+        let positions = Positions::new(BTreeMap::new(), 1000.);
+        let config = OrderConfig {
+            cash_buffer: 0.5,
+            min_trade_value: 100.,
+            ..OrderConfig::default()
+        };
+        let orders = generate_orders(
+            &targets(vec![("A", 0.95), ("B", 0.05)]),
+            &positions,
+            &MockDataClient::new(),
+            &MockDataClient::today(),
+            &config,
+        )?;
+        // B is worth less than min_trade_value and skipped
+        assert_eq!(orders.len(), 1);
+        assert!(orders[0].value() <= 500.);
+        Ok(())
+    }
+
+    #[test]
+    fn no_shorting() {
+        let positions = Positions::new(BTreeMap::new(), 1000.);
+        let result = generate_orders(
+            &targets(vec![("A", -0.5)]),
+            &positions,
+            &MockDataClient::new(),
+            &MockDataClient::today(),
+            &OrderConfig::default(),
+        );
+        assert!(result.is_err());
+        let result = generate_orders(
+            &targets(vec![("A", 0.7), ("B", 0.7)]),
+            &positions,
+            &MockDataClient::new(),
+            &MockDataClient::today(),
+            &OrderConfig::default(),
+        );
+        assert!(result.is_err());
+    }
+}
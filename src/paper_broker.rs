@@ -0,0 +1,299 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+use crate::broker::{BrokerClient, Fill, OrderId, OrderStatus};
+use crate::data::DataClient;
+use crate::errors::{GenResult, OrderError};
+use crate::orders::{latest_prices, Order, Positions, Side};
+use crate::time_series::TimeStamp;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PaperBrokerConfig {
+    /// Cash of a new account
+    pub initial_cash: f64,
+    /// Fraction of the price paid above it when buying and received below it when selling
+    pub slippage: f64,
+}
+
+impl Default for PaperBrokerConfig {
+    fn default() -> Self {
+        PaperBrokerConfig {
+            initial_cash: 100_000.,
+            slippage: 0.,
+        }
+    }
+}
+
+impl PaperBrokerConfig {
+    /// Price `side` trades at when the market is at `price`.
+    pub fn fill_price(&self, side: Side, price: f64) -> f64 {
+        match side {
+            Side::Buy => price * (1. + self.slippage),
+            Side::Sell => price * (1. - self.slippage),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OrderRecord {
+    pub order: Order,
+    pub status: OrderStatus,
+    pub submitted: TimeStamp,
+}
+
+/// Everything persisted between runs.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PaperAccount {
+    pub positions: Positions,
+    pub orders: BTreeMap<OrderId, OrderRecord>,
+    pub fills: Vec<Fill>,
+    next_order_id: u64,
+}
+
+impl PaperAccount {
+    pub fn new(cash: f64) -> Self {
+        PaperAccount {
+            positions: Positions::new(BTreeMap::new(), cash),
+            orders: BTreeMap::new(),
+            fills: vec![],
+            next_order_id: 1,
+        }
+    }
+
+    /// Fill `order` at `price` if the account can afford it, never going short.
+    pub(crate) fn execute(&mut self, order: Order, price: f64, timestamp: TimeStamp) -> OrderId {
+        let order_id = format!("{:08}", self.next_order_id);
+        self.next_order_id += 1;
+        let status = match self.check(&order, price) {
+            Ok(()) => {
+                let held = self.positions.quantity(&order.symbol);
+                let (quantity, cash) = match order.side {
+                    Side::Buy => (held + order.quantity, -order.quantity * price),
+                    Side::Sell => (held - order.quantity, order.quantity * price),
+                };
+                self.positions.cash += cash;
+                if quantity > 0. {
+                    self.positions
+                        .quantities
+                        .insert(order.symbol.clone(), quantity);
+                } else {
+                    self.positions.quantities.remove(&order.symbol);
+                }
+                self.fills.push(Fill {
+                    order_id: order_id.clone(),
+                    symbol: order.symbol.clone(),
+                    side: order.side,
+                    quantity: order.quantity,
+                    price,
+                    timestamp,
+                });
+                OrderStatus::Filled
+            }
+            Err(reason) => OrderStatus::Rejected { reason },
+        };
+        self.orders.insert(
+            order_id.clone(),
+            OrderRecord {
+                order,
+                status,
+                submitted: timestamp,
+            },
+        );
+        order_id
+    }
+
+    fn check(&self, order: &Order, price: f64) -> Result<(), String> {
+        if !order.quantity.is_finite() || order.quantity <= 0. {
+            return Err(format!("invalid quantity {}", order.quantity));
+        }
+        match order.side {
+            Side::Buy if order.quantity * price > self.positions.cash => Err(format!(
+                "insufficient cash {} for {}",
+                self.positions.cash,
+                order.quantity * price
+            )),
+            Side::Sell if order.quantity > self.positions.quantity(&order.symbol) => Err(format!(
+                "insufficient quantity {} of {}",
+                self.positions.quantity(&order.symbol),
+                order.symbol
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Simulated `BrokerClient` filling orders immediately at the latest `DataClient` price, plus
+/// slippage.
+///
+/// Orders are filled as of `set_timestamp`, or now if it was never set.  When opened from a file,
+/// the account is saved back to it after every order.
+pub struct PaperBroker {
+    data_client: Box<dyn DataClient>,
+    config: PaperBrokerConfig,
+    account: PaperAccount,
+    path: Option<PathBuf>,
+    timestamp: Option<TimeStamp>,
+}
+
+impl PaperBroker {
+    /// In memory account, lost when dropped.
+    pub fn new(data_client: Box<dyn DataClient>, config: PaperBrokerConfig) -> Self {
+        let account = PaperAccount::new(config.initial_cash);
+        PaperBroker {
+            data_client,
+            config,
+            account,
+            path: None,
+            timestamp: None,
+        }
+    }
+
+    /// Resume the account saved at `path`, or start a new one there.
+    pub fn open(
+        path: &Path,
+        data_client: Box<dyn DataClient>,
+        config: PaperBrokerConfig,
+    ) -> GenResult<Self> {
+        let mut paper_broker = PaperBroker::new(data_client, config);
+        if path.exists() {
+            let json = fs::read_to_string(path)?;
+            paper_broker.account = serde_json::from_str(&json)?;
+        }
+        paper_broker.path = Some(path.to_path_buf());
+        paper_broker.save()?;
+        Ok(paper_broker)
+    }
+
+    pub fn account(&self) -> &PaperAccount {
+        &self.account
+    }
+
+    pub fn set_timestamp(&mut self, timestamp: TimeStamp) {
+        self.timestamp = Some(timestamp);
+    }
+
+    fn timestamp(&self) -> TimeStamp {
+        self.timestamp.unwrap_or_else(Utc::now)
+    }
+
+    /// Write to a temporary file first so a crash never leaves a partial account behind.
+    fn save(&self) -> GenResult<()> {
+        if let Some(path) = &self.path {
+            let tmp_path = path.with_extension("tmp");
+            fs::write(&tmp_path, serde_json::to_string_pretty(&self.account)?)?;
+            fs::rename(&tmp_path, path)?;
+        }
+        Ok(())
+    }
+}
+
+impl BrokerClient for PaperBroker {
+    fn positions(&self) -> GenResult<Positions> {
+        Ok(self.account.positions.clone())
+    }
+
+    fn cash(&self) -> GenResult<f64> {
+        Ok(self.account.positions.cash)
+    }
+
+    fn submit_order(&mut self, order: Order) -> GenResult<OrderId> {
+        let timestamp = self.timestamp();
+        let symbols: BTreeSet<String> = vec![order.symbol.clone()].into_iter().collect();
+        let price = latest_prices(&symbols, &*self.data_client, &timestamp)?[&order.symbol];
+        let price = self.config.fill_price(order.side, price);
+        let order_id = self.account.execute(order, price, timestamp);
+        self.save()?;
+        Ok(order_id)
+    }
+
+    fn order_status(&self, order_id: &str) -> GenResult<OrderStatus> {
+        match self.account.orders.get(order_id) {
+            Some(order_record) => Ok(order_record.status.clone()),
+            None => Err(OrderError::new(format!("unknown order {}", order_id))),
+        }
+    }
+
+    fn fills(&self) -> GenResult<Vec<Fill>> {
+        Ok(self.account.fills.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+
+    use crate::broker::{BrokerClient, OrderStatus};
+    use crate::errors::GenResult;
+    use crate::mock_client::MockDataClient;
+    use crate::orders::{Order, Side};
+    use crate::paper_broker::{PaperBroker, PaperBrokerConfig};
+
+    fn broker_fixture(slippage: f64) -> PaperBroker {
+        let config = PaperBrokerConfig {
+            initial_cash: 1000.,
+            slippage,
+        };
+        let mut paper_broker = PaperBroker::new(Box::new(MockDataClient::new()), config);
+        paper_broker.set_timestamp(MockDataClient::today());
+        paper_broker
+    }
+
+    #[test]
+    fn buy_then_sell() -> GenResult<()> {
+        let mut paper_broker = broker_fixture(0.01);
+        let buy = paper_broker.submit_order(Order::new(String::from("A"), Side::Buy, 10., 0.))?;
+        assert_eq!(paper_broker.order_status(&buy)?, OrderStatus::Filled);
+        assert_eq!(paper_broker.positions()?.quantity("A"), 10.);
+        let sell = paper_broker.submit_order(Order::new(String::from("A"), Side::Sell, 10., 0.))?;
+        assert_eq!(paper_broker.order_status(&sell)?, OrderStatus::Filled);
+        assert!(paper_broker.positions()?.quantities.is_empty());
+        // slippage is paid both ways
+        let fills = paper_broker.fills()?;
+        assert_eq!(fills.len(), 2);
+        assert!(fills[0].price > fills[1].price);
+        assert!(paper_broker.cash()? < 1000.);
+        Ok(())
+    }
+
+    #[test]
+    fn reject_short_and_overdraft() -> GenResult<()> {
+        let mut paper_broker = broker_fixture(0.);
+        let sell = paper_broker.submit_order(Order::new(String::from("A"), Side::Sell, 1., 0.))?;
+        assert!(matches!(
+            paper_broker.order_status(&sell)?,
+            OrderStatus::Rejected { .. }
+        ));
+        let buy = paper_broker.submit_order(Order::new(String::from("A"), Side::Buy, 1e6, 0.))?;
+        assert!(matches!(
+            paper_broker.order_status(&buy)?,
+            OrderStatus::Rejected { .. }
+        ));
+        assert_eq!(paper_broker.cash()?, 1000.);
+        assert!(paper_broker.fills()?.is_empty());
+        assert!(paper_broker.order_status("unknown").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn persist_account() -> GenResult<()> {
+        let path = env::temp_dir().join(format!("paper_broker_{}.json", std::process::id()));
+        let _ = fs::remove_file(&path);
+        let data_client = Box::new(MockDataClient::new());
+        let mut paper_broker =
+            PaperBroker::open(&path, data_client.clone(), PaperBrokerConfig::default())?;
+        paper_broker.set_timestamp(MockDataClient::today());
+        paper_broker.submit_order(Order::new(String::from("B"), Side::Buy, 5., 0.))?;
+        let reopened = PaperBroker::open(&path, data_client, PaperBrokerConfig::default())?;
+        assert_eq!(reopened.account().fills.len(), 1);
+        assert_eq!(reopened.positions()?.quantity("B"), 5.);
+        assert_abs_diff_eq!(reopened.cash()?, paper_broker.cash()?, epsilon = 1e-9);
+        fs::remove_file(&path)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,561 @@
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::fs::{create_dir_all, File};
+use std::io::{BufRead, BufReader, BufWriter, Read, Write};
+use std::path::{is_separator, Component, Path, PathBuf};
+
+use chrono::{TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::bot::asset_score::{AssetScore, AssetScoreStatus};
+use crate::data::{Asset, Symbol};
+use crate::dto::strategy::NAMESPACE_SEPARATOR;
+use crate::errors::{FormatError, GenError, GenResult};
+use crate::time_series::{nan_if_null, DataPointValue, TimeSeries1D, TimeStamp};
+
+const TIME_SERIES_MAGIC: &[u8; 4] = b"LTS1";
+const SCORES_MAGIC: &[u8; 4] = b"LSC1";
+/// Largest length read from a binary file, longer lengths are taken as a corrupt file
+const MAX_BINARY_LEN: u64 = 1 << 24;
+
+/// File formats time series and scores are saved in, so one run can reload the output of another.
+///
+/// - `Csv`: a header row then one row per value, scores one row per asset and score value
+/// - `JsonLines`: one JSON object per line, one per value or one per asset score.  JSON has no
+///   NaN or infinity, non finite values are written as `null` and read back as NaN
+/// - `Binary`: little endian lengths, timestamps as seconds and nanoseconds, values as f64
+///
+/// Only `JsonLines` keeps the named scores and retained calcs of an `AssetScore`, `Csv` and
+/// `Binary` hold its primary score.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Csv,
+    JsonLines,
+    Binary,
+}
+
+impl Format {
+    /// Format of a `.csv`, `.jsonl` or `.bin` file.
+    pub fn from_path(path: &Path) -> GenResult<Self> {
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some(extension) => Format::try_from(extension),
+            None => Err(FormatError::new(format!(
+                "{} has no extension",
+                path.display()
+            ))),
+        }
+    }
+}
+
+impl TryFrom<&str> for Format {
+    type Error = GenError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.to_uppercase().as_str() {
+            "CSV" => Ok(Format::Csv),
+            "JSONL" | "JSON_LINES" | "NDJSON" => Ok(Format::JsonLines),
+            "BIN" | "BINARY" => Ok(Format::Binary),
+            _ => Err(FormatError::new(format!("unknown format {}", value))),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct DataPoint {
+    timestamp: TimeStamp,
+    #[serde(deserialize_with = "nan_if_null")]
+    value: DataPointValue,
+}
+
+/// Row of the scores csv, an asset without score values has a single row without `score`.
+#[derive(Debug, Serialize, Deserialize)]
+struct ScoreRow {
+    symbol: Symbol,
+    timestamp: TimeStamp,
+    status: AssetScoreStatus,
+    score_timestamp: Option<TimeStamp>,
+    score: Option<DataPointValue>,
+}
+
+pub fn write_time_series<W: Write>(
+    writer: W,
+    time_series: &TimeSeries1D,
+    format: Format,
+) -> GenResult<()> {
+    match format {
+        Format::Csv => {
+            let mut writer = csv::Writer::from_writer(writer);
+            for (timestamp, value) in time_series.iter() {
+                writer.serialize(DataPoint {
+                    timestamp: *timestamp,
+                    value: *value,
+                })?;
+            }
+            writer.flush()?;
+        }
+        Format::JsonLines => {
+            let mut writer = writer;
+            for (timestamp, value) in time_series.iter() {
+                let data_point = DataPoint {
+                    timestamp: *timestamp,
+                    value: *value,
+                };
+                serde_json::to_writer(&mut writer, &data_point)?;
+                writer.write_all(b"\n")?;
+            }
+            writer.flush()?;
+        }
+        Format::Binary => {
+            let mut writer = writer;
+            writer.write_all(TIME_SERIES_MAGIC)?;
+            write_binary_series(&mut writer, time_series)?;
+            writer.flush()?;
+        }
+    }
+    Ok(())
+}
+
+pub fn read_time_series<R: Read>(reader: R, format: Format) -> GenResult<TimeSeries1D> {
+    let (index, values) = match format {
+        Format::Csv => {
+            let mut reader = csv::Reader::from_reader(reader);
+            let mut index = vec![];
+            let mut values = vec![];
+            for data_point in reader.deserialize() {
+                let data_point: DataPoint = data_point?;
+                index.push(data_point.timestamp);
+                values.push(data_point.value);
+            }
+            (index, values)
+        }
+        Format::JsonLines => {
+            let mut index = vec![];
+            let mut values = vec![];
+            for line in BufReader::new(reader).lines() {
+                let line = line?;
+                if line.trim().is_empty() {
+                    continue;
+                }
+                let data_point: DataPoint = serde_json::from_str(&line)?;
+                index.push(data_point.timestamp);
+                values.push(data_point.value);
+            }
+            (index, values)
+        }
+        Format::Binary => {
+            let mut reader = BufReader::new(reader);
+            check_magic(&mut reader, TIME_SERIES_MAGIC)?;
+            return read_binary_series(&mut reader);
+        }
+    };
+    Ok(TimeSeries1D::from_vec(index, values))
+}
+
+/// Save `time_series` in the format of the `path` extension.
+pub fn save_time_series(path: &Path, time_series: &TimeSeries1D) -> GenResult<()> {
+    let format = Format::from_path(path)?;
+    write_time_series(BufWriter::new(File::create(path)?), time_series, format)
+}
+
+/// Load a time series saved by `save_time_series`.
+pub fn load_time_series(path: &Path) -> GenResult<TimeSeries1D> {
+    read_time_series(File::open(path)?, Format::from_path(path)?)
+}
+
+/// Save the retained calcs of `asset_score` to `<dir>/<symbol>/<calc>.csv`, the calcs of a
+/// namespace, eg. `trend/sma50`, in a directory of their own.
+///
+/// References naming anything but a file below `<dir>/<symbol>`, eg. `../scores`, are rejected.
+pub fn save_calcs(dir: &Path, asset_score: &AssetScore) -> GenResult<()> {
+    let asset_dir = dir.join(asset_score.asset().symbol());
+    for (reference, time_series) in asset_score.calcs() {
+        let path = calc_path(&asset_dir, reference)?;
+        if let Some(parent) = path.parent() {
+            create_dir_all(parent)?;
+        }
+        save_time_series(&path, time_series)?;
+    }
+    Ok(())
+}
+
+/// `<asset_dir>/<namespace>/<calc>.csv`, each part of `reference` a plain file name without `\`,
+/// so the dump lays out the same on every platform.
+fn calc_path(asset_dir: &Path, reference: &str) -> GenResult<PathBuf> {
+    let mut path = asset_dir.to_path_buf();
+    let mut parts = reference.split(NAMESPACE_SEPARATOR).peekable();
+    while let Some(part) = parts.next() {
+        let mut components = Path::new(part).components();
+        let plain = matches!(components.next(), Some(Component::Normal(_)))
+            && components.next().is_none()
+            && !part.contains(|c: char| c == '\\' || is_separator(c));
+        if !plain {
+            return Err(FormatError::new(format!(
+                "calc {} is not a file name, can not save it",
+                reference
+            )));
+        }
+        if parts.peek().is_some() {
+            path.push(part);
+        } else {
+            path.push(format!("{}.csv", part));
+        }
+    }
+    Ok(path)
+}
+
+pub fn write_scores<W: Write>(
+    writer: W,
+    asset_scores: &BTreeMap<Asset, AssetScore>,
+    format: Format,
+) -> GenResult<()> {
+    match format {
+        Format::Csv => {
+            let mut writer = csv::Writer::from_writer(writer);
+            for asset_score in asset_scores.values() {
+                let row = |score_timestamp, score| ScoreRow {
+                    symbol: asset_score.asset().symbol().to_string(),
+                    timestamp: *asset_score.timestamp(),
+                    status: asset_score.status().clone(),
+                    score_timestamp,
+                    score,
+                };
+                if asset_score.score().is_empty() {
+                    writer.serialize(row(None, None))?;
+                } else {
+                    for (timestamp, value) in asset_score.score().iter() {
+                        writer.serialize(row(Some(*timestamp), Some(*value)))?;
+                    }
+                }
+            }
+            writer.flush()?;
+        }
+        Format::JsonLines => {
+            let mut writer = writer;
+            for asset_score in asset_scores.values() {
+                serde_json::to_writer(&mut writer, asset_score)?;
+                writer.write_all(b"\n")?;
+            }
+            writer.flush()?;
+        }
+        Format::Binary => {
+            let mut writer = writer;
+            writer.write_all(SCORES_MAGIC)?;
+            write_u64(&mut writer, asset_scores.len() as u64)?;
+            for asset_score in asset_scores.values() {
+                let symbol = asset_score.asset().symbol().as_bytes();
+                write_u64(&mut writer, symbol.len() as u64)?;
+                writer.write_all(symbol)?;
+                write_timestamp(&mut writer, asset_score.timestamp())?;
+                writer.write_all(&[status_code(asset_score.status())])?;
+                write_binary_series(&mut writer, asset_score.score())?;
+            }
+            writer.flush()?;
+        }
+    }
+    Ok(())
+}
+
+pub fn read_scores<R: Read>(reader: R, format: Format) -> GenResult<BTreeMap<Asset, AssetScore>> {
+    let asset_scores: Vec<AssetScore> = match format {
+        Format::Csv => {
+            let mut reader = csv::Reader::from_reader(reader);
+            let mut rows: BTreeMap<Symbol, Vec<ScoreRow>> = BTreeMap::new();
+            for row in reader.deserialize() {
+                let row: ScoreRow = row?;
+                rows.entry(row.symbol.clone()).or_default().push(row);
+            }
+            rows.into_iter()
+                .map(|(symbol, rows)| {
+                    let (index, values) = rows
+                        .iter()
+                        .filter_map(|row| row.score_timestamp.map(|t| (t, row.score)))
+                        .map(|(t, score)| (t, score.unwrap_or(DataPointValue::NAN)))
+                        .unzip();
+                    AssetScore::from_parts(
+                        Asset::new(symbol),
+                        rows[0].timestamp,
+                        TimeSeries1D::from_vec(index, values),
+                        rows[0].status.clone(),
+                    )
+                })
+                .collect()
+        }
+        Format::JsonLines => {
+            let mut asset_scores = vec![];
+            for line in BufReader::new(reader).lines() {
+                let line = line?;
+                if line.trim().is_empty() {
+                    continue;
+                }
+                asset_scores.push(serde_json::from_str(&line)?);
+            }
+            asset_scores
+        }
+        Format::Binary => {
+            let mut reader = BufReader::new(reader);
+            check_magic(&mut reader, SCORES_MAGIC)?;
+            let len = read_len(&mut reader, "scores")?;
+            let mut asset_scores = Vec::with_capacity(len);
+            for _ in 0..len {
+                let mut symbol = vec![0u8; read_len(&mut reader, "symbol")?];
+                reader.read_exact(&mut symbol)?;
+                let symbol = String::from_utf8(symbol)
+                    .map_err(|e| FormatError::new(format!("invalid symbol: {}", e)))?;
+                let timestamp = read_timestamp(&mut reader)?;
+                let mut status = [0u8];
+                reader.read_exact(&mut status)?;
+                asset_scores.push(AssetScore::from_parts(
+                    Asset::new(symbol),
+                    timestamp,
+                    read_binary_series(&mut reader)?,
+                    status_from_code(status[0])?,
+                ));
+            }
+            asset_scores
+        }
+    };
+    Ok(asset_scores
+        .into_iter()
+        .map(|asset_score| (asset_score.asset().clone(), asset_score))
+        .collect())
+}
+
+/// Save `asset_scores` in the format of the `path` extension.
+pub fn save_scores(path: &Path, asset_scores: &BTreeMap<Asset, AssetScore>) -> GenResult<()> {
+    let format = Format::from_path(path)?;
+    write_scores(BufWriter::new(File::create(path)?), asset_scores, format)
+}
+
+/// Load scores saved by `save_scores`, eg. as input to `orders::target_weights`.
+pub fn load_scores(path: &Path) -> GenResult<BTreeMap<Asset, AssetScore>> {
+    read_scores(File::open(path)?, Format::from_path(path)?)
+}
+
+fn check_magic<R: Read>(reader: &mut R, magic: &[u8; 4]) -> GenResult<()> {
+    let mut header = [0u8; 4];
+    reader.read_exact(&mut header)?;
+    if &header == magic {
+        Ok(())
+    } else {
+        Err(FormatError::new(format!(
+            "expected binary header {:?}, found {:?}",
+            String::from_utf8_lossy(magic),
+            String::from_utf8_lossy(&header)
+        )))
+    }
+}
+
+fn status_code(status: &AssetScoreStatus) -> u8 {
+    match status {
+        AssetScoreStatus::NotStarted => 0,
+        AssetScoreStatus::InProgress => 1,
+        AssetScoreStatus::Complete => 2,
+        AssetScoreStatus::Error => 3,
+    }
+}
+
+fn status_from_code(code: u8) -> GenResult<AssetScoreStatus> {
+    match code {
+        0 => Ok(AssetScoreStatus::NotStarted),
+        1 => Ok(AssetScoreStatus::InProgress),
+        2 => Ok(AssetScoreStatus::Complete),
+        3 => Ok(AssetScoreStatus::Error),
+        _ => Err(FormatError::new(format!("unknown status code {}", code))),
+    }
+}
+
+/// Length, then every timestamp, then every value
+fn write_binary_series<W: Write>(writer: &mut W, time_series: &TimeSeries1D) -> GenResult<()> {
+    write_u64(writer, time_series.len() as u64)?;
+    for timestamp in time_series.index_slice() {
+        write_timestamp(writer, timestamp)?;
+    }
+    for value in time_series.values_slice() {
+        writer.write_all(&value.to_le_bytes())?;
+    }
+    Ok(())
+}
+
+fn read_binary_series<R: Read>(reader: &mut R) -> GenResult<TimeSeries1D> {
+    let len = read_len(reader, "time series")?;
+    let mut index = Vec::with_capacity(len);
+    for _ in 0..len {
+        index.push(read_timestamp(reader)?);
+    }
+    let mut values = Vec::with_capacity(len);
+    let mut bytes = [0u8; 8];
+    for _ in 0..len {
+        reader.read_exact(&mut bytes)?;
+        values.push(DataPointValue::from_le_bytes(bytes));
+    }
+    Ok(TimeSeries1D::from_vec(index, values))
+}
+
+fn write_u64<W: Write>(writer: &mut W, value: u64) -> GenResult<()> {
+    writer.write_all(&value.to_le_bytes())?;
+    Ok(())
+}
+
+fn read_u64<R: Read>(reader: &mut R) -> GenResult<u64> {
+    let mut bytes = [0u8; 8];
+    reader.read_exact(&mut bytes)?;
+    Ok(u64::from_le_bytes(bytes))
+}
+
+/// Length of what follows, failing on lengths no file written by this module holds.
+fn read_len<R: Read>(reader: &mut R, what: &str) -> GenResult<usize> {
+    let len = read_u64(reader)?;
+    if len > MAX_BINARY_LEN {
+        return Err(FormatError::new(format!(
+            "{} length {} exceeds {}",
+            what, len, MAX_BINARY_LEN
+        )));
+    }
+    Ok(len as usize)
+}
+
+fn write_timestamp<W: Write>(writer: &mut W, timestamp: &TimeStamp) -> GenResult<()> {
+    writer.write_all(&timestamp.timestamp().to_le_bytes())?;
+    writer.write_all(&timestamp.timestamp_subsec_nanos().to_le_bytes())?;
+    Ok(())
+}
+
+fn read_timestamp<R: Read>(reader: &mut R) -> GenResult<TimeStamp> {
+    let mut seconds = [0u8; 8];
+    let mut nanos = [0u8; 4];
+    reader.read_exact(&mut seconds)?;
+    reader.read_exact(&mut nanos)?;
+    let (seconds, nanos) = (i64::from_le_bytes(seconds), u32::from_le_bytes(nanos));
+    Utc.timestamp_opt(seconds, nanos).single().ok_or_else(|| {
+        FormatError::new(format!("invalid timestamp {}s {}ns", seconds, nanos)) as GenError
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::convert::TryFrom;
+    use std::path::Path;
+
+    use chrono::Duration;
+
+    use crate::bot::asset_score::{AssetScore, AssetScoreStatus};
+    use crate::data::Asset;
+    use crate::errors::{FormatError, GenResult};
+    use crate::persist::{
+        calc_path, read_scores, read_time_series, write_scores, write_time_series, Format,
+    };
+    use crate::time_series::TimeSeries1D;
+
+    const FORMATS: [Format; 3] = [Format::Csv, Format::JsonLines, Format::Binary];
+
+    fn assert_same(actual: &TimeSeries1D, expected: &TimeSeries1D) {
+        assert_eq!(actual.index(), expected.index());
+        for (a, e) in actual.values_slice().iter().zip(expected.values_slice()) {
+            assert!(a == e || (a.is_nan() && e.is_nan()), "{} != {}", a, e);
+        }
+    }
+
+    #[test]
+    fn time_series_round_trip() -> GenResult<()> {
+        let ts = TimeSeries1D::from_values(vec![1.5, f64::NAN, -3., 1e-12]);
+        for format in FORMATS.iter() {
+            let mut buffer = vec![];
+            write_time_series(&mut buffer, &ts, *format)?;
+            assert_same(&read_time_series(buffer.as_slice(), *format)?, &ts);
+        }
+        let mut csv = vec![];
+        write_time_series(&mut csv, &ts.filter_lt(&ts.index()[1]), Format::Csv)?;
+        assert_eq!(
+            String::from_utf8(csv)?,
+            "timestamp,value\n2010-01-01T00:00:00Z,1.5\n"
+        );
+        assert!(read_time_series("LSC1".as_bytes(), Format::Binary).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn corrupt_binary_lengths() {
+        let corrupt = |magic: &[u8]| {
+            let mut buffer = magic.to_vec();
+            buffer.extend_from_slice(&u64::MAX.to_le_bytes());
+            buffer
+        };
+        let error = read_time_series(corrupt(b"LTS1").as_slice(), Format::Binary).unwrap_err();
+        assert!(error.is::<FormatError>());
+        let error = read_scores(corrupt(b"LSC1").as_slice(), Format::Binary).unwrap_err();
+        assert!(error.is::<FormatError>());
+        // a single score whose symbol is too long
+        let mut buffer = b"LSC1".to_vec();
+        buffer.extend_from_slice(&1u64.to_le_bytes());
+        buffer.extend_from_slice(&u64::MAX.to_le_bytes());
+        let error = read_scores(buffer.as_slice(), Format::Binary).unwrap_err();
+        assert!(error.is::<FormatError>());
+    }
+
+    #[test]
+    fn scores_round_trip() -> GenResult<()> {
+        let timestamp = TimeSeries1D::epoch() + Duration::days(3);
+        let asset_scores: BTreeMap<Asset, AssetScore> = vec![
+            (
+                "A",
+                TimeSeries1D::from_values(vec![1., 2.]),
+                AssetScoreStatus::Complete,
+            ),
+            (
+                "B",
+                TimeSeries1D::from_values(vec![]),
+                AssetScoreStatus::Error,
+            ),
+        ]
+        .into_iter()
+        .map(|(symbol, score, status)| {
+            let asset = Asset::new(symbol.to_string());
+            let asset_score = AssetScore::from_parts(asset.clone(), timestamp, score, status);
+            (asset, asset_score)
+        })
+        .collect();
+        for format in FORMATS.iter() {
+            let mut buffer = vec![];
+            write_scores(&mut buffer, &asset_scores, *format)?;
+            assert_eq!(read_scores(buffer.as_slice(), *format)?, asset_scores);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn format_of_path() {
+        assert_eq!(
+            Format::from_path(Path::new("out/scores.jsonl")).unwrap(),
+            Format::JsonLines
+        );
+        assert_eq!(Format::try_from("CSV").unwrap(), Format::Csv);
+        assert!(Format::from_path(Path::new("scores")).is_err());
+        assert!(Format::from_path(Path::new("scores.xlsx")).is_err());
+    }
+
+    #[test]
+    fn calc_paths_stay_in_the_asset_dir() -> GenResult<()> {
+        let dir = Path::new("dump/A");
+        assert_eq!(calc_path(dir, "sma50")?, dir.join("sma50.csv"));
+        assert_eq!(
+            calc_path(dir, "trend/sma.50")?,
+            dir.join("trend/sma.50.csv")
+        );
+        for reference in [
+            "../scores",
+            "/etc/passwd",
+            "trend//sma",
+            "trend/..",
+            ".",
+            "a\\b",
+            "",
+        ]
+        .iter()
+        {
+            let error = calc_path(dir, reference).unwrap_err();
+            assert!(error.is::<FormatError>(), "{}", reference);
+        }
+        Ok(())
+    }
+}
@@ -713,6 +713,1771 @@ impl ::protobuf::reflect::ProtobufValue for TimeSeries {
     }
 }
 
+#[derive(PartialEq,Clone,Default)]
+pub struct SymbolsRequest {
+    // message fields
+    pub exchange: ::std::string::String,
+    pub tags: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SymbolsRequest {
+    fn default() -> &'a SymbolsRequest {
+        <SymbolsRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SymbolsRequest {
+    pub fn new() -> SymbolsRequest {
+        ::std::default::Default::default()
+    }
+
+    // string exchange = 1;
+
+
+    pub fn get_exchange(&self) -> &str {
+        &self.exchange
+    }
+    pub fn clear_exchange(&mut self) {
+        self.exchange.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_exchange(&mut self, v: ::std::string::String) {
+        self.exchange = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_exchange(&mut self) -> &mut ::std::string::String {
+        &mut self.exchange
+    }
+
+    // Take field
+    pub fn take_exchange(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.exchange, ::std::string::String::new())
+    }
+
+    // repeated string tags = 2;
+
+
+    pub fn get_tags(&self) -> &[::std::string::String] {
+        &self.tags
+    }
+    pub fn clear_tags(&mut self) {
+        self.tags.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tags(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.tags = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_tags(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.tags
+    }
+
+    // Take field
+    pub fn take_tags(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.tags, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for SymbolsRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.exchange)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.tags)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.exchange.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.exchange);
+        }
+        for value in &self.tags {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.exchange.is_empty() {
+            os.write_string(1, &self.exchange)?;
+        }
+        for v in &self.tags {
+            os.write_string(2, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SymbolsRequest {
+        SymbolsRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "exchange",
+                |m: &SymbolsRequest| { &m.exchange },
+                |m: &mut SymbolsRequest| { &mut m.exchange },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "tags",
+                |m: &SymbolsRequest| { &m.tags },
+                |m: &mut SymbolsRequest| { &mut m.tags },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SymbolsRequest>(
+                "SymbolsRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SymbolsRequest {
+        static instance: ::protobuf::rt::LazyV2<SymbolsRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SymbolsRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for SymbolsRequest {
+    fn clear(&mut self) {
+        self.exchange.clear();
+        self.tags.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SymbolsRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SymbolsRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SymbolInfo {
+    // message fields
+    pub symbol: ::std::string::String,
+    pub exchange: ::std::string::String,
+    pub tags: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SymbolInfo {
+    fn default() -> &'a SymbolInfo {
+        <SymbolInfo as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SymbolInfo {
+    pub fn new() -> SymbolInfo {
+        ::std::default::Default::default()
+    }
+
+    // string symbol = 1;
+
+
+    pub fn get_symbol(&self) -> &str {
+        &self.symbol
+    }
+    pub fn clear_symbol(&mut self) {
+        self.symbol.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_symbol(&mut self, v: ::std::string::String) {
+        self.symbol = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_symbol(&mut self) -> &mut ::std::string::String {
+        &mut self.symbol
+    }
+
+    // Take field
+    pub fn take_symbol(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.symbol, ::std::string::String::new())
+    }
+
+    // string exchange = 2;
+
+
+    pub fn get_exchange(&self) -> &str {
+        &self.exchange
+    }
+    pub fn clear_exchange(&mut self) {
+        self.exchange.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_exchange(&mut self, v: ::std::string::String) {
+        self.exchange = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_exchange(&mut self) -> &mut ::std::string::String {
+        &mut self.exchange
+    }
+
+    // Take field
+    pub fn take_exchange(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.exchange, ::std::string::String::new())
+    }
+
+    // repeated string tags = 3;
+
+
+    pub fn get_tags(&self) -> &[::std::string::String] {
+        &self.tags
+    }
+    pub fn clear_tags(&mut self) {
+        self.tags.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tags(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.tags = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_tags(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.tags
+    }
+
+    // Take field
+    pub fn take_tags(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.tags, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for SymbolInfo {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.symbol)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.exchange)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.tags)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.symbol.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.symbol);
+        }
+        if !self.exchange.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.exchange);
+        }
+        for value in &self.tags {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.symbol.is_empty() {
+            os.write_string(1, &self.symbol)?;
+        }
+        if !self.exchange.is_empty() {
+            os.write_string(2, &self.exchange)?;
+        }
+        for v in &self.tags {
+            os.write_string(3, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SymbolInfo {
+        SymbolInfo::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "symbol",
+                |m: &SymbolInfo| { &m.symbol },
+                |m: &mut SymbolInfo| { &mut m.symbol },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "exchange",
+                |m: &SymbolInfo| { &m.exchange },
+                |m: &mut SymbolInfo| { &mut m.exchange },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "tags",
+                |m: &SymbolInfo| { &m.tags },
+                |m: &mut SymbolInfo| { &mut m.tags },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SymbolInfo>(
+                "SymbolInfo",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SymbolInfo {
+        static instance: ::protobuf::rt::LazyV2<SymbolInfo> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SymbolInfo::new)
+    }
+}
+
+impl ::protobuf::Clear for SymbolInfo {
+    fn clear(&mut self) {
+        self.symbol.clear();
+        self.exchange.clear();
+        self.tags.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SymbolInfo {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SymbolInfo {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Symbols {
+    // message fields
+    pub symbols: ::protobuf::RepeatedField<SymbolInfo>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Symbols {
+    fn default() -> &'a Symbols {
+        <Symbols as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Symbols {
+    pub fn new() -> Symbols {
+        ::std::default::Default::default()
+    }
+
+    // repeated .query.SymbolInfo symbols = 1;
+
+
+    pub fn get_symbols(&self) -> &[SymbolInfo] {
+        &self.symbols
+    }
+    pub fn clear_symbols(&mut self) {
+        self.symbols.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_symbols(&mut self, v: ::protobuf::RepeatedField<SymbolInfo>) {
+        self.symbols = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_symbols(&mut self) -> &mut ::protobuf::RepeatedField<SymbolInfo> {
+        &mut self.symbols
+    }
+
+    // Take field
+    pub fn take_symbols(&mut self) -> ::protobuf::RepeatedField<SymbolInfo> {
+        ::std::mem::replace(&mut self.symbols, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for Symbols {
+    fn is_initialized(&self) -> bool {
+        for v in &self.symbols {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.symbols)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.symbols {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.symbols {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Symbols {
+        Symbols::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<SymbolInfo>>(
+                "symbols",
+                |m: &Symbols| { &m.symbols },
+                |m: &mut Symbols| { &mut m.symbols },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Symbols>(
+                "Symbols",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Symbols {
+        static instance: ::protobuf::rt::LazyV2<Symbols> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Symbols::new)
+    }
+}
+
+impl ::protobuf::Clear for Symbols {
+    fn clear(&mut self) {
+        self.symbols.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Symbols {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Symbols {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SeriesRequest {
+    // message fields
+    pub symbol: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SeriesRequest {
+    fn default() -> &'a SeriesRequest {
+        <SeriesRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SeriesRequest {
+    pub fn new() -> SeriesRequest {
+        ::std::default::Default::default()
+    }
+
+    // string symbol = 1;
+
+
+    pub fn get_symbol(&self) -> &str {
+        &self.symbol
+    }
+    pub fn clear_symbol(&mut self) {
+        self.symbol.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_symbol(&mut self, v: ::std::string::String) {
+        self.symbol = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_symbol(&mut self) -> &mut ::std::string::String {
+        &mut self.symbol
+    }
+
+    // Take field
+    pub fn take_symbol(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.symbol, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for SeriesRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.symbol)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.symbol.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.symbol);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.symbol.is_empty() {
+            os.write_string(1, &self.symbol)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SeriesRequest {
+        SeriesRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "symbol",
+                |m: &SeriesRequest| { &m.symbol },
+                |m: &mut SeriesRequest| { &mut m.symbol },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SeriesRequest>(
+                "SeriesRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SeriesRequest {
+        static instance: ::protobuf::rt::LazyV2<SeriesRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SeriesRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for SeriesRequest {
+    fn clear(&mut self) {
+        self.symbol.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SeriesRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SeriesRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SeriesNames {
+    // message fields
+    pub series: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SeriesNames {
+    fn default() -> &'a SeriesNames {
+        <SeriesNames as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SeriesNames {
+    pub fn new() -> SeriesNames {
+        ::std::default::Default::default()
+    }
+
+    // repeated string series = 1;
+
+
+    pub fn get_series(&self) -> &[::std::string::String] {
+        &self.series
+    }
+    pub fn clear_series(&mut self) {
+        self.series.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_series(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.series = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_series(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.series
+    }
+
+    // Take field
+    pub fn take_series(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.series, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for SeriesNames {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.series)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.series {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.series {
+            os.write_string(1, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SeriesNames {
+        SeriesNames::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "series",
+                |m: &SeriesNames| { &m.series },
+                |m: &mut SeriesNames| { &mut m.series },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SeriesNames>(
+                "SeriesNames",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SeriesNames {
+        static instance: ::protobuf::rt::LazyV2<SeriesNames> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SeriesNames::new)
+    }
+}
+
+impl ::protobuf::Clear for SeriesNames {
+    fn clear(&mut self) {
+        self.series.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SeriesNames {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SeriesNames {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct BatchRequest {
+    // message fields
+    pub requests: ::protobuf::RepeatedField<RangedRequest>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a BatchRequest {
+    fn default() -> &'a BatchRequest {
+        <BatchRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BatchRequest {
+    pub fn new() -> BatchRequest {
+        ::std::default::Default::default()
+    }
+
+    // repeated .query.RangedRequest requests = 1;
+
+
+    pub fn get_requests(&self) -> &[RangedRequest] {
+        &self.requests
+    }
+    pub fn clear_requests(&mut self) {
+        self.requests.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_requests(&mut self, v: ::protobuf::RepeatedField<RangedRequest>) {
+        self.requests = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_requests(&mut self) -> &mut ::protobuf::RepeatedField<RangedRequest> {
+        &mut self.requests
+    }
+
+    // Take field
+    pub fn take_requests(&mut self) -> ::protobuf::RepeatedField<RangedRequest> {
+        ::std::mem::replace(&mut self.requests, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for BatchRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.requests {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.requests)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.requests {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.requests {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> BatchRequest {
+        BatchRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<RangedRequest>>(
+                "requests",
+                |m: &BatchRequest| { &m.requests },
+                |m: &mut BatchRequest| { &mut m.requests },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<BatchRequest>(
+                "BatchRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static BatchRequest {
+        static instance: ::protobuf::rt::LazyV2<BatchRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(BatchRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for BatchRequest {
+    fn clear(&mut self) {
+        self.requests.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for BatchRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BatchRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SeriesData {
+    // message fields
+    pub symbol: ::std::string::String,
+    pub series: ::std::string::String,
+    pub data: ::protobuf::RepeatedField<DataPoint>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SeriesData {
+    fn default() -> &'a SeriesData {
+        <SeriesData as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SeriesData {
+    pub fn new() -> SeriesData {
+        ::std::default::Default::default()
+    }
+
+    // string symbol = 1;
+
+
+    pub fn get_symbol(&self) -> &str {
+        &self.symbol
+    }
+    pub fn clear_symbol(&mut self) {
+        self.symbol.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_symbol(&mut self, v: ::std::string::String) {
+        self.symbol = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_symbol(&mut self) -> &mut ::std::string::String {
+        &mut self.symbol
+    }
+
+    // Take field
+    pub fn take_symbol(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.symbol, ::std::string::String::new())
+    }
+
+    // string series = 2;
+
+
+    pub fn get_series(&self) -> &str {
+        &self.series
+    }
+    pub fn clear_series(&mut self) {
+        self.series.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_series(&mut self, v: ::std::string::String) {
+        self.series = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_series(&mut self) -> &mut ::std::string::String {
+        &mut self.series
+    }
+
+    // Take field
+    pub fn take_series(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.series, ::std::string::String::new())
+    }
+
+    // repeated .query.DataPoint data = 3;
+
+
+    pub fn get_data(&self) -> &[DataPoint] {
+        &self.data
+    }
+    pub fn clear_data(&mut self) {
+        self.data.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_data(&mut self, v: ::protobuf::RepeatedField<DataPoint>) {
+        self.data = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_data(&mut self) -> &mut ::protobuf::RepeatedField<DataPoint> {
+        &mut self.data
+    }
+
+    // Take field
+    pub fn take_data(&mut self) -> ::protobuf::RepeatedField<DataPoint> {
+        ::std::mem::replace(&mut self.data, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for SeriesData {
+    fn is_initialized(&self) -> bool {
+        for v in &self.data {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.symbol)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.series)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.data)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.symbol.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.symbol);
+        }
+        if !self.series.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.series);
+        }
+        for value in &self.data {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.symbol.is_empty() {
+            os.write_string(1, &self.symbol)?;
+        }
+        if !self.series.is_empty() {
+            os.write_string(2, &self.series)?;
+        }
+        for v in &self.data {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SeriesData {
+        SeriesData::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "symbol",
+                |m: &SeriesData| { &m.symbol },
+                |m: &mut SeriesData| { &mut m.symbol },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "series",
+                |m: &SeriesData| { &m.series },
+                |m: &mut SeriesData| { &mut m.series },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<DataPoint>>(
+                "data",
+                |m: &SeriesData| { &m.data },
+                |m: &mut SeriesData| { &mut m.data },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SeriesData>(
+                "SeriesData",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SeriesData {
+        static instance: ::protobuf::rt::LazyV2<SeriesData> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SeriesData::new)
+    }
+}
+
+impl ::protobuf::Clear for SeriesData {
+    fn clear(&mut self) {
+        self.symbol.clear();
+        self.series.clear();
+        self.data.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SeriesData {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SeriesData {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct BatchResponse {
+    // message fields
+    pub results: ::protobuf::RepeatedField<SeriesData>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a BatchResponse {
+    fn default() -> &'a BatchResponse {
+        <BatchResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BatchResponse {
+    pub fn new() -> BatchResponse {
+        ::std::default::Default::default()
+    }
+
+    // repeated .query.SeriesData results = 1;
+
+
+    pub fn get_results(&self) -> &[SeriesData] {
+        &self.results
+    }
+    pub fn clear_results(&mut self) {
+        self.results.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_results(&mut self, v: ::protobuf::RepeatedField<SeriesData>) {
+        self.results = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_results(&mut self) -> &mut ::protobuf::RepeatedField<SeriesData> {
+        &mut self.results
+    }
+
+    // Take field
+    pub fn take_results(&mut self) -> ::protobuf::RepeatedField<SeriesData> {
+        ::std::mem::replace(&mut self.results, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for BatchResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.results {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.results)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.results {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.results {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> BatchResponse {
+        BatchResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<SeriesData>>(
+                "results",
+                |m: &BatchResponse| { &m.results },
+                |m: &mut BatchResponse| { &mut m.results },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<BatchResponse>(
+                "BatchResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static BatchResponse {
+        static instance: ::protobuf::rt::LazyV2<BatchResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(BatchResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for BatchResponse {
+    fn clear(&mut self) {
+        self.results.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for BatchResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BatchResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SeriesDataPoint {
+    // message fields
+    pub symbol: ::std::string::String,
+    pub series: ::std::string::String,
+    pub data_point: ::protobuf::SingularPtrField<DataPoint>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SeriesDataPoint {
+    fn default() -> &'a SeriesDataPoint {
+        <SeriesDataPoint as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SeriesDataPoint {
+    pub fn new() -> SeriesDataPoint {
+        ::std::default::Default::default()
+    }
+
+    // string symbol = 1;
+
+
+    pub fn get_symbol(&self) -> &str {
+        &self.symbol
+    }
+    pub fn clear_symbol(&mut self) {
+        self.symbol.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_symbol(&mut self, v: ::std::string::String) {
+        self.symbol = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_symbol(&mut self) -> &mut ::std::string::String {
+        &mut self.symbol
+    }
+
+    // Take field
+    pub fn take_symbol(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.symbol, ::std::string::String::new())
+    }
+
+    // string series = 2;
+
+
+    pub fn get_series(&self) -> &str {
+        &self.series
+    }
+    pub fn clear_series(&mut self) {
+        self.series.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_series(&mut self, v: ::std::string::String) {
+        self.series = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_series(&mut self) -> &mut ::std::string::String {
+        &mut self.series
+    }
+
+    // Take field
+    pub fn take_series(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.series, ::std::string::String::new())
+    }
+
+    // .query.DataPoint data_point = 3;
+
+
+    pub fn get_data_point(&self) -> &DataPoint {
+        self.data_point.as_ref().unwrap_or_else(|| <DataPoint as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_data_point(&mut self) {
+        self.data_point.clear();
+    }
+
+    pub fn has_data_point(&self) -> bool {
+        self.data_point.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_data_point(&mut self, v: DataPoint) {
+        self.data_point = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_data_point(&mut self) -> &mut DataPoint {
+        if self.data_point.is_none() {
+            self.data_point.set_default();
+        }
+        self.data_point.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_data_point(&mut self) -> DataPoint {
+        self.data_point.take().unwrap_or_else(|| DataPoint::new())
+    }
+}
+
+impl ::protobuf::Message for SeriesDataPoint {
+    fn is_initialized(&self) -> bool {
+        for v in &self.data_point {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.symbol)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.series)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.data_point)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.symbol.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.symbol);
+        }
+        if !self.series.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.series);
+        }
+        if let Some(ref v) = self.data_point.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.symbol.is_empty() {
+            os.write_string(1, &self.symbol)?;
+        }
+        if !self.series.is_empty() {
+            os.write_string(2, &self.series)?;
+        }
+        if let Some(ref v) = self.data_point.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SeriesDataPoint {
+        SeriesDataPoint::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "symbol",
+                |m: &SeriesDataPoint| { &m.symbol },
+                |m: &mut SeriesDataPoint| { &mut m.symbol },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "series",
+                |m: &SeriesDataPoint| { &m.series },
+                |m: &mut SeriesDataPoint| { &mut m.series },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<DataPoint>>(
+                "data_point",
+                |m: &SeriesDataPoint| { &m.data_point },
+                |m: &mut SeriesDataPoint| { &mut m.data_point },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SeriesDataPoint>(
+                "SeriesDataPoint",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SeriesDataPoint {
+        static instance: ::protobuf::rt::LazyV2<SeriesDataPoint> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SeriesDataPoint::new)
+    }
+}
+
+impl ::protobuf::Clear for SeriesDataPoint {
+    fn clear(&mut self) {
+        self.symbol.clear();
+        self.series.clear();
+        self.data_point.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SeriesDataPoint {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SeriesDataPoint {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x0bquery.proto\x12\x05query\x1a\x1fgoogle/protobuf/timestamp.proto\"\
     \xa1\x01\n\rRangedRequest\x12\x16\n\x06symbol\x18\x01\x20\x01(\tR\x06sym\
@@ -722,9 +2487,28 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \n\tDataPoint\x128\n\ttimestamp\x18\x01\x20\x01(\x0b2\x1a.google.protobu\
     f.TimestampR\ttimestamp\x12\x14\n\x05value\x18\x02\x20\x01(\x01R\x05valu\
     e\"2\n\nTimeSeries\x12$\n\x04data\x18\x01\x20\x03(\x0b2\x10.query.DataPo\
-    intR\x04data2@\n\nMarketData\x122\n\x05Query\x12\x14.query.RangedRequest\
-    \x1a\x11.query.TimeSeries\"\0B#Z!github.com/luckless-finance/queryb\x06p\
-    roto3\
+    intR\x04data\"@\n\x0eSymbolsRequest\x12\x1a\n\x08exchange\x18\x01\x20\
+    \x01(\tR\x08exchange\x12\x12\n\x04tags\x18\x02\x20\x03(\tR\x04tags\"T\n\
+    \nSymbolInfo\x12\x16\n\x06symbol\x18\x01\x20\x01(\tR\x06symbol\x12\x1a\n\
+    \x08exchange\x18\x02\x20\x01(\tR\x08exchange\x12\x12\n\x04tags\x18\x03\
+    \x20\x03(\tR\x04tags\"6\n\x07Symbols\x12+\n\x07symbols\x18\x01\x20\x03(\
+    \x0b2\x11.query.SymbolInfoR\x07symbols\"'\n\rSeriesRequest\x12\x16\n\x06\
+    symbol\x18\x01\x20\x01(\tR\x06symbol\"%\n\x0bSeriesNames\x12\x16\n\x06se\
+    ries\x18\x01\x20\x03(\tR\x06series\"@\n\x0cBatchRequest\x120\n\x08reques\
+    ts\x18\x01\x20\x03(\x0b2\x14.query.RangedRequestR\x08requests\"b\n\nSeri\
+    esData\x12\x16\n\x06symbol\x18\x01\x20\x01(\tR\x06symbol\x12\x16\n\x06se\
+    ries\x18\x02\x20\x01(\tR\x06series\x12$\n\x04data\x18\x03\x20\x03(\x0b2\
+    \x10.query.DataPointR\x04data\"<\n\rBatchResponse\x12+\n\x07results\x18\
+    \x01\x20\x03(\x0b2\x11.query.SeriesDataR\x07results\"r\n\x0fSeriesDataPo\
+    int\x12\x16\n\x06symbol\x18\x01\x20\x01(\tR\x06symbol\x12\x16\n\x06serie\
+    s\x18\x02\x20\x01(\tR\x06series\x12/\n\ndata_point\x18\x03\x20\x01(\x0b2\
+    \x10.query.DataPointR\tdataPoint2\xad\x02\n\nMarketData\x122\n\x05Query\
+    \x12\x14.query.RangedRequest\x1a\x11.query.TimeSeries\"\0\x126\n\x0bList\
+    Symbols\x12\x15.query.SymbolsRequest\x1a\x0e.query.Symbols\"\0\x128\n\nL\
+    istSeries\x12\x14.query.SeriesRequest\x1a\x12.query.SeriesNames\"\0\x129\
+    \n\nBatchQuery\x12\x13.query.BatchRequest\x1a\x14.query.BatchResponse\"\
+    \0\x12>\n\x0bStreamQuery\x12\x13.query.BatchRequest\x1a\x16.query.Series\
+    DataPoint\"\00\x01B#Z!github.com/luckless-finance/queryb\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -1,52 +1,508 @@
 use std::array::IntoIter;
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, HashMap, VecDeque};
 use std::convert::TryInto;
+use std::env;
+use std::fs::File;
+use std::io::Read;
 use std::iter::FromIterator;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
 
 use chrono::{DateTime, TimeZone, Utc};
+use dotenv::dotenv;
 use futures::executor;
-use grpc::{ClientConf, ClientStubExt};
+use grpc::{ClientConf, ClientStubExt, GrpcStatus};
+use log::{info, warn};
 use protobuf::well_known_types::Timestamp;
-use protobuf::SingularPtrField;
+use protobuf::{RepeatedField, SingularPtrField};
+use serde::{Deserialize, Serialize};
 
 use crate::bot::asset_score::CalculationStatus::Error;
-use crate::data::{Asset, DataClient, Query, Symbol};
+use crate::data::{from_proto, to_proto, Asset, DataClient, Query, Series, Symbol};
 use crate::dto::strategy::{from_path, StrategyDto};
 use crate::errors::{GenError, GenResult, QueryError};
-use crate::query::{DataPoint, RangedRequest};
+use crate::query::{
+    BatchRequest, DataPoint, RangedRequest, SeriesDataPoint, SeriesRequest, SymbolInfo,
+    SymbolsRequest, TimeSeries,
+};
 use crate::query_grpc::MarketDataClient;
 use crate::time_series::{DataPointValue, TimeSeries1D, TimeStamp};
 
+pub const DEFAULT_HOST: &str = "localhost";
+pub const DEFAULT_PORT: u16 = 50052;
+
+/// Series kept by the prefetch cache of a `QueryClient`, the oldest are evicted first. The cache
+/// grows to hold the largest prefetched batch.
+pub const MAX_CACHED_SERIES: usize = 4096;
+
+pub const QUERY_HOST: &str = "QUERY_HOST";
+pub const QUERY_PORT: &str = "QUERY_PORT";
+pub const QUERY_TLS: &str = "QUERY_TLS";
+pub const QUERY_TIMEOUT_MS: &str = "QUERY_TIMEOUT_MS";
+pub const QUERY_MAX_RETRIES: &str = "QUERY_MAX_RETRIES";
+pub const QUERY_BACKOFF_MS: &str = "QUERY_BACKOFF_MS";
+pub const QUERY_EXCHANGE: &str = "QUERY_EXCHANGE";
+pub const QUERY_TAGS: &str = "QUERY_TAGS";
+
+/// Connection settings of the Query `MarketData` gRPC service.
+///
+/// Settings are layered: defaults, then an optional yaml file, then the environment
+/// (including a `.env` file), then CLI flags.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QueryClientConfig {
+    pub host: String,
+    pub port: u16,
+    /// Use TLS (native-tls) instead of plain-text transport
+    pub tls: bool,
+    /// Deadline of a single request attempt
+    pub timeout_ms: u64,
+    /// Number of retries after an UNAVAILABLE response
+    pub max_retries: u32,
+    /// Initial delay between retries, doubled after every attempt
+    pub backoff_ms: u64,
+    /// Only discover symbols listed on this exchange
+    pub exchange: Option<String>,
+    /// Only discover symbols having all of these tags
+    pub tags: Vec<String>,
+}
+
+impl Default for QueryClientConfig {
+    fn default() -> Self {
+        QueryClientConfig {
+            host: DEFAULT_HOST.to_string(),
+            port: DEFAULT_PORT,
+            tls: false,
+            timeout_ms: 5_000,
+            max_retries: 3,
+            backoff_ms: 250,
+            exchange: None,
+            tags: vec![],
+        }
+    }
+}
+
+impl QueryClientConfig {
+    /// Load defaults, overridden by the optional yaml file at `config_path`, overridden by the environment.
+    pub fn load(config_path: Option<&Path>) -> GenResult<Self> {
+        let config = match config_path {
+            Some(path) => QueryClientConfig::from_path(path)?,
+            None => QueryClientConfig::default(),
+        };
+        config.with_env()
+    }
+    /// Parse yaml config file, missing keys take their default value.
+    pub fn from_path(file_path: &Path) -> GenResult<Self> {
+        let mut config_file = File::open(file_path).map_err(|e| {
+            QueryError::new(format!("unable to open config {:?}: {}", file_path, e))
+        })?;
+        let mut config_yaml = String::new();
+        config_file.read_to_string(&mut config_yaml)?;
+        Ok(serde_yaml::from_str(&config_yaml)?)
+    }
+    /// Override settings with `QUERY_*` environment variables, a `.env` file is honoured.
+    pub fn with_env(self) -> GenResult<Self> {
+        dotenv().ok();
+        self.with_vars(|name| env::var(name).ok())
+    }
+    /// Override settings with the variables resolved by `lookup`.
+    pub fn with_vars<F>(mut self, lookup: F) -> GenResult<Self>
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        if let Some(host) = lookup(QUERY_HOST) {
+            self.host = host;
+        }
+        if let Some(port) = parse_var(&lookup, QUERY_PORT)? {
+            self.port = port;
+        }
+        if let Some(tls) = parse_var(&lookup, QUERY_TLS)? {
+            self.tls = tls;
+        }
+        if let Some(timeout_ms) = parse_var(&lookup, QUERY_TIMEOUT_MS)? {
+            self.timeout_ms = timeout_ms;
+        }
+        if let Some(max_retries) = parse_var(&lookup, QUERY_MAX_RETRIES)? {
+            self.max_retries = max_retries;
+        }
+        if let Some(backoff_ms) = parse_var(&lookup, QUERY_BACKOFF_MS)? {
+            self.backoff_ms = backoff_ms;
+        }
+        if let Some(exchange) = lookup(QUERY_EXCHANGE) {
+            self.exchange = Some(exchange);
+        }
+        if let Some(tags) = lookup(QUERY_TAGS) {
+            self.tags = tags
+                .split(',')
+                .map(|tag| tag.trim().to_string())
+                .filter(|tag| !tag.is_empty())
+                .collect();
+        }
+        Ok(self)
+    }
+    pub fn timeout(&self) -> Duration {
+        Duration::from_millis(self.timeout_ms)
+    }
+    /// Delay before retry number `attempt` (zero based)
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        Duration::from_millis(self.backoff_ms.saturating_mul(1u64 << attempt.min(16)))
+    }
+}
+
+fn parse_var<F, T>(lookup: &F, name: &str) -> GenResult<Option<T>>
+where
+    F: Fn(&str) -> Option<String>,
+    T: FromStr,
+{
+    match lookup(name) {
+        None => Ok(None),
+        Some(value) => match value.trim().parse::<T>() {
+            Ok(parsed) => Ok(Some(parsed)),
+            Err(_) => Err(QueryError::new(format!(
+                "invalid value for {}: {:?}",
+                name, value
+            ))),
+        },
+    }
+}
+
+/// Prefetched series and the range it was fetched for
+struct CachedSeries {
+    first: TimeStamp,
+    last: TimeStamp,
+    time_series: TimeSeries1D,
+}
+
+impl CachedSeries {
+    fn covers(&self, query: &Query) -> bool {
+        self.first <= query.first() && query.last() <= self.last
+    }
+}
+
+/// Prefetched series by symbol and series, holding at most `capacity` series
+struct SeriesCache {
+    capacity: usize,
+    series: HashMap<(Symbol, Series), CachedSeries>,
+    /// Keys of `series`, oldest first
+    fetched: VecDeque<(Symbol, Series)>,
+}
+
+impl SeriesCache {
+    fn new(capacity: usize) -> Self {
+        SeriesCache {
+            capacity,
+            series: HashMap::new(),
+            fetched: VecDeque::new(),
+        }
+    }
+    fn get(&self, query: &Query) -> Option<&CachedSeries> {
+        self.series
+            .get(&(query.symbol().to_string(), query.series().to_string()))
+            .filter(|cached| cached.covers(query))
+    }
+    fn insert(&mut self, key: (Symbol, Series), cached: CachedSeries) {
+        if self.series.insert(key.clone(), cached).is_some() {
+            self.fetched.retain(|fetched| *fetched != key);
+        }
+        self.fetched.push_back(key);
+        while self.fetched.len() > self.capacity {
+            if let Some(oldest) = self.fetched.pop_front() {
+                self.series.remove(&oldest);
+            }
+        }
+    }
+}
+
 pub struct QueryClient {
-    market_data_client: MarketDataClient,
+    config: QueryClientConfig,
+    market_data_client: Arc<MarketDataClient>,
     assets: HashMap<Symbol, Asset>,
+    /// Shared by duplicates so a prefetch serves every asset run
+    cache: Arc<Mutex<SeriesCache>>,
 }
 
 impl QueryClient {
-    pub fn new() -> QueryClient {
-        QueryClient {
-            market_data_client: build_market_data_client(),
-            assets: HashMap::from_iter(IntoIter::new([
-                ("A".to_string(), Asset::new("A".to_string())),
-                ("B".to_string(), Asset::new("B".to_string())),
-            ])),
+    /// Connect and discover the asset universe, filtered by the configured exchange and tags.
+    pub fn new(config: QueryClientConfig) -> GenResult<QueryClient> {
+        let mut query_client = QueryClient::with_assets(config, vec![])?;
+        let symbols = query_client.list_symbols()?;
+        query_client.assets = symbols
+            .iter()
+            .map(|info| (info.symbol.clone(), Asset::new(info.symbol.clone())))
+            .collect();
+        Ok(query_client)
+    }
+    /// Connect with the given asset universe, skipping discovery.
+    pub fn with_assets(config: QueryClientConfig, assets: Vec<Asset>) -> GenResult<QueryClient> {
+        Ok(QueryClient {
+            market_data_client: Arc::new(build_market_data_client(&config)?),
+            config,
+            assets: assets
+                .into_iter()
+                .map(|asset| (asset.symbol().to_string(), asset))
+                .collect(),
+            cache: Arc::new(Mutex::new(SeriesCache::new(MAX_CACHED_SERIES))),
+        })
+    }
+    pub fn config(&self) -> &QueryClientConfig {
+        &self.config
+    }
+    /// Symbols known to the Query service matching the configured exchange and tags.
+    pub fn list_symbols(&self) -> GenResult<Vec<SymbolInfo>> {
+        let mut request = SymbolsRequest::new();
+        request.exchange = self.config.exchange.clone().unwrap_or_default();
+        request.tags = RepeatedField::from_vec(self.config.tags.clone());
+        let symbols = self.call("ListSymbols", request, |client, o, req| {
+            client.list_symbols(o, req)
+        })?;
+        Ok(symbols.symbols.into_vec())
+    }
+    /// Names of the series available for `symbol`.
+    pub fn list_series(&self, symbol: &str) -> GenResult<Vec<Series>> {
+        let mut request = SeriesRequest::new();
+        request.symbol = symbol.to_string();
+        let series_names = self.call("ListSeries", request, |client, o, req| {
+            client.list_series(o, req)
+        })?;
+        Ok(series_names.series.into_vec())
+    }
+    /// Fetch many series in a single round trip.
+    pub fn batch_query(
+        &self,
+        queries: Vec<Query>,
+    ) -> GenResult<Vec<(Symbol, Series, TimeSeries1D)>> {
+        let request = batch_request(queries)?;
+        let response = self.call("BatchQuery", request, |client, o, req| {
+            client.batch_query(o, req)
+        })?;
+        Ok(response
+            .results
+            .iter()
+            .map(|result| {
+                (
+                    result.symbol.clone(),
+                    result.series.clone(),
+                    to_time_series(result.get_data()),
+                )
+            })
+            .collect())
+    }
+    /// Fetch many series as a stream of data points, each item blocks until it is received.
+    ///
+    /// Unlike `batch_query` the response is not bounded by the maximum message size.  The stream
+    /// fails when no data point is received within the configured deadline, it is not retried.
+    pub fn stream_query(
+        &self,
+        queries: Vec<Query>,
+    ) -> GenResult<impl Iterator<Item = GenResult<SeriesDataPoint>>> {
+        let request = batch_request(queries)?;
+        let market_data_client = self.market_data_client.clone();
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            let stream = market_data_client
+                .stream_query(grpc::RequestOptions::new(), request)
+                .drop_metadata();
+            for item in executor::block_on_stream(stream) {
+                // the receiver is gone when the deadline has already expired
+                if sender.send(item).is_err() {
+                    break;
+                }
+            }
+        });
+        let timeout = self.config.timeout();
+        let mut expired = false;
+        Ok(std::iter::from_fn(move || {
+            if expired {
+                return None;
+            }
+            match receiver.recv_timeout(timeout) {
+                Ok(item) => Some(item.map_err(|e| {
+                    QueryError::new(format!("StreamQuery failed: {}", e)) as GenError
+                })),
+                Err(RecvTimeoutError::Disconnected) => None,
+                Err(RecvTimeoutError::Timeout) => {
+                    expired = true;
+                    Some(Err(QueryError::new(format!(
+                        "StreamQuery failed: no data point within {}ms",
+                        timeout.as_millis()
+                    ))))
+                }
+            }
+        }))
+    }
+
+    fn cached(&self, query: &Query) -> Option<TimeSeries1D> {
+        let cache = self.cache.lock().ok()?;
+        let cached = cache.get(query)?;
+        Some(
+            cached
+                .time_series
+                .filter_ge(&query.first())
+                .filter_le(&query.last()),
+        )
+    }
+
+    /// Invoke a unary rpc, retrying with backoff while the service is unavailable.
+    fn call<Req, Resp, F>(&self, rpc_name: &str, request: Req, rpc: F) -> GenResult<Resp>
+    where
+        Req: Clone + Send + 'static,
+        Resp: Send + 'static,
+        F: Fn(&MarketDataClient, grpc::RequestOptions, Req) -> grpc::SingleResponse<Resp>
+            + Copy
+            + Send
+            + 'static,
+    {
+        let mut attempt: u32 = 0;
+        loop {
+            match self.call_once(request.clone(), rpc) {
+                Ok(response) => return Ok(response),
+                Err(e) if is_unavailable(&e) && attempt < self.config.max_retries => {
+                    let backoff = self.config.backoff(attempt);
+                    warn!(
+                        "Query service unavailable ({}), retry {} of {} in {:?}",
+                        e,
+                        attempt + 1,
+                        self.config.max_retries,
+                        backoff
+                    );
+                    thread::sleep(backoff);
+                    attempt += 1;
+                }
+                Err(e) => {
+                    return Err(QueryError::new(format!(
+                        "{} failed after {} attempt(s): {}",
+                        rpc_name,
+                        attempt + 1,
+                        e
+                    )))
+                }
+            }
         }
     }
+
+    /// Single attempt bounded by the configured deadline.
+    fn call_once<Req, Resp, F>(&self, request: Req, rpc: F) -> Result<Resp, grpc::Error>
+    where
+        Req: Send + 'static,
+        Resp: Send + 'static,
+        F: Fn(&MarketDataClient, grpc::RequestOptions, Req) -> grpc::SingleResponse<Resp>
+            + Send
+            + 'static,
+    {
+        call_with_deadline(
+            self.market_data_client.clone(),
+            request,
+            rpc,
+            self.config.timeout(),
+        )
+    }
 }
 
-pub fn build_market_data_client() -> MarketDataClient {
-    const DEFAULT_PORT: u16 = 50052;
-    const HOST: &str = "localhost";
-    println!("Building gRPC client for {}:{:?}", HOST, DEFAULT_PORT);
-    MarketDataClient::new_plain(HOST, DEFAULT_PORT, ClientConf::new()).expect("client")
+/// Invoke a unary rpc of `client` on its own thread, giving up after `timeout`.
+///
+/// grpc has no deadline support, so an expired call keeps running in the background.
+pub(crate) fn call_with_deadline<C, Req, Resp, F>(
+    client: Arc<C>,
+    request: Req,
+    rpc: F,
+    timeout: Duration,
+) -> Result<Resp, grpc::Error>
+where
+    C: Send + Sync + 'static,
+    Req: Send + 'static,
+    Resp: Send + 'static,
+    F: Fn(&C, grpc::RequestOptions, Req) -> grpc::SingleResponse<Resp> + Send + 'static,
+{
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let result = executor::block_on(async {
+            let (_meta, resp) = rpc(&*client, grpc::RequestOptions::new(), request).await?;
+            let (response, _trailing) = resp.await?;
+            Ok::<Resp, grpc::Error>(response)
+        });
+        // the receiver is gone when the deadline has already expired
+        sender.send(result).ok();
+    });
+    match receiver.recv_timeout(timeout) {
+        Ok(result) => result,
+        Err(_) => Err(grpc::Error::GrpcMessage(grpc::GrpcMessageError {
+            grpc_status: GrpcStatus::DeadlineExceeded as i32,
+            grpc_message: format!("no response within {}ms", timeout.as_millis()),
+        })),
+    }
+}
+
+pub fn build_market_data_client(config: &QueryClientConfig) -> GenResult<MarketDataClient> {
+    info!(
+        "Building gRPC client for {}:{:?} (tls: {})",
+        config.host, config.port, config.tls
+    );
+    let conf = ClientConf::new();
+    let market_data_client = if config.tls {
+        MarketDataClient::new_tls::<tls_api_native_tls::TlsConnector>(
+            &config.host,
+            config.port,
+            conf,
+        )
+    } else {
+        MarketDataClient::new_plain(&config.host, config.port, conf)
+    };
+    market_data_client.map_err(|e| {
+        QueryError::new(format!(
+            "unable to build client for {}:{}: {}",
+            config.host, config.port, e
+        )) as GenError
+    })
+}
+
+/// Transport failures and UNAVAILABLE responses are transient and worth retrying.
+pub(crate) fn is_unavailable(error: &grpc::Error) -> bool {
+    match error {
+        grpc::Error::GrpcMessage(message) => message.grpc_status == GrpcStatus::Unavailable as i32,
+        grpc::Error::Io(_) | grpc::Error::Http(_) => true,
+        _ => false,
+    }
+}
+
+fn batch_request(queries: Vec<Query>) -> GenResult<BatchRequest> {
+    let mut request = BatchRequest::new();
+    for query in queries {
+        request.requests.push(query.try_into()?);
+    }
+    Ok(request)
+}
+
+pub(crate) fn to_time_series(data: &[DataPoint]) -> TimeSeries1D {
+    let mut temp: BTreeMap<TimeStamp, DataPointValue> = BTreeMap::new();
+    for data_point in data.iter() {
+        let timestamp: TimeStamp = from_proto(data_point.get_timestamp().clone());
+        temp.entry(timestamp).or_insert(data_point.value);
+    }
+    TimeSeries1D::new(temp)
+}
+
+pub(crate) fn to_proto_time_series(time_series: &TimeSeries1D) -> TimeSeries {
+    let mut proto_time_series = TimeSeries::new();
+    for (timestamp, value) in time_series.index().into_iter().zip(time_series.values()) {
+        let mut data_point = DataPoint::new();
+        data_point.timestamp = SingularPtrField::some(to_proto(timestamp));
+        data_point.value = value;
+        proto_time_series.data.push(data_point);
+    }
+    proto_time_series
 }
 
 impl DataClient for QueryClient {
     fn duplicate(&self) -> Box<dyn DataClient> {
         Box::new(QueryClient {
-            market_data_client: build_market_data_client(),
+            config: self.config.clone(),
+            market_data_client: self.market_data_client.clone(),
             assets: self.assets.clone(),
+            cache: self.cache.clone(),
         })
     }
 
@@ -62,35 +518,80 @@ impl DataClient for QueryClient {
     }
 
     fn query(&self, query: Query) -> GenResult<TimeSeries1D> {
-        let foo = executor::block_on(async {
-            let result = self
-                .market_data_client
-                .query(grpc::RequestOptions::new(), query.try_into()?)
-                .await;
-            // if result.is_err() {
-            //     println!();
-            //     Err(QueryError::new(format!("error connecting query server: {:?}", result.err().unwrap())))
-            // } else {
-            println!("connected to query server");
-            let (_meta, resp) = result.unwrap();
-            let mut temp: BTreeMap<TimeStamp, DataPointValue> = BTreeMap::new();
-            for data_point in resp.await.unwrap().0.data.iter() {
-                let timestamp = data_point.clone().timestamp.unwrap();
-                let timestamp: TimeStamp =
-                    Utc.timestamp(timestamp.seconds, timestamp.nanos.abs() as u32);
-                temp.entry(timestamp).or_insert(data_point.value);
-                // println!(
-                //     "timestamp: '{}', double: {:?}\n",
-                //     timestamp.to_rfc3339(),
-                //     data_point.double
-                // );
+        if let Some(time_series) = self.cached(&query) {
+            return Ok(time_series);
+        }
+        let request: RangedRequest = query.try_into()?;
+        let rpc_name = format!("Query {} {}", request.symbol, request.series);
+        let time_series = self.call(&rpc_name, request, |client, o, req| client.query(o, req))?;
+        Ok(to_time_series(time_series.get_data()))
+    }
+
+    /// Fetch the series of `queries` not cached yet with a single `StreamQuery`.
+    fn prefetch(&self, queries: Vec<Query>) -> GenResult<()> {
+        let queries: Vec<Query> = {
+            let cache = self
+                .cache
+                .lock()
+                .map_err(|_| QueryError::new("prefetch cache is poisoned".to_string()))?;
+            queries
+                .into_iter()
+                .filter(|query| cache.get(query).is_none())
+                .collect()
+        };
+        if queries.is_empty() {
+            return Ok(());
+        }
+        // a series queried more than once is fetched once over the union of the ranges
+        let mut ranges: HashMap<(Symbol, Series), (TimeStamp, TimeStamp)> = HashMap::new();
+        for q in &queries {
+            ranges
+                .entry((q.symbol().to_string(), q.series().to_string()))
+                .and_modify(|(first, last)| {
+                    *first = (*first).min(q.first());
+                    *last = (*last).max(q.last());
+                })
+                .or_insert((q.first(), q.last()));
+        }
+        let queries: Vec<Query> = ranges
+            .iter()
+            .map(|((symbol, series), (first, last))| {
+                Query::new(symbol.clone(), series.clone(), *first, *last)
+            })
+            .collect();
+        let mut data: HashMap<(Symbol, Series), BTreeMap<TimeStamp, DataPointValue>> = ranges
+            .keys()
+            .map(|key| (key.clone(), BTreeMap::new()))
+            .collect();
+        for item in self.stream_query(queries)? {
+            let series_data_point = item?;
+            let data_point = series_data_point.get_data_point();
+            data.entry((
+                series_data_point.symbol.clone(),
+                series_data_point.series.clone(),
+            ))
+            .or_default()
+            .entry(from_proto(data_point.get_timestamp().clone()))
+            .or_insert(data_point.value);
+        }
+        let mut cache = self
+            .cache
+            .lock()
+            .map_err(|_| QueryError::new("prefetch cache is poisoned".to_string()))?;
+        cache.capacity = cache.capacity.max(data.len());
+        for (key, values) in data {
+            if let Some((first, last)) = ranges.get(&key) {
+                cache.insert(
+                    key,
+                    CachedSeries {
+                        first: *first,
+                        last: *last,
+                        time_series: TimeSeries1D::new(values),
+                    },
+                );
             }
-            GenResult::Ok(TimeSeries1D::new(temp))
-        });
-        match foo {
-            Err(e) => Err(QueryError::new(format!("fasdf {:?}", e))),
-            Ok(ts) => Ok(ts),
         }
+        Ok(())
     }
 }
 
@@ -150,60 +651,287 @@ mod tests {
 
     use crate::data::to_proto;
     use crate::query::RangedRequest;
-    use crate::query_client::{build_market_data_client, parse_date, QueryClient};
+    use std::collections::HashMap;
+    use std::convert::TryInto;
+    use std::time::Duration as StdDuration;
+
+    use crate::data::{doomsday, epoch, Asset, DataClient, Query};
+    use crate::errors::GenResult;
+    use crate::mock_client::MockDataClient;
+    use crate::query::TimeSeries;
+    use crate::query_client::{
+        build_market_data_client, is_unavailable, parse_date, CachedSeries, QueryClient,
+        QueryClientConfig, SeriesCache, QUERY_HOST, QUERY_PORT, QUERY_TAGS, QUERY_TLS,
+    };
     use crate::query_grpc::MarketDataClient;
+    use crate::query_server::{serve, LOCAL_HOST};
     use crate::time_series::{TimeSeries1D, TimeStamp};
     use futures::executor;
     use grpc::{ClientConf, ClientStubExt};
+    use std::sync::Arc;
 
     fn build_request() -> RangedRequest {
         let mut request = RangedRequest::new();
-        let now_pb = to_proto(Utc::now());
+        let today_pb = to_proto(MockDataClient::today());
 
-        request.symbol = "RUST".to_string();
+        request.symbol = "A".to_string();
         request.series = "CLOSE".to_string();
-        request.first = SingularPtrField::some(now_pb.clone());
-        request.last = SingularPtrField::some(now_pb);
+        request.first = SingularPtrField::some(today_pb.clone());
+        request.last = SingularPtrField::some(today_pb);
         request
     }
 
-    pub async fn query_server(client: &MarketDataClient) {
+    /// Serve `MockDataClient` on an ephemeral port.
+    fn local_server() -> (grpc::Server, u16) {
+        let server = serve(Arc::new(MockDataClient::new()), 0).expect("server");
+        let port = server.local_addr().port().expect("port");
+        (server, port)
+    }
+
+    fn local_config(port: u16) -> QueryClientConfig {
+        QueryClientConfig {
+            host: LOCAL_HOST.to_string(),
+            port,
+            ..QueryClientConfig::default()
+        }
+    }
+
+    pub async fn query_server(client: &MarketDataClient) -> grpc::Result<TimeSeries> {
         println!("query server non-stream");
-        let result = client
+        let (_meta, resp) = client
             .query(grpc::RequestOptions::new(), build_request())
-            .await;
-        if result.is_err() {
-            println!("error connecting query server: {:?}", result.err().unwrap());
-        } else {
-            println!("connected to query server");
-            let (_meta, resp) = result.unwrap();
-            for data_point in resp.await.unwrap().0.data.iter() {
-                let timestamp = data_point.clone().timestamp.unwrap();
-                let timestamp: TimeStamp =
-                    Utc.timestamp(timestamp.seconds, timestamp.nanos.abs() as u32);
-                println!(
-                    "timestamp: '{}', double: {:?}\n",
-                    timestamp.to_rfc3339(),
-                    data_point.value
-                );
-            }
+            .await?;
+        let (time_series, _trailers) = resp.await?;
+        for data_point in time_series.data.iter() {
+            let timestamp = data_point.clone().timestamp.unwrap();
+            let timestamp: TimeStamp =
+                Utc.timestamp(timestamp.seconds, timestamp.nanos.abs() as u32);
+            println!(
+                "timestamp: '{}', double: {:?}\n",
+                timestamp.to_rfc3339(),
+                data_point.value
+            );
         }
+        Ok(time_series)
     }
     const DEFAULT_PORT: u16 = 50052;
     const HOST: &str = "localhost";
 
     #[test]
     fn query_single_data_point() {
-        println!("gRPC client connecting to {}:{:?}", HOST, DEFAULT_PORT);
+        let (_server, port) = local_server();
+        println!("gRPC client connecting to {}:{:?}", LOCAL_HOST, port);
         let client =
-            MarketDataClient::new_plain(HOST, DEFAULT_PORT, ClientConf::new()).expect("client");
-        executor::block_on(async { query_server(&client).await });
+            MarketDataClient::new_plain(LOCAL_HOST, port, ClientConf::new()).expect("client");
+        let time_series =
+            executor::block_on(async { query_server(&client).await }).expect("time series");
+        assert_eq!(time_series.data.len(), 1);
+        assert_eq!(
+            time_series.data[0].get_timestamp(),
+            &to_proto(MockDataClient::today())
+        );
     }
 
     #[test]
     fn server_grpc_server() {
-        let client = build_market_data_client();
-        query_server(&client);
+        let (_server, port) = local_server();
+        let client = build_market_data_client(&local_config(port)).expect("client");
+        let time_series =
+            executor::block_on(async { query_server(&client).await }).expect("time series");
+        assert_eq!(time_series.data.len(), 1);
+    }
+
+    #[test]
+    fn query_client_end_to_end() -> GenResult<()> {
+        let (_server, port) = local_server();
+        let client = QueryClient::new(local_config(port))?;
+        // assets are discovered with ListSymbols
+        assert_eq!(client.assets().len(), 3);
+        let asset = client.asset(&String::from("A"))?.clone();
+        let expected = MockDataClient::new().query(asset.clone().try_into()?)?;
+        assert_eq!(client.query(asset.clone().try_into()?)?, expected);
+        let queries: Vec<Query> = vec![asset.clone().try_into()?, asset.clone().try_into()?];
+        assert_eq!(client.batch_query(queries)?.len(), 2);
+        // prefetched queries are served from cache once the server is gone
+        client.prefetch(vec![asset.try_into()?])?;
+        drop(_server);
+        assert_eq!(
+            client.query(Asset::new(String::from("A")).try_into()?)?,
+            expected
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn config_defaults() {
+        let config = QueryClientConfig::default();
+        assert_eq!(config.host, HOST);
+        assert_eq!(config.port, DEFAULT_PORT);
+        assert!(!config.tls);
+        assert_eq!(config.backoff(0), StdDuration::from_millis(250));
+        assert_eq!(config.backoff(2), StdDuration::from_millis(1000));
+    }
+
+    #[test]
+    fn config_from_yaml() {
+        let config: QueryClientConfig =
+            serde_yaml::from_str("host: query.luckless.finance\nport: 443\ntls: true\n")
+                .expect("unable to parse config");
+        assert_eq!(config.host, "query.luckless.finance");
+        assert_eq!(config.port, 443);
+        assert!(config.tls);
+        // omitted keys fall back to defaults
+        assert_eq!(config.max_retries, QueryClientConfig::default().max_retries);
+    }
+
+    #[test]
+    fn config_with_vars() {
+        let vars: HashMap<&str, &str> = vec![
+            (QUERY_HOST, "query"),
+            (QUERY_TLS, "true"),
+            (QUERY_TAGS, "sp500, tech"),
+        ]
+        .into_iter()
+        .collect();
+        let config = QueryClientConfig::default()
+            .with_vars(|name| vars.get(name).map(|v| v.to_string()))
+            .expect("valid vars");
+        assert_eq!(config.host, "query");
+        assert_eq!(config.port, DEFAULT_PORT);
+        assert!(config.tls);
+        assert_eq!(config.tags, vec!["sp500", "tech"]);
+        assert_eq!(config.exchange, None);
+    }
+
+    #[test]
+    fn config_with_invalid_var() {
+        let result = QueryClientConfig::default().with_vars(|name| match name {
+            QUERY_PORT => Some("not-a-port".to_string()),
+            _ => None,
+        });
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn unavailable_is_retryable() {
+        let unavailable = grpc::Error::GrpcMessage(grpc::GrpcMessageError {
+            grpc_status: grpc::GrpcStatus::Unavailable as i32,
+            grpc_message: String::from("unavailable"),
+        });
+        let not_found = grpc::Error::GrpcMessage(grpc::GrpcMessageError {
+            grpc_status: grpc::GrpcStatus::NotFound as i32,
+            grpc_message: String::from("not found"),
+        });
+        assert!(is_unavailable(&unavailable));
+        assert!(!is_unavailable(&not_found));
+    }
+
+    #[test]
+    fn unreachable_server_is_query_error() {
+        let config = QueryClientConfig {
+            // nothing listens on the discard port
+            port: 9,
+            timeout_ms: 500,
+            max_retries: 1,
+            backoff_ms: 10,
+            ..QueryClientConfig::default()
+        };
+        let asset = Asset::new(String::from("A"));
+        let client = QueryClient::with_assets(config.clone(), vec![asset.clone()]).expect("client");
+        let result = client.query(asset.try_into().expect("query"));
+        assert!(result.is_err());
+        // asset discovery fails the same way
+        assert!(QueryClient::new(config).is_err());
+    }
+
+    #[test]
+    fn prefetched_query_is_served_from_cache() -> GenResult<()> {
+        let config = QueryClientConfig {
+            port: 9,
+            max_retries: 0,
+            ..QueryClientConfig::default()
+        };
+        let client = QueryClient::with_assets(config, vec![])?;
+        client.cache.lock().expect("cache").insert(
+            (String::from("A"), String::from("close")),
+            CachedSeries {
+                first: epoch(),
+                last: doomsday(),
+                time_series: TimeSeries1D::from_values(vec![1., 2., 3.]),
+            },
+        );
+        let last = TimeSeries1D::epoch() + TimeSeries1D::index_unit();
+        let query = Query::new(String::from("A"), String::from("close"), epoch(), last);
+        assert_eq!(client.query(query)?.values(), vec![1., 2.]);
+        // not prefetched, so the unreachable server is asked
+        let query = Query::complete(String::from("B"), String::from("close"));
+        assert!(client.query(query).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn cached_queries_are_not_prefetched() -> GenResult<()> {
+        let config = QueryClientConfig {
+            port: 9,
+            timeout_ms: 500,
+            ..QueryClientConfig::default()
+        };
+        let client = QueryClient::with_assets(config, vec![])?;
+        client.cache.lock().expect("cache").insert(
+            (String::from("A"), String::from("close")),
+            CachedSeries {
+                first: epoch(),
+                last: doomsday(),
+                time_series: TimeSeries1D::from_values(vec![1., 2., 3.]),
+            },
+        );
+        let today = MockDataClient::today();
+        let query = Query::new(String::from("A"), String::from("close"), epoch(), today);
+        client.prefetch(vec![query])?;
+        // not cached, so the unreachable server is asked
+        let query = Query::new(String::from("B"), String::from("close"), epoch(), today);
+        assert!(client.prefetch(vec![query]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn prefetch_holds_the_batch_and_merges_ranges() -> GenResult<()> {
+        let (_server, port) = local_server();
+        let client = QueryClient::with_assets(local_config(port), vec![])?;
+        client.cache.lock().expect("cache").capacity = 1;
+        let today = MockDataClient::today();
+        let middle = epoch() + (today - epoch()) / 2;
+        let query = |symbol: &str, first, last| {
+            Query::new(symbol.to_string(), String::from("close"), first, last)
+        };
+        client.prefetch(vec![
+            query("A", epoch(), middle),
+            query("A", middle, today),
+            query("B", epoch(), today),
+            query("C", epoch(), today),
+        ])?;
+        let cache = client.cache.lock().expect("cache");
+        for symbol in &["A", "B", "C"] {
+            assert!(cache.get(&query(symbol, epoch(), today)).is_some());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn series_cache_evicts_oldest() {
+        let cached = || CachedSeries {
+            first: epoch(),
+            last: doomsday(),
+            time_series: TimeSeries1D::from_values(vec![1.]),
+        };
+        let query = |symbol: &str| Query::complete(symbol.to_string(), String::from("close"));
+        let mut cache = SeriesCache::new(2);
+        for symbol in &["A", "B", "A", "C"] {
+            cache.insert((symbol.to_string(), String::from("close")), cached());
+        }
+        assert!(cache.get(&query("A")).is_some());
+        assert!(cache.get(&query("B")).is_none());
+        assert!(cache.get(&query("C")).is_some());
     }
 
     #[test]
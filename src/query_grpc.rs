@@ -23,6 +23,14 @@
 
 pub trait MarketData {
     fn query(&self, o: ::grpc::ServerHandlerContext, req: ::grpc::ServerRequestSingle<super::query::RangedRequest>, resp: ::grpc::ServerResponseUnarySink<super::query::TimeSeries>) -> ::grpc::Result<()>;
+
+    fn list_symbols(&self, o: ::grpc::ServerHandlerContext, req: ::grpc::ServerRequestSingle<super::query::SymbolsRequest>, resp: ::grpc::ServerResponseUnarySink<super::query::Symbols>) -> ::grpc::Result<()>;
+
+    fn list_series(&self, o: ::grpc::ServerHandlerContext, req: ::grpc::ServerRequestSingle<super::query::SeriesRequest>, resp: ::grpc::ServerResponseUnarySink<super::query::SeriesNames>) -> ::grpc::Result<()>;
+
+    fn batch_query(&self, o: ::grpc::ServerHandlerContext, req: ::grpc::ServerRequestSingle<super::query::BatchRequest>, resp: ::grpc::ServerResponseUnarySink<super::query::BatchResponse>) -> ::grpc::Result<()>;
+
+    fn stream_query(&self, o: ::grpc::ServerHandlerContext, req: ::grpc::ServerRequestSingle<super::query::BatchRequest>, resp: ::grpc::ServerResponseSink<super::query::SeriesDataPoint>) -> ::grpc::Result<()>;
 }
 
 // client
@@ -49,6 +57,46 @@ impl MarketDataClient {
         });
         self.grpc_client.call_unary(o, req, descriptor)
     }
+
+    pub fn list_symbols(&self, o: ::grpc::RequestOptions, req: super::query::SymbolsRequest) -> ::grpc::SingleResponse<super::query::Symbols> {
+        let descriptor = ::grpc::rt::ArcOrStatic::Static(&::grpc::rt::MethodDescriptor {
+            name: ::grpc::rt::StringOrStatic::Static("/query.MarketData/ListSymbols"),
+            streaming: ::grpc::rt::GrpcStreaming::Unary,
+            req_marshaller: ::grpc::rt::ArcOrStatic::Static(&::grpc_protobuf::MarshallerProtobuf),
+            resp_marshaller: ::grpc::rt::ArcOrStatic::Static(&::grpc_protobuf::MarshallerProtobuf),
+        });
+        self.grpc_client.call_unary(o, req, descriptor)
+    }
+
+    pub fn list_series(&self, o: ::grpc::RequestOptions, req: super::query::SeriesRequest) -> ::grpc::SingleResponse<super::query::SeriesNames> {
+        let descriptor = ::grpc::rt::ArcOrStatic::Static(&::grpc::rt::MethodDescriptor {
+            name: ::grpc::rt::StringOrStatic::Static("/query.MarketData/ListSeries"),
+            streaming: ::grpc::rt::GrpcStreaming::Unary,
+            req_marshaller: ::grpc::rt::ArcOrStatic::Static(&::grpc_protobuf::MarshallerProtobuf),
+            resp_marshaller: ::grpc::rt::ArcOrStatic::Static(&::grpc_protobuf::MarshallerProtobuf),
+        });
+        self.grpc_client.call_unary(o, req, descriptor)
+    }
+
+    pub fn batch_query(&self, o: ::grpc::RequestOptions, req: super::query::BatchRequest) -> ::grpc::SingleResponse<super::query::BatchResponse> {
+        let descriptor = ::grpc::rt::ArcOrStatic::Static(&::grpc::rt::MethodDescriptor {
+            name: ::grpc::rt::StringOrStatic::Static("/query.MarketData/BatchQuery"),
+            streaming: ::grpc::rt::GrpcStreaming::Unary,
+            req_marshaller: ::grpc::rt::ArcOrStatic::Static(&::grpc_protobuf::MarshallerProtobuf),
+            resp_marshaller: ::grpc::rt::ArcOrStatic::Static(&::grpc_protobuf::MarshallerProtobuf),
+        });
+        self.grpc_client.call_unary(o, req, descriptor)
+    }
+
+    pub fn stream_query(&self, o: ::grpc::RequestOptions, req: super::query::BatchRequest) -> ::grpc::StreamingResponse<super::query::SeriesDataPoint> {
+        let descriptor = ::grpc::rt::ArcOrStatic::Static(&::grpc::rt::MethodDescriptor {
+            name: ::grpc::rt::StringOrStatic::Static("/query.MarketData/StreamQuery"),
+            streaming: ::grpc::rt::GrpcStreaming::ServerStreaming,
+            req_marshaller: ::grpc::rt::ArcOrStatic::Static(&::grpc_protobuf::MarshallerProtobuf),
+            resp_marshaller: ::grpc::rt::ArcOrStatic::Static(&::grpc_protobuf::MarshallerProtobuf),
+        });
+        self.grpc_client.call_server_streaming(o, req, descriptor)
+    }
 }
 
 // server
@@ -73,6 +121,54 @@ impl MarketDataServer {
                         ::grpc::rt::MethodHandlerUnary::new(move |ctx, req, resp| (*handler_copy).query(ctx, req, resp))
                     },
                 ),
+                ::grpc::rt::ServerMethod::new(
+                    ::grpc::rt::ArcOrStatic::Static(&::grpc::rt::MethodDescriptor {
+                        name: ::grpc::rt::StringOrStatic::Static("/query.MarketData/ListSymbols"),
+                        streaming: ::grpc::rt::GrpcStreaming::Unary,
+                        req_marshaller: ::grpc::rt::ArcOrStatic::Static(&::grpc_protobuf::MarshallerProtobuf),
+                        resp_marshaller: ::grpc::rt::ArcOrStatic::Static(&::grpc_protobuf::MarshallerProtobuf),
+                    }),
+                    {
+                        let handler_copy = handler_arc.clone();
+                        ::grpc::rt::MethodHandlerUnary::new(move |ctx, req, resp| (*handler_copy).list_symbols(ctx, req, resp))
+                    },
+                ),
+                ::grpc::rt::ServerMethod::new(
+                    ::grpc::rt::ArcOrStatic::Static(&::grpc::rt::MethodDescriptor {
+                        name: ::grpc::rt::StringOrStatic::Static("/query.MarketData/ListSeries"),
+                        streaming: ::grpc::rt::GrpcStreaming::Unary,
+                        req_marshaller: ::grpc::rt::ArcOrStatic::Static(&::grpc_protobuf::MarshallerProtobuf),
+                        resp_marshaller: ::grpc::rt::ArcOrStatic::Static(&::grpc_protobuf::MarshallerProtobuf),
+                    }),
+                    {
+                        let handler_copy = handler_arc.clone();
+                        ::grpc::rt::MethodHandlerUnary::new(move |ctx, req, resp| (*handler_copy).list_series(ctx, req, resp))
+                    },
+                ),
+                ::grpc::rt::ServerMethod::new(
+                    ::grpc::rt::ArcOrStatic::Static(&::grpc::rt::MethodDescriptor {
+                        name: ::grpc::rt::StringOrStatic::Static("/query.MarketData/BatchQuery"),
+                        streaming: ::grpc::rt::GrpcStreaming::Unary,
+                        req_marshaller: ::grpc::rt::ArcOrStatic::Static(&::grpc_protobuf::MarshallerProtobuf),
+                        resp_marshaller: ::grpc::rt::ArcOrStatic::Static(&::grpc_protobuf::MarshallerProtobuf),
+                    }),
+                    {
+                        let handler_copy = handler_arc.clone();
+                        ::grpc::rt::MethodHandlerUnary::new(move |ctx, req, resp| (*handler_copy).batch_query(ctx, req, resp))
+                    },
+                ),
+                ::grpc::rt::ServerMethod::new(
+                    ::grpc::rt::ArcOrStatic::Static(&::grpc::rt::MethodDescriptor {
+                        name: ::grpc::rt::StringOrStatic::Static("/query.MarketData/StreamQuery"),
+                        streaming: ::grpc::rt::GrpcStreaming::ServerStreaming,
+                        req_marshaller: ::grpc::rt::ArcOrStatic::Static(&::grpc_protobuf::MarshallerProtobuf),
+                        resp_marshaller: ::grpc::rt::ArcOrStatic::Static(&::grpc_protobuf::MarshallerProtobuf),
+                    }),
+                    {
+                        let handler_copy = handler_arc.clone();
+                        ::grpc::rt::MethodHandlerServerStreaming::new(move |ctx, req, resp| (*handler_copy).stream_query(ctx, req, resp))
+                    },
+                ),
             ],
         )
     }
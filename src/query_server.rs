@@ -0,0 +1,264 @@
+use std::sync::Arc;
+use std::thread;
+
+use grpc::{
+    Metadata, ServerHandlerContext, ServerRequestSingle, ServerResponseSink,
+    ServerResponseUnarySink,
+};
+use log::warn;
+use protobuf::{RepeatedField, SingularPtrField};
+
+use crate::bot_server::{grpc_status, reply};
+use crate::data::{doomsday, epoch, from_proto, DataClient, Query, DEFAULT_SERIES};
+use crate::errors::{GenResult, QueryError};
+use crate::query::{
+    BatchRequest, BatchResponse, RangedRequest, SeriesData, SeriesDataPoint, SeriesNames,
+    SeriesRequest, SymbolInfo, Symbols, SymbolsRequest, TimeSeries,
+};
+use crate::query_client::to_proto_time_series;
+use crate::query_grpc::{MarketData, MarketDataServer};
+
+/// Address the stand-in server binds to.
+pub const LOCAL_HOST: &str = "127.0.0.1";
+
+/// Stand-in for the Query service, serving the `MarketData` rpcs from any `DataClient`.
+///
+/// `DataClient` has no notion of exchanges, tags or series names, so every asset is listed
+/// without exchange or tags and offers the single `DEFAULT_SERIES`.
+#[derive(Clone)]
+pub struct MarketDataService {
+    data_client: Arc<dyn DataClient + Send + Sync>,
+}
+
+impl MarketDataService {
+    pub fn new(data_client: Arc<dyn DataClient + Send + Sync>) -> Self {
+        MarketDataService { data_client }
+    }
+
+    pub fn query(&self, request: &RangedRequest) -> GenResult<TimeSeries> {
+        // an unknown symbol is NOT_FOUND rather than whatever the data client reports
+        self.data_client.asset(&request.symbol)?;
+        let first = request
+            .first
+            .as_ref()
+            .cloned()
+            .map_or_else(epoch, from_proto);
+        let last = request
+            .last
+            .as_ref()
+            .cloned()
+            .map_or_else(doomsday, from_proto);
+        let query = Query::new(request.symbol.clone(), request.series.clone(), first, last);
+        let time_series = self.data_client.query(query)?;
+        Ok(to_proto_time_series(&time_series.filter_ge(&first)))
+    }
+
+    pub fn list_symbols(&self, request: &SymbolsRequest) -> Symbols {
+        let mut symbols: Vec<SymbolInfo> = self
+            .data_client
+            .assets()
+            .keys()
+            .map(|symbol| {
+                let mut symbol_info = SymbolInfo::new();
+                symbol_info.symbol = symbol.clone();
+                symbol_info
+            })
+            .filter(|symbol_info| {
+                (request.exchange.is_empty() || request.exchange == symbol_info.exchange)
+                    && request
+                        .tags
+                        .iter()
+                        .all(|tag| symbol_info.tags.contains(tag))
+            })
+            .collect();
+        symbols.sort_by(|a, b| a.symbol.cmp(&b.symbol));
+        let mut response = Symbols::new();
+        response.symbols = RepeatedField::from_vec(symbols);
+        response
+    }
+
+    pub fn list_series(&self, request: &SeriesRequest) -> GenResult<SeriesNames> {
+        self.data_client.asset(&request.symbol)?;
+        let mut response = SeriesNames::new();
+        response.series = RepeatedField::from_vec(vec![DEFAULT_SERIES.to_string()]);
+        Ok(response)
+    }
+
+    pub fn batch_query(&self, request: &BatchRequest) -> GenResult<BatchResponse> {
+        let mut response = BatchResponse::new();
+        for ranged_request in request.requests.iter() {
+            let mut series_data = SeriesData::new();
+            series_data.symbol = ranged_request.symbol.clone();
+            series_data.series = ranged_request.series.clone();
+            series_data.data = self.query(ranged_request)?.data;
+            response.results.push(series_data);
+        }
+        Ok(response)
+    }
+
+    /// Flatten a batch into data points, in request order.
+    pub fn stream_query(&self, request: &BatchRequest) -> GenResult<Vec<SeriesDataPoint>> {
+        let mut data_points = vec![];
+        for ranged_request in request.requests.iter() {
+            for data_point in self.query(ranged_request)?.data.into_iter() {
+                let mut series_data_point = SeriesDataPoint::new();
+                series_data_point.symbol = ranged_request.symbol.clone();
+                series_data_point.series = ranged_request.series.clone();
+                series_data_point.data_point = SingularPtrField::some(data_point);
+                data_points.push(series_data_point);
+            }
+        }
+        Ok(data_points)
+    }
+}
+
+impl MarketData for MarketDataService {
+    fn query(
+        &self,
+        _o: ServerHandlerContext,
+        req: ServerRequestSingle<RangedRequest>,
+        resp: ServerResponseUnarySink<TimeSeries>,
+    ) -> grpc::Result<()> {
+        let service = self.clone();
+        thread::spawn(move || reply(resp, service.query(&req.message)));
+        Ok(())
+    }
+
+    fn list_symbols(
+        &self,
+        _o: ServerHandlerContext,
+        req: ServerRequestSingle<SymbolsRequest>,
+        resp: ServerResponseUnarySink<Symbols>,
+    ) -> grpc::Result<()> {
+        let service = self.clone();
+        thread::spawn(move || reply(resp, Ok(service.list_symbols(&req.message))));
+        Ok(())
+    }
+
+    fn list_series(
+        &self,
+        _o: ServerHandlerContext,
+        req: ServerRequestSingle<SeriesRequest>,
+        resp: ServerResponseUnarySink<SeriesNames>,
+    ) -> grpc::Result<()> {
+        let service = self.clone();
+        thread::spawn(move || reply(resp, service.list_series(&req.message)));
+        Ok(())
+    }
+
+    fn batch_query(
+        &self,
+        _o: ServerHandlerContext,
+        req: ServerRequestSingle<BatchRequest>,
+        resp: ServerResponseUnarySink<BatchResponse>,
+    ) -> grpc::Result<()> {
+        let service = self.clone();
+        thread::spawn(move || reply(resp, service.batch_query(&req.message)));
+        Ok(())
+    }
+
+    fn stream_query(
+        &self,
+        _o: ServerHandlerContext,
+        req: ServerRequestSingle<BatchRequest>,
+        mut resp: ServerResponseSink<SeriesDataPoint>,
+    ) -> grpc::Result<()> {
+        let service = self.clone();
+        thread::spawn(move || {
+            let sent = match service.stream_query(&req.message) {
+                Ok(data_points) => data_points
+                    .into_iter()
+                    .try_for_each(|data_point| resp.send_data(data_point))
+                    .and_then(|_| resp.send_trailers(Metadata::new())),
+                Err(e) => resp.send_grpc_error(grpc_status(&e), e.to_string()),
+            };
+            if let Err(e) = sent {
+                warn!("unable to send response: {}", e);
+            }
+        });
+        Ok(())
+    }
+}
+
+/// Host the `MarketData` service on `port` (0 for an ephemeral port), the returned server stops
+/// when dropped.
+///
+/// The bound port is `server.local_addr().port()`.
+pub fn serve(data_client: Arc<dyn DataClient + Send + Sync>, port: u16) -> GenResult<grpc::Server> {
+    let mut server_builder = grpc::ServerBuilder::new_plain();
+    server_builder
+        .http
+        .set_addr((LOCAL_HOST, port))
+        .map_err(|e| QueryError::new(format!("invalid port {}: {}", port, e)))?;
+    server_builder.add_service(MarketDataServer::new_service_def(MarketDataService::new(
+        data_client,
+    )));
+    let server = server_builder
+        .build()
+        .map_err(|e| QueryError::new(format!("unable to serve on port {}: {}", port, e)))?;
+    Ok(server)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use grpc::GrpcStatus;
+    use protobuf::SingularPtrField;
+
+    use crate::bot_server::grpc_status;
+    use crate::data::to_proto;
+    use crate::errors::GenResult;
+    use crate::mock_client::MockDataClient;
+    use crate::query::{RangedRequest, SeriesRequest, SymbolsRequest};
+    use crate::query_server::MarketDataService;
+    use crate::time_series::TimeSeries1D;
+
+    fn service_fixture() -> MarketDataService {
+        MarketDataService::new(Arc::new(MockDataClient::new()))
+    }
+
+    #[test]
+    fn query_range() -> GenResult<()> {
+        let last = MockDataClient::today();
+        let mut request = RangedRequest::new();
+        request.symbol = String::from("A");
+        request.series = String::from("price");
+        request.first = SingularPtrField::some(to_proto(last - TimeSeries1D::index_unit()));
+        request.last = SingularPtrField::some(to_proto(last));
+        assert_eq!(service_fixture().query(&request)?.data.len(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn query_unknown_symbol() {
+        let mut request = RangedRequest::new();
+        request.symbol = String::from("ZZZ");
+        let error = service_fixture().query(&request).err().expect("error");
+        assert!(matches!(grpc_status(&error), GrpcStatus::NotFound));
+    }
+
+    #[test]
+    fn list_symbols() {
+        let service = service_fixture();
+        let symbols: Vec<String> = service
+            .list_symbols(&SymbolsRequest::new())
+            .symbols
+            .iter()
+            .map(|symbol_info| symbol_info.symbol.clone())
+            .collect();
+        assert_eq!(symbols, vec!["A", "B", "C"]);
+        // mock assets are not listed on any exchange
+        let mut request = SymbolsRequest::new();
+        request.exchange = String::from("NYSE");
+        assert!(service.list_symbols(&request).symbols.is_empty());
+    }
+
+    #[test]
+    fn list_series() -> GenResult<()> {
+        let mut request = SeriesRequest::new();
+        request.symbol = String::from("A");
+        assert_eq!(service_fixture().list_series(&request)?.series.len(), 1);
+        Ok(())
+    }
+}
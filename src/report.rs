@@ -0,0 +1,352 @@
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::io::Write;
+use std::path::Path;
+
+use chrono::{SecondsFormat, Utc};
+use serde::Serialize;
+
+use crate::bot::asset_score::{AssetScore, AssetScoreStatus};
+use crate::data::{Asset, Symbol};
+use crate::errors::{FormatError, GenError, GenResult};
+use crate::time_series::{DataPointValue, TimeSeries1D, TimeStamp};
+
+/// Bumped whenever a field of the report is renamed or removed
+pub const REPORT_SCHEMA_VERSION: u32 = 1;
+
+/// How the results of a run are written for other tools, or for a person at a terminal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Json,
+    Csv,
+    Yaml,
+    Table,
+}
+
+impl OutputFormat {
+    /// Format of an output file by extension, `Table` for unknown extensions.
+    pub fn from_path(path: &Path) -> Self {
+        path.extension()
+            .and_then(|extension| extension.to_str())
+            .and_then(|extension| OutputFormat::try_from(extension).ok())
+            .unwrap_or(OutputFormat::Table)
+    }
+}
+
+impl TryFrom<&str> for OutputFormat {
+    type Error = GenError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.to_uppercase().as_str() {
+            "JSON" => Ok(OutputFormat::Json),
+            "CSV" => Ok(OutputFormat::Csv),
+            "YAML" | "YML" => Ok(OutputFormat::Yaml),
+            "TABLE" | "TXT" => Ok(OutputFormat::Table),
+            _ => Err(FormatError::new(format!("unknown output format {}", value))),
+        }
+    }
+}
+
+pub fn parse_output_format(arg: &str) -> Result<OutputFormat, String> {
+    OutputFormat::try_from(arg).map_err(|e| e.to_string())
+}
+
+/// What was run, when and on which data.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RunMetadata {
+    pub schema_version: u32,
+    pub luckless_version: String,
+    pub strategy: String,
+    /// As of timestamp of the scores
+    pub timestamp: TimeStamp,
+    /// Wall clock time the report was made
+    pub generated_at: TimeStamp,
+    /// eg. `mock` or the Query service address
+    pub data_source: String,
+}
+
+impl RunMetadata {
+    pub fn new(strategy: &str, timestamp: TimeStamp, data_source: &str) -> Self {
+        RunMetadata {
+            schema_version: REPORT_SCHEMA_VERSION,
+            luckless_version: env!("CARGO_PKG_VERSION").to_string(),
+            strategy: strategy.to_string(),
+            timestamp,
+            generated_at: Utc::now(),
+            data_source: data_source.to_string(),
+        }
+    }
+}
+
+/// Latest score of an asset, with the full score series when requested.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ScoreRecord {
+    pub symbol: Symbol,
+    pub status: AssetScoreStatus,
+    /// Timestamp of the latest score value
+    pub score_timestamp: Option<TimeStamp>,
+    pub score: Option<DataPointValue>,
+    /// Latest value of each named score, left out of csv and table reports
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub named_scores: BTreeMap<String, Option<DataPointValue>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub series: Option<TimeSeries1D>,
+}
+
+/// Results of scoring a strategy once, in a stable schema.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RunReport {
+    pub metadata: RunMetadata,
+    pub scores: Vec<ScoreRecord>,
+}
+
+/// Row of the csv report, a full series has one row per score value.
+#[derive(Debug, Serialize)]
+struct CsvRow<'a> {
+    strategy: &'a str,
+    timestamp: TimeStamp,
+    data_source: &'a str,
+    symbol: &'a str,
+    status: &'a AssetScoreStatus,
+    score_timestamp: Option<TimeStamp>,
+    score: Option<DataPointValue>,
+}
+
+impl RunReport {
+    pub fn new(
+        metadata: RunMetadata,
+        asset_scores: &BTreeMap<Asset, AssetScore>,
+        full_series: bool,
+    ) -> Self {
+        let scores = asset_scores
+            .values()
+            .map(|asset_score| {
+                let latest = asset_score.score().view().last();
+                ScoreRecord {
+                    symbol: asset_score.asset().symbol().to_string(),
+                    status: asset_score.status().clone(),
+                    score_timestamp: latest.map(|(timestamp, _)| *timestamp),
+                    score: latest.map(|(_, score)| *score),
+                    named_scores: asset_score
+                        .scores()
+                        .iter()
+                        .map(|(name, score)| (name.clone(), score.values_slice().last().cloned()))
+                        .collect(),
+                    series: if full_series {
+                        Some(asset_score.score().clone())
+                    } else {
+                        None
+                    },
+                }
+            })
+            .collect();
+        RunReport { metadata, scores }
+    }
+
+    pub fn write<W: Write>(&self, mut writer: W, format: OutputFormat) -> GenResult<()> {
+        match format {
+            OutputFormat::Json => {
+                serde_json::to_writer_pretty(&mut writer, self)?;
+                writer.write_all(b"\n")?;
+            }
+            OutputFormat::Yaml => serde_yaml::to_writer(&mut writer, self)?,
+            OutputFormat::Csv => self.write_csv(&mut writer)?,
+            OutputFormat::Table => self.write_table(&mut writer)?,
+        }
+        writer.flush()?;
+        Ok(())
+    }
+
+    fn write_csv<W: Write>(&self, writer: W) -> GenResult<()> {
+        let mut writer = csv::Writer::from_writer(writer);
+        for record in &self.scores {
+            let row = |score_timestamp, score| CsvRow {
+                strategy: &self.metadata.strategy,
+                timestamp: self.metadata.timestamp,
+                data_source: &self.metadata.data_source,
+                symbol: &record.symbol,
+                status: &record.status,
+                score_timestamp,
+                score,
+            };
+            match &record.series {
+                Some(series) if !series.is_empty() => {
+                    for (timestamp, value) in series.iter() {
+                        writer.serialize(row(Some(*timestamp), Some(*value)))?;
+                    }
+                }
+                _ => writer.serialize(row(record.score_timestamp, record.score))?,
+            }
+        }
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Aligned columns preceded by the run metadata, the full series are left out.
+    fn write_table<W: Write>(&self, writer: &mut W) -> GenResult<()> {
+        writeln!(
+            writer,
+            "strategy: {}\ntimestamp: {}\ndata source: {}\n",
+            self.metadata.strategy,
+            self.metadata
+                .timestamp
+                .to_rfc3339_opts(SecondsFormat::Secs, true),
+            self.metadata.data_source
+        )?;
+        let header = vec![
+            String::from("SYMBOL"),
+            String::from("STATUS"),
+            String::from("SCORE TIMESTAMP"),
+            String::from("SCORE"),
+        ];
+        let rows: Vec<Vec<String>> = self
+            .scores
+            .iter()
+            .map(|record| {
+                vec![
+                    record.symbol.clone(),
+                    format!("{:?}", record.status),
+                    record
+                        .score_timestamp
+                        .map(|timestamp| timestamp.to_rfc3339_opts(SecondsFormat::Secs, true))
+                        .unwrap_or_default(),
+                    record
+                        .score
+                        .map(|score| format!("{:.6}", score))
+                        .unwrap_or_default(),
+                ]
+            })
+            .collect();
+        let widths: Vec<usize> = (0..header.len())
+            .map(|column| {
+                rows.iter()
+                    .chain(std::iter::once(&header))
+                    .map(|row| row[column].len())
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+        for row in std::iter::once(&header).chain(rows.iter()) {
+            // text columns are left aligned, the score right aligned
+            let line = format!(
+                "{:<w0$}  {:<w1$}  {:<w2$}  {:>w3$}",
+                row[0],
+                row[1],
+                row[2],
+                row[3],
+                w0 = widths[0],
+                w1 = widths[1],
+                w2 = widths[2],
+                w3 = widths[3]
+            );
+            writeln!(writer, "{}", line.trim_end())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::convert::TryFrom;
+    use std::path::Path;
+
+    use crate::bot::asset_score::{AssetScore, AssetScoreStatus};
+    use crate::data::Asset;
+    use crate::errors::GenResult;
+    use crate::report::{OutputFormat, RunMetadata, RunReport};
+    use crate::time_series::TimeSeries1D;
+
+    fn report_fixture(full_series: bool) -> RunReport {
+        let timestamp = TimeSeries1D::epoch();
+        let asset_scores: BTreeMap<Asset, AssetScore> = vec![
+            ("A", vec![0.5, 1.25], AssetScoreStatus::Complete),
+            ("LONG", vec![], AssetScoreStatus::Error),
+        ]
+        .into_iter()
+        .map(|(symbol, values, status)| {
+            let asset = Asset::new(symbol.to_string());
+            let score = TimeSeries1D::from_values(values);
+            (
+                asset.clone(),
+                AssetScore::from_parts(asset, timestamp, score, status),
+            )
+        })
+        .collect();
+        let metadata = RunMetadata::new("Test Strategy", timestamp, "mock");
+        RunReport::new(metadata, &asset_scores, full_series)
+    }
+
+    fn written(report: &RunReport, format: OutputFormat) -> GenResult<String> {
+        let mut buffer = vec![];
+        report.write(&mut buffer, format)?;
+        Ok(String::from_utf8(buffer)?)
+    }
+
+    #[test]
+    fn latest_scores() -> GenResult<()> {
+        let report = report_fixture(false);
+        assert_eq!(report.scores[0].score, Some(1.25));
+        assert_eq!(report.scores[1].score, None);
+        let json: serde_json::Value = serde_json::from_str(&written(&report, OutputFormat::Json)?)?;
+        assert_eq!(json["metadata"]["strategy"], "Test Strategy");
+        assert_eq!(json["metadata"]["schema_version"], 1);
+        assert_eq!(json["scores"][0]["status"], "Complete");
+        assert!(json["scores"][0].get("series").is_none());
+        let yaml: serde_yaml::Value = serde_yaml::from_str(&written(&report, OutputFormat::Yaml)?)?;
+        assert_eq!(yaml["scores"][1]["symbol"], "LONG");
+        let csv = written(&report, OutputFormat::Csv)?;
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(
+            lines[0],
+            "strategy,timestamp,data_source,symbol,status,score_timestamp,score"
+        );
+        assert_eq!(
+            lines[1],
+            "Test Strategy,2010-01-01T00:00:00Z,mock,A,Complete,2010-01-02T00:00:00Z,1.25"
+        );
+        assert_eq!(
+            lines[2],
+            "Test Strategy,2010-01-01T00:00:00Z,mock,LONG,Error,,"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn full_series() -> GenResult<()> {
+        let report = report_fixture(true);
+        let json: serde_json::Value = serde_json::from_str(&written(&report, OutputFormat::Json)?)?;
+        assert_eq!(json["scores"][0]["series"].as_array().unwrap().len(), 2);
+        // one row per score value, plus the header and the asset without scores
+        assert_eq!(written(&report, OutputFormat::Csv)?.lines().count(), 4);
+        Ok(())
+    }
+
+    #[test]
+    fn aligned_table() -> GenResult<()> {
+        let table = written(&report_fixture(false), OutputFormat::Table)?;
+        let lines: Vec<&str> = table.lines().skip(4).collect();
+        assert_eq!(
+            lines,
+            vec![
+                "SYMBOL  STATUS    SCORE TIMESTAMP          SCORE",
+                "A       Complete  2010-01-02T00:00:00Z  1.250000",
+                "LONG    Error",
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn format_of_output() {
+        assert_eq!(
+            OutputFormat::from_path(Path::new("scores.yml")),
+            OutputFormat::Yaml
+        );
+        assert_eq!(
+            OutputFormat::from_path(Path::new("scores")),
+            OutputFormat::Table
+        );
+        assert!(OutputFormat::try_from("xml").is_err());
+    }
+}
@@ -0,0 +1,1788 @@
+// This file is generated by rust-protobuf 2.18.2. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `scoring.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_18_2;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ScoreRequest {
+    // message fields
+    pub strategy_yaml: ::std::string::String,
+    pub strategy_id: ::std::string::String,
+    pub symbols: ::protobuf::RepeatedField<::std::string::String>,
+    pub timestamp: ::protobuf::SingularPtrField<::protobuf::well_known_types::Timestamp>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ScoreRequest {
+    fn default() -> &'a ScoreRequest {
+        <ScoreRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ScoreRequest {
+    pub fn new() -> ScoreRequest {
+        ::std::default::Default::default()
+    }
+
+    // string strategy_yaml = 1;
+
+
+    pub fn get_strategy_yaml(&self) -> &str {
+        &self.strategy_yaml
+    }
+    pub fn clear_strategy_yaml(&mut self) {
+        self.strategy_yaml.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_strategy_yaml(&mut self, v: ::std::string::String) {
+        self.strategy_yaml = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_strategy_yaml(&mut self) -> &mut ::std::string::String {
+        &mut self.strategy_yaml
+    }
+
+    // Take field
+    pub fn take_strategy_yaml(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.strategy_yaml, ::std::string::String::new())
+    }
+
+    // string strategy_id = 2;
+
+
+    pub fn get_strategy_id(&self) -> &str {
+        &self.strategy_id
+    }
+    pub fn clear_strategy_id(&mut self) {
+        self.strategy_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_strategy_id(&mut self, v: ::std::string::String) {
+        self.strategy_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_strategy_id(&mut self) -> &mut ::std::string::String {
+        &mut self.strategy_id
+    }
+
+    // Take field
+    pub fn take_strategy_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.strategy_id, ::std::string::String::new())
+    }
+
+    // repeated string symbols = 3;
+
+
+    pub fn get_symbols(&self) -> &[::std::string::String] {
+        &self.symbols
+    }
+    pub fn clear_symbols(&mut self) {
+        self.symbols.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_symbols(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.symbols = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_symbols(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.symbols
+    }
+
+    // Take field
+    pub fn take_symbols(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.symbols, ::protobuf::RepeatedField::new())
+    }
+
+    // .google.protobuf.Timestamp timestamp = 4;
+
+
+    pub fn get_timestamp(&self) -> &::protobuf::well_known_types::Timestamp {
+        self.timestamp.as_ref().unwrap_or_else(|| <::protobuf::well_known_types::Timestamp as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_timestamp(&mut self) {
+        self.timestamp.clear();
+    }
+
+    pub fn has_timestamp(&self) -> bool {
+        self.timestamp.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_timestamp(&mut self, v: ::protobuf::well_known_types::Timestamp) {
+        self.timestamp = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_timestamp(&mut self) -> &mut ::protobuf::well_known_types::Timestamp {
+        if self.timestamp.is_none() {
+            self.timestamp.set_default();
+        }
+        self.timestamp.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_timestamp(&mut self) -> ::protobuf::well_known_types::Timestamp {
+        self.timestamp.take().unwrap_or_else(|| ::protobuf::well_known_types::Timestamp::new())
+    }
+}
+
+impl ::protobuf::Message for ScoreRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.timestamp {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.strategy_yaml)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.strategy_id)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.symbols)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.timestamp)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.strategy_yaml.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.strategy_yaml);
+        }
+        if !self.strategy_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.strategy_id);
+        }
+        for value in &self.symbols {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        if let Some(ref v) = self.timestamp.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.strategy_yaml.is_empty() {
+            os.write_string(1, &self.strategy_yaml)?;
+        }
+        if !self.strategy_id.is_empty() {
+            os.write_string(2, &self.strategy_id)?;
+        }
+        for v in &self.symbols {
+            os.write_string(3, &v)?;
+        };
+        if let Some(ref v) = self.timestamp.as_ref() {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ScoreRequest {
+        ScoreRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "strategy_yaml",
+                |m: &ScoreRequest| { &m.strategy_yaml },
+                |m: &mut ScoreRequest| { &mut m.strategy_yaml },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "strategy_id",
+                |m: &ScoreRequest| { &m.strategy_id },
+                |m: &mut ScoreRequest| { &mut m.strategy_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "symbols",
+                |m: &ScoreRequest| { &m.symbols },
+                |m: &mut ScoreRequest| { &mut m.symbols },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<::protobuf::well_known_types::Timestamp>>(
+                "timestamp",
+                |m: &ScoreRequest| { &m.timestamp },
+                |m: &mut ScoreRequest| { &mut m.timestamp },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ScoreRequest>(
+                "ScoreRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ScoreRequest {
+        static instance: ::protobuf::rt::LazyV2<ScoreRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ScoreRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for ScoreRequest {
+    fn clear(&mut self) {
+        self.strategy_yaml.clear();
+        self.strategy_id.clear();
+        self.symbols.clear();
+        self.timestamp.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ScoreRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ScoreRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ScoredAsset {
+    // message fields
+    pub symbol: ::std::string::String,
+    pub status: ::std::string::String,
+    pub score: ::protobuf::SingularPtrField<super::query::TimeSeries>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ScoredAsset {
+    fn default() -> &'a ScoredAsset {
+        <ScoredAsset as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ScoredAsset {
+    pub fn new() -> ScoredAsset {
+        ::std::default::Default::default()
+    }
+
+    // string symbol = 1;
+
+
+    pub fn get_symbol(&self) -> &str {
+        &self.symbol
+    }
+    pub fn clear_symbol(&mut self) {
+        self.symbol.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_symbol(&mut self, v: ::std::string::String) {
+        self.symbol = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_symbol(&mut self) -> &mut ::std::string::String {
+        &mut self.symbol
+    }
+
+    // Take field
+    pub fn take_symbol(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.symbol, ::std::string::String::new())
+    }
+
+    // string status = 2;
+
+
+    pub fn get_status(&self) -> &str {
+        &self.status
+    }
+    pub fn clear_status(&mut self) {
+        self.status.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_status(&mut self, v: ::std::string::String) {
+        self.status = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_status(&mut self) -> &mut ::std::string::String {
+        &mut self.status
+    }
+
+    // Take field
+    pub fn take_status(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.status, ::std::string::String::new())
+    }
+
+    // .query.TimeSeries score = 3;
+
+
+    pub fn get_score(&self) -> &super::query::TimeSeries {
+        self.score.as_ref().unwrap_or_else(|| <super::query::TimeSeries as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_score(&mut self) {
+        self.score.clear();
+    }
+
+    pub fn has_score(&self) -> bool {
+        self.score.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_score(&mut self, v: super::query::TimeSeries) {
+        self.score = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_score(&mut self) -> &mut super::query::TimeSeries {
+        if self.score.is_none() {
+            self.score.set_default();
+        }
+        self.score.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_score(&mut self) -> super::query::TimeSeries {
+        self.score.take().unwrap_or_else(|| super::query::TimeSeries::new())
+    }
+}
+
+impl ::protobuf::Message for ScoredAsset {
+    fn is_initialized(&self) -> bool {
+        for v in &self.score {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.symbol)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.status)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.score)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.symbol.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.symbol);
+        }
+        if !self.status.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.status);
+        }
+        if let Some(ref v) = self.score.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.symbol.is_empty() {
+            os.write_string(1, &self.symbol)?;
+        }
+        if !self.status.is_empty() {
+            os.write_string(2, &self.status)?;
+        }
+        if let Some(ref v) = self.score.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ScoredAsset {
+        ScoredAsset::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "symbol",
+                |m: &ScoredAsset| { &m.symbol },
+                |m: &mut ScoredAsset| { &mut m.symbol },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "status",
+                |m: &ScoredAsset| { &m.status },
+                |m: &mut ScoredAsset| { &mut m.status },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::query::TimeSeries>>(
+                "score",
+                |m: &ScoredAsset| { &m.score },
+                |m: &mut ScoredAsset| { &mut m.score },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ScoredAsset>(
+                "ScoredAsset",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ScoredAsset {
+        static instance: ::protobuf::rt::LazyV2<ScoredAsset> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ScoredAsset::new)
+    }
+}
+
+impl ::protobuf::Clear for ScoredAsset {
+    fn clear(&mut self) {
+        self.symbol.clear();
+        self.status.clear();
+        self.score.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ScoredAsset {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ScoredAsset {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ScoreResponse {
+    // message fields
+    pub scores: ::protobuf::RepeatedField<ScoredAsset>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ScoreResponse {
+    fn default() -> &'a ScoreResponse {
+        <ScoreResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ScoreResponse {
+    pub fn new() -> ScoreResponse {
+        ::std::default::Default::default()
+    }
+
+    // repeated .scoring.ScoredAsset scores = 1;
+
+
+    pub fn get_scores(&self) -> &[ScoredAsset] {
+        &self.scores
+    }
+    pub fn clear_scores(&mut self) {
+        self.scores.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_scores(&mut self, v: ::protobuf::RepeatedField<ScoredAsset>) {
+        self.scores = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_scores(&mut self) -> &mut ::protobuf::RepeatedField<ScoredAsset> {
+        &mut self.scores
+    }
+
+    // Take field
+    pub fn take_scores(&mut self) -> ::protobuf::RepeatedField<ScoredAsset> {
+        ::std::mem::replace(&mut self.scores, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for ScoreResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.scores {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.scores)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.scores {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.scores {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ScoreResponse {
+        ScoreResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ScoredAsset>>(
+                "scores",
+                |m: &ScoreResponse| { &m.scores },
+                |m: &mut ScoreResponse| { &mut m.scores },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ScoreResponse>(
+                "ScoreResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ScoreResponse {
+        static instance: ::protobuf::rt::LazyV2<ScoreResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ScoreResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for ScoreResponse {
+    fn clear(&mut self) {
+        self.scores.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ScoreResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ScoreResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct BackTestRequest {
+    // message fields
+    pub strategy_yaml: ::std::string::String,
+    pub strategy_id: ::std::string::String,
+    pub symbols: ::protobuf::RepeatedField<::std::string::String>,
+    pub first: ::protobuf::SingularPtrField<::protobuf::well_known_types::Timestamp>,
+    pub last: ::protobuf::SingularPtrField<::protobuf::well_known_types::Timestamp>,
+    pub frequency: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a BackTestRequest {
+    fn default() -> &'a BackTestRequest {
+        <BackTestRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BackTestRequest {
+    pub fn new() -> BackTestRequest {
+        ::std::default::Default::default()
+    }
+
+    // string strategy_yaml = 1;
+
+
+    pub fn get_strategy_yaml(&self) -> &str {
+        &self.strategy_yaml
+    }
+    pub fn clear_strategy_yaml(&mut self) {
+        self.strategy_yaml.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_strategy_yaml(&mut self, v: ::std::string::String) {
+        self.strategy_yaml = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_strategy_yaml(&mut self) -> &mut ::std::string::String {
+        &mut self.strategy_yaml
+    }
+
+    // Take field
+    pub fn take_strategy_yaml(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.strategy_yaml, ::std::string::String::new())
+    }
+
+    // string strategy_id = 2;
+
+
+    pub fn get_strategy_id(&self) -> &str {
+        &self.strategy_id
+    }
+    pub fn clear_strategy_id(&mut self) {
+        self.strategy_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_strategy_id(&mut self, v: ::std::string::String) {
+        self.strategy_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_strategy_id(&mut self) -> &mut ::std::string::String {
+        &mut self.strategy_id
+    }
+
+    // Take field
+    pub fn take_strategy_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.strategy_id, ::std::string::String::new())
+    }
+
+    // repeated string symbols = 3;
+
+
+    pub fn get_symbols(&self) -> &[::std::string::String] {
+        &self.symbols
+    }
+    pub fn clear_symbols(&mut self) {
+        self.symbols.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_symbols(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.symbols = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_symbols(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.symbols
+    }
+
+    // Take field
+    pub fn take_symbols(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.symbols, ::protobuf::RepeatedField::new())
+    }
+
+    // .google.protobuf.Timestamp first = 4;
+
+
+    pub fn get_first(&self) -> &::protobuf::well_known_types::Timestamp {
+        self.first.as_ref().unwrap_or_else(|| <::protobuf::well_known_types::Timestamp as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_first(&mut self) {
+        self.first.clear();
+    }
+
+    pub fn has_first(&self) -> bool {
+        self.first.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_first(&mut self, v: ::protobuf::well_known_types::Timestamp) {
+        self.first = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_first(&mut self) -> &mut ::protobuf::well_known_types::Timestamp {
+        if self.first.is_none() {
+            self.first.set_default();
+        }
+        self.first.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_first(&mut self) -> ::protobuf::well_known_types::Timestamp {
+        self.first.take().unwrap_or_else(|| ::protobuf::well_known_types::Timestamp::new())
+    }
+
+    // .google.protobuf.Timestamp last = 5;
+
+
+    pub fn get_last(&self) -> &::protobuf::well_known_types::Timestamp {
+        self.last.as_ref().unwrap_or_else(|| <::protobuf::well_known_types::Timestamp as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_last(&mut self) {
+        self.last.clear();
+    }
+
+    pub fn has_last(&self) -> bool {
+        self.last.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_last(&mut self, v: ::protobuf::well_known_types::Timestamp) {
+        self.last = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_last(&mut self) -> &mut ::protobuf::well_known_types::Timestamp {
+        if self.last.is_none() {
+            self.last.set_default();
+        }
+        self.last.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_last(&mut self) -> ::protobuf::well_known_types::Timestamp {
+        self.last.take().unwrap_or_else(|| ::protobuf::well_known_types::Timestamp::new())
+    }
+
+    // string frequency = 6;
+
+
+    pub fn get_frequency(&self) -> &str {
+        &self.frequency
+    }
+    pub fn clear_frequency(&mut self) {
+        self.frequency.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_frequency(&mut self, v: ::std::string::String) {
+        self.frequency = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_frequency(&mut self) -> &mut ::std::string::String {
+        &mut self.frequency
+    }
+
+    // Take field
+    pub fn take_frequency(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.frequency, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for BackTestRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.first {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.last {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.strategy_yaml)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.strategy_id)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.symbols)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.first)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.last)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.frequency)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.strategy_yaml.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.strategy_yaml);
+        }
+        if !self.strategy_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.strategy_id);
+        }
+        for value in &self.symbols {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        if let Some(ref v) = self.first.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.last.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.frequency.is_empty() {
+            my_size += ::protobuf::rt::string_size(6, &self.frequency);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.strategy_yaml.is_empty() {
+            os.write_string(1, &self.strategy_yaml)?;
+        }
+        if !self.strategy_id.is_empty() {
+            os.write_string(2, &self.strategy_id)?;
+        }
+        for v in &self.symbols {
+            os.write_string(3, &v)?;
+        };
+        if let Some(ref v) = self.first.as_ref() {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.last.as_ref() {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.frequency.is_empty() {
+            os.write_string(6, &self.frequency)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> BackTestRequest {
+        BackTestRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "strategy_yaml",
+                |m: &BackTestRequest| { &m.strategy_yaml },
+                |m: &mut BackTestRequest| { &mut m.strategy_yaml },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "strategy_id",
+                |m: &BackTestRequest| { &m.strategy_id },
+                |m: &mut BackTestRequest| { &mut m.strategy_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "symbols",
+                |m: &BackTestRequest| { &m.symbols },
+                |m: &mut BackTestRequest| { &mut m.symbols },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<::protobuf::well_known_types::Timestamp>>(
+                "first",
+                |m: &BackTestRequest| { &m.first },
+                |m: &mut BackTestRequest| { &mut m.first },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<::protobuf::well_known_types::Timestamp>>(
+                "last",
+                |m: &BackTestRequest| { &m.last },
+                |m: &mut BackTestRequest| { &mut m.last },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "frequency",
+                |m: &BackTestRequest| { &m.frequency },
+                |m: &mut BackTestRequest| { &mut m.frequency },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<BackTestRequest>(
+                "BackTestRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static BackTestRequest {
+        static instance: ::protobuf::rt::LazyV2<BackTestRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(BackTestRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for BackTestRequest {
+    fn clear(&mut self) {
+        self.strategy_yaml.clear();
+        self.strategy_id.clear();
+        self.symbols.clear();
+        self.first.clear();
+        self.last.clear();
+        self.frequency.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for BackTestRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BackTestRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct BackTestResponse {
+    // message fields
+    pub scores: ::protobuf::RepeatedField<ScoredAsset>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a BackTestResponse {
+    fn default() -> &'a BackTestResponse {
+        <BackTestResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BackTestResponse {
+    pub fn new() -> BackTestResponse {
+        ::std::default::Default::default()
+    }
+
+    // repeated .scoring.ScoredAsset scores = 1;
+
+
+    pub fn get_scores(&self) -> &[ScoredAsset] {
+        &self.scores
+    }
+    pub fn clear_scores(&mut self) {
+        self.scores.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_scores(&mut self, v: ::protobuf::RepeatedField<ScoredAsset>) {
+        self.scores = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_scores(&mut self) -> &mut ::protobuf::RepeatedField<ScoredAsset> {
+        &mut self.scores
+    }
+
+    // Take field
+    pub fn take_scores(&mut self) -> ::protobuf::RepeatedField<ScoredAsset> {
+        ::std::mem::replace(&mut self.scores, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for BackTestResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.scores {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.scores)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.scores {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.scores {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> BackTestResponse {
+        BackTestResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ScoredAsset>>(
+                "scores",
+                |m: &BackTestResponse| { &m.scores },
+                |m: &mut BackTestResponse| { &mut m.scores },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<BackTestResponse>(
+                "BackTestResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static BackTestResponse {
+        static instance: ::protobuf::rt::LazyV2<BackTestResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(BackTestResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for BackTestResponse {
+    fn clear(&mut self) {
+        self.scores.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for BackTestResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BackTestResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ValidateRequest {
+    // message fields
+    pub strategy_yaml: ::std::string::String,
+    pub strategy_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ValidateRequest {
+    fn default() -> &'a ValidateRequest {
+        <ValidateRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ValidateRequest {
+    pub fn new() -> ValidateRequest {
+        ::std::default::Default::default()
+    }
+
+    // string strategy_yaml = 1;
+
+
+    pub fn get_strategy_yaml(&self) -> &str {
+        &self.strategy_yaml
+    }
+    pub fn clear_strategy_yaml(&mut self) {
+        self.strategy_yaml.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_strategy_yaml(&mut self, v: ::std::string::String) {
+        self.strategy_yaml = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_strategy_yaml(&mut self) -> &mut ::std::string::String {
+        &mut self.strategy_yaml
+    }
+
+    // Take field
+    pub fn take_strategy_yaml(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.strategy_yaml, ::std::string::String::new())
+    }
+
+    // string strategy_id = 2;
+
+
+    pub fn get_strategy_id(&self) -> &str {
+        &self.strategy_id
+    }
+    pub fn clear_strategy_id(&mut self) {
+        self.strategy_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_strategy_id(&mut self, v: ::std::string::String) {
+        self.strategy_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_strategy_id(&mut self) -> &mut ::std::string::String {
+        &mut self.strategy_id
+    }
+
+    // Take field
+    pub fn take_strategy_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.strategy_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for ValidateRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.strategy_yaml)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.strategy_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.strategy_yaml.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.strategy_yaml);
+        }
+        if !self.strategy_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.strategy_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.strategy_yaml.is_empty() {
+            os.write_string(1, &self.strategy_yaml)?;
+        }
+        if !self.strategy_id.is_empty() {
+            os.write_string(2, &self.strategy_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ValidateRequest {
+        ValidateRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "strategy_yaml",
+                |m: &ValidateRequest| { &m.strategy_yaml },
+                |m: &mut ValidateRequest| { &mut m.strategy_yaml },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "strategy_id",
+                |m: &ValidateRequest| { &m.strategy_id },
+                |m: &mut ValidateRequest| { &mut m.strategy_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ValidateRequest>(
+                "ValidateRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ValidateRequest {
+        static instance: ::protobuf::rt::LazyV2<ValidateRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ValidateRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for ValidateRequest {
+    fn clear(&mut self) {
+        self.strategy_yaml.clear();
+        self.strategy_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ValidateRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ValidateRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ValidateResponse {
+    // message fields
+    pub valid: bool,
+    pub reason: ::std::string::String,
+    pub execution_order: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ValidateResponse {
+    fn default() -> &'a ValidateResponse {
+        <ValidateResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ValidateResponse {
+    pub fn new() -> ValidateResponse {
+        ::std::default::Default::default()
+    }
+
+    // bool valid = 1;
+
+
+    pub fn get_valid(&self) -> bool {
+        self.valid
+    }
+    pub fn clear_valid(&mut self) {
+        self.valid = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_valid(&mut self, v: bool) {
+        self.valid = v;
+    }
+
+    // string reason = 2;
+
+
+    pub fn get_reason(&self) -> &str {
+        &self.reason
+    }
+    pub fn clear_reason(&mut self) {
+        self.reason.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_reason(&mut self, v: ::std::string::String) {
+        self.reason = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_reason(&mut self) -> &mut ::std::string::String {
+        &mut self.reason
+    }
+
+    // Take field
+    pub fn take_reason(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.reason, ::std::string::String::new())
+    }
+
+    // repeated string execution_order = 3;
+
+
+    pub fn get_execution_order(&self) -> &[::std::string::String] {
+        &self.execution_order
+    }
+    pub fn clear_execution_order(&mut self) {
+        self.execution_order.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_execution_order(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.execution_order = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_execution_order(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.execution_order
+    }
+
+    // Take field
+    pub fn take_execution_order(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.execution_order, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for ValidateResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.valid = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.reason)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.execution_order)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.valid != false {
+            my_size += 2;
+        }
+        if !self.reason.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.reason);
+        }
+        for value in &self.execution_order {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.valid != false {
+            os.write_bool(1, self.valid)?;
+        }
+        if !self.reason.is_empty() {
+            os.write_string(2, &self.reason)?;
+        }
+        for v in &self.execution_order {
+            os.write_string(3, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ValidateResponse {
+        ValidateResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "valid",
+                |m: &ValidateResponse| { &m.valid },
+                |m: &mut ValidateResponse| { &mut m.valid },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "reason",
+                |m: &ValidateResponse| { &m.reason },
+                |m: &mut ValidateResponse| { &mut m.reason },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "execution_order",
+                |m: &ValidateResponse| { &m.execution_order },
+                |m: &mut ValidateResponse| { &mut m.execution_order },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ValidateResponse>(
+                "ValidateResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ValidateResponse {
+        static instance: ::protobuf::rt::LazyV2<ValidateResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ValidateResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for ValidateResponse {
+    fn clear(&mut self) {
+        self.valid = false;
+        self.reason.clear();
+        self.execution_order.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ValidateResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ValidateResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\rscoring.proto\x12\x07scoring\x1a\x1fgoogle/protobuf/timestamp.proto\
+    \x1a\x0bquery.proto\"\xa8\x01\n\x0cScoreRequest\x12#\n\rstrategy_yaml\
+    \x18\x01\x20\x01(\tR\x0cstrategyYaml\x12\x1f\n\x0bstrategy_id\x18\x02\
+    \x20\x01(\tR\nstrategyId\x12\x18\n\x07symbols\x18\x03\x20\x03(\tR\x07sym\
+    bols\x128\n\ttimestamp\x18\x04\x20\x01(\x0b2\x1a.google.protobuf.Timesta\
+    mpR\ttimestamp\"f\n\x0bScoredAsset\x12\x16\n\x06symbol\x18\x01\x20\x01(\
+    \tR\x06symbol\x12\x16\n\x06status\x18\x02\x20\x01(\tR\x06status\x12'\n\
+    \x05score\x18\x03\x20\x01(\x0b2\x11.query.TimeSeriesR\x05score\"=\n\rSco\
+    reResponse\x12,\n\x06scores\x18\x01\x20\x03(\x0b2\x14.scoring.ScoredAsse\
+    tR\x06scores\"\xf1\x01\n\x0fBackTestRequest\x12#\n\rstrategy_yaml\x18\
+    \x01\x20\x01(\tR\x0cstrategyYaml\x12\x1f\n\x0bstrategy_id\x18\x02\x20\
+    \x01(\tR\nstrategyId\x12\x18\n\x07symbols\x18\x03\x20\x03(\tR\x07symbols\
+    \x120\n\x05first\x18\x04\x20\x01(\x0b2\x1a.google.protobuf.TimestampR\
+    \x05first\x12.\n\x04last\x18\x05\x20\x01(\x0b2\x1a.google.protobuf.Times\
+    tampR\x04last\x12\x1c\n\tfrequency\x18\x06\x20\x01(\tR\tfrequency\"@\n\
+    \x10BackTestResponse\x12,\n\x06scores\x18\x01\x20\x03(\x0b2\x14.scoring.\
+    ScoredAssetR\x06scores\"W\n\x0fValidateRequest\x12#\n\rstrategy_yaml\x18\
+    \x01\x20\x01(\tR\x0cstrategyYaml\x12\x1f\n\x0bstrategy_id\x18\x02\x20\
+    \x01(\tR\nstrategyId\"i\n\x10ValidateResponse\x12\x14\n\x05valid\x18\x01\
+    \x20\x01(\x08R\x05valid\x12\x16\n\x06reason\x18\x02\x20\x01(\tR\x06reaso\
+    n\x12'\n\x0fexecution_order\x18\x03\x20\x03(\tR\x0eexecutionOrder2\xd6\
+    \x01\n\x03Bot\x12>\n\x0bScoreAssets\x12\x15.scoring.ScoreRequest\x1a\x16\
+    .scoring.ScoreResponse\"\0\x12D\n\x0bRunBackTest\x12\x18.scoring.BackTes\
+    tRequest\x1a\x19.scoring.BackTestResponse\"\0\x12I\n\x10ValidateStrategy\
+    \x12\x18.scoring.ValidateRequest\x1a\x19.scoring.ValidateResponse\"\0B!Z\
+    \x1fgithub.com/luckless-finance/botb\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -4,15 +4,15 @@ use std::borrow::Borrow;
 use std::cmp::Ordering;
 use std::collections::BTreeMap;
 use std::convert::TryFrom;
-use std::ops::{Add, Div, Mul, Neg, Sub};
+use std::ops::{Add, Bound, Div, Mul, Neg, RangeBounds, Sub};
 
 use chrono::prelude::*;
 use chrono::{Duration, TimeZone};
-use itertools::{fold, zip};
 use serde::ser::{SerializeSeq, SerializeStruct};
-use serde::{Deserialize, Serialize, Serializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-use crate::errors::{GenError, GenResult};
+use crate::errors::{GenError, GenResult, TimeSeriesError};
+use crate::frame::TimeSeriesFrame;
 
 pub type DataPointValue = f64;
 // TODO enforce allocations 0 <= a <= 1
@@ -20,9 +20,18 @@ pub type Allocation = f64;
 pub type TimeStamp = DateTime<Utc>;
 pub type Index = Vec<TimeStamp>;
 
+/// Values at strictly increasing timestamps, stored as contiguous index and value vectors.
 #[derive(Clone, Debug, PartialEq)]
 pub struct TimeSeries1D {
-    data: BTreeMap<TimeStamp, DataPointValue>,
+    index: Index,
+    values: Vec<DataPointValue>,
+}
+
+/// Borrowed range of a `TimeSeries1D`, sharing its index and values without copying them.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TimeSeriesView<'a> {
+    index: &'a [TimeStamp],
+    values: &'a [DataPointValue],
 }
 
 /// Serialize as list of key,value pairs
@@ -32,13 +41,53 @@ impl Serialize for TimeSeries1D {
         S: Serializer,
     {
         let mut s = serializer.serialize_seq(Some(self.len()))?;
-        for (timestamp, value) in &self.data {
+        for (timestamp, value) in self.iter() {
             s.serialize_element(&(timestamp, value))?;
         }
         s.end()
     }
 }
 
+/// Deserialize the list of key,value pairs written by `Serialize`, null values are NaN
+impl<'de> Deserialize<'de> for TimeSeries1D {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let pairs: Vec<(TimeStamp, NullableValue)> = Vec::deserialize(deserializer)?;
+        let (index, values) = pairs
+            .into_iter()
+            .map(|(timestamp, value)| (timestamp, value.0))
+            .unzip();
+        Ok(TimeSeries1D::from_vec(index, values))
+    }
+}
+
+/// Value written as null by human readable formats when it is not finite
+struct NullableValue(DataPointValue);
+
+impl<'de> Deserialize<'de> for NullableValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            nan_if_null(deserializer).map(NullableValue)
+        } else {
+            DataPointValue::deserialize(deserializer).map(NullableValue)
+        }
+    }
+}
+
+/// Deserialize a missing or null value as NaN, eg. `#[serde(deserialize_with = "nan_if_null")]`
+pub fn nan_if_null<'de, D>(deserializer: D) -> Result<DataPointValue, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Option::<DataPointValue>::deserialize(deserializer)
+        .map(|value| value.unwrap_or(DataPointValue::NAN))
+}
+
 impl TimeSeries1D {
     // TODO abstraction leak: TimeSeries1D should not know about constraints imposed on time
     pub fn epoch() -> DateTime<Utc> {
@@ -49,9 +98,12 @@ impl TimeSeries1D {
         Duration::days(1)
     }
     pub fn new(data: BTreeMap<TimeStamp, DataPointValue>) -> Self {
-        TimeSeries1D { data }
+        let (index, values) = data.into_iter().unzip();
+        TimeSeries1D { index, values }
     }
     /// Create new `TimeSeries` from given `index` and `values` vectors
+    ///
+    /// An unsorted `index` is sorted, the last value of a repeated timestamp is kept.
     pub fn from_vec(index: Index, values: Vec<DataPointValue>) -> Self {
         assert_eq!(
             index.len(),
@@ -60,8 +112,10 @@ impl TimeSeries1D {
             index.len(),
             values.len()
         );
-        TimeSeries1D {
-            data: zip(index, values).collect(),
+        if index.windows(2).all(|pair| pair[0] < pair[1]) {
+            TimeSeries1D { index, values }
+        } else {
+            TimeSeries1D::new(index.into_iter().zip(values).collect())
         }
     }
     /// Create new `TimeSeries` with given `values` and an index containing `(0..values().len())`
@@ -73,124 +127,281 @@ impl TimeSeries1D {
             values,
         )
     }
+    /// Same index, new `values`
+    fn with_values(&self, values: Vec<DataPointValue>) -> Self {
+        TimeSeries1D {
+            index: self.index.clone(),
+            values,
+        }
+    }
     /// get clone of `values`
     pub fn values(&self) -> Vec<DataPointValue> {
-        self.data.values().cloned().collect()
+        self.values.clone()
     }
     /// get clone of `index`
     pub fn index(&self) -> Index {
-        self.data.keys().cloned().collect()
+        self.index.clone()
+    }
+    /// Borrow `values` without copying them
+    pub fn values_slice(&self) -> &[DataPointValue] {
+        &self.values
+    }
+    /// Borrow `index` without copying it
+    pub fn index_slice(&self) -> &[TimeStamp] {
+        &self.index
+    }
+    pub fn iter(&self) -> impl Iterator<Item = (&TimeStamp, &DataPointValue)> {
+        self.index.iter().zip(self.values.iter())
     }
     pub fn is_empty(&self) -> bool {
-        self.data.is_empty()
+        self.index.is_empty()
     }
     pub fn len(&self) -> usize {
-        self.data.len()
+        self.index.len()
     }
     pub fn get(&self, timestamp: &TimeStamp) -> Option<&DataPointValue> {
-        self.data.get(timestamp)
+        self.view().get(timestamp)
+    }
+    /// Borrow the whole series
+    pub fn view(&self) -> TimeSeriesView<'_> {
+        TimeSeriesView {
+            index: &self.index,
+            values: &self.values,
+        }
+    }
+    /// Borrow the values with timestamps in `range`, found by binary search.
+    /// # Example
+    /// ```
+    /// use luckless::time_series::TimeSeries1D;
+    /// let ts = TimeSeries1D::from_values(vec![1., 2., 3., 4.]);
+    /// let index = ts.index();
+    /// assert_eq!(ts.range(index[1]..index[3]).values(), &[2., 3.]);
+    /// assert_eq!(ts.range(..=index[1]).values(), &[1., 2.]);
+    /// ```
+    pub fn range<R: RangeBounds<TimeStamp>>(&self, range: R) -> TimeSeriesView<'_> {
+        self.view().range(range)
     }
     /// Align the indices of 2 `TimeSeries`, only values with indices in both `TimeSeries` are included.
     /// Creates 2 new `TimeSeries` instances.
     pub fn intersect(&self, rhs: &TimeSeries1D) -> (Self, Self) {
-        let mut l_i = 0;
-        let lhs_i = &self.index();
-        let lhs_v = &self.values();
-        let mut r_i = 0;
-        let rhs_i = &rhs.index();
-        let rhs_v = &rhs.values();
-
-        let mut both_ri: Index = Vec::new();
-        let mut both_li: Index = Vec::new();
-        let mut both_r: Vec<DataPointValue> = Vec::new();
-        let mut both_l: Vec<DataPointValue> = Vec::new();
-
-        while l_i < lhs_i.len() && r_i < rhs_i.len() {
-            match lhs_i[l_i].cmp(&rhs_i[r_i]) {
-                Ordering::Less => l_i = l_i + 1,
+        let (index, lhs_values, rhs_values) = self.inner_join(rhs);
+        (
+            TimeSeries1D {
+                index: index.clone(),
+                values: lhs_values,
+            },
+            TimeSeries1D {
+                index,
+                values: rhs_values,
+            },
+        )
+    }
+    /// Timestamps of both series with the values of each, merged in a single pass.
+    fn inner_join(&self, rhs: &TimeSeries1D) -> (Index, Vec<DataPointValue>, Vec<DataPointValue>) {
+        let capacity = self.len().min(rhs.len());
+        let mut index: Index = Vec::with_capacity(capacity);
+        let mut lhs_values: Vec<DataPointValue> = Vec::with_capacity(capacity);
+        let mut rhs_values: Vec<DataPointValue> = Vec::with_capacity(capacity);
+        let (mut l_i, mut r_i) = (0, 0);
+        while l_i < self.len() && r_i < rhs.len() {
+            match self.index[l_i].cmp(&rhs.index[r_i]) {
+                Ordering::Less => l_i += 1,
                 Ordering::Equal => {
-                    both_li.push(rhs_i[r_i].clone());
-                    both_ri.push(rhs_i[r_i].clone());
-                    both_r.push(rhs_v[r_i].clone());
-                    both_l.push(lhs_v[l_i].clone());
-                    l_i = l_i + 1;
-                    r_i = r_i + 1;
+                    index.push(self.index[l_i]);
+                    lhs_values.push(self.values[l_i]);
+                    rhs_values.push(rhs.values[r_i]);
+                    l_i += 1;
+                    r_i += 1;
                 }
-                Ordering::Greater => r_i = r_i + 1,
+                Ordering::Greater => r_i += 1,
             };
         }
-        (
-            TimeSeries1D::from_vec(both_li, both_l),
-            TimeSeries1D::from_vec(both_ri, both_r),
-        )
+        (index, lhs_values, rhs_values)
+    }
+    /// Combine the values of both series at the timestamps kept by `join`, a value missing from
+    /// either side is passed to `f` as NaN.
+    /// # Example
+    /// ```
+    /// use luckless::time_series::{Join, TimeSeries1D};
+    /// let lhs = TimeSeries1D::from_values(vec![1., 2., 3.]);
+    /// let rhs = TimeSeries1D::from_vec(lhs.index()[1..].to_vec(), vec![10., 20.]);
+    /// let max = |l: f64, r: f64| l.max(r);
+    /// assert_eq!(lhs.zip_with(&rhs, Join::Inner, max).values(), vec![10., 20.]);
+    /// // f64::max ignores NaN
+    /// assert_eq!(lhs.zip_with(&rhs, Join::Left, max).values(), vec![1., 10., 20.]);
+    /// assert_eq!(rhs.zip_with(&lhs, Join::Outer, max).len(), 3);
+    /// ```
+    pub fn zip_with<F>(&self, rhs: &TimeSeries1D, join: Join, f: F) -> Self
+    where
+        F: Fn(DataPointValue, DataPointValue) -> DataPointValue,
+    {
+        let capacity = match join {
+            Join::Inner => self.len().min(rhs.len()),
+            Join::Left => self.len(),
+            Join::Outer => self.len().max(rhs.len()),
+        };
+        let mut index: Index = Vec::with_capacity(capacity);
+        let mut values: Vec<DataPointValue> = Vec::with_capacity(capacity);
+        let (mut l_i, mut r_i) = (0, 0);
+        while l_i < self.len() || r_i < rhs.len() {
+            let order = match (self.index.get(l_i), rhs.index.get(r_i)) {
+                (Some(l), Some(r)) => l.cmp(r),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => unreachable!(),
+            };
+            match order {
+                Ordering::Less => {
+                    if join != Join::Inner {
+                        index.push(self.index[l_i]);
+                        values.push(f(self.values[l_i], DataPointValue::NAN));
+                    }
+                    l_i += 1;
+                }
+                Ordering::Equal => {
+                    index.push(self.index[l_i]);
+                    values.push(f(self.values[l_i], rhs.values[r_i]));
+                    l_i += 1;
+                    r_i += 1;
+                }
+                Ordering::Greater => {
+                    if join == Join::Outer {
+                        index.push(rhs.index[r_i]);
+                        values.push(f(DataPointValue::NAN, rhs.values[r_i]));
+                    }
+                    r_i += 1;
+                }
+            };
+        }
+        TimeSeries1D { index, values }
+    }
+    /// Apply `f` to every value, keeping the index.
+    pub fn map<F: Fn(DataPointValue) -> DataPointValue>(&self, f: F) -> Self {
+        self.with_values(self.values.iter().map(|value| f(*value)).collect())
+    }
+    /// Apply `f` to every value in place, reusing the buffer of an owned series.
+    fn map_in_place<F: Fn(DataPointValue) -> DataPointValue>(mut self, f: F) -> Self {
+        self.values.iter_mut().for_each(|value| *value = f(*value));
+        self
+    }
+    /// Accumulate the values in timestamp order, use `iter()` to fold over timestamps too.
+    /// # Example
+    /// ```
+    /// use luckless::time_series::TimeSeries1D;
+    /// let ts = TimeSeries1D::from_values(vec![1., 2., 3.]);
+    /// assert_eq!(ts.fold(0., |sum, value| sum + value), 6.);
+    /// assert_eq!(ts.fold(1., |product, value| product * value), 6.);
+    /// ```
+    pub fn fold<B, F: FnMut(B, DataPointValue) -> B>(&self, init: B, f: F) -> B {
+        self.values.iter().cloned().fold(init, f)
     }
     pub fn add(&self, rhs: DataPointValue) -> Self {
-        let product_idx: Index = self.index().clone();
-        let product_values: Vec<DataPointValue> = self.values().iter().map(|x| x + rhs).collect();
-        TimeSeries1D::from_vec(product_idx, product_values)
+        self.map(|x| x + rhs)
     }
     pub fn sub(&self, rhs: DataPointValue) -> Self {
         self.add(rhs.neg())
     }
     pub fn mul(&self, rhs: DataPointValue) -> Self {
-        let product_idx: Index = self.index().clone();
-        let product_values: Vec<DataPointValue> = self.values().iter().map(|x| x * rhs).collect();
-        TimeSeries1D::from_vec(product_idx, product_values)
+        self.map(|x| x * rhs)
     }
     pub fn div(&self, rhs: DataPointValue) -> Self {
-        let product_idx: Index = self.index().clone();
-        let product_values: Vec<DataPointValue> = self.values().iter().map(|x| x / rhs).collect();
-        TimeSeries1D::from_vec(product_idx, product_values)
+        self.map(|x| x / rhs)
+    }
+    /// `div` failing on a zero `rhs` instead of producing infinities.
+    pub fn checked_div(&self, rhs: DataPointValue) -> GenResult<Self> {
+        if rhs == 0. {
+            Err(TimeSeriesError::new(String::from("division by zero")))
+        } else {
+            Ok(self.div(rhs))
+        }
     }
-    // taken from https://stackoverflow.com/a/53825685
-    // generic solution https://stackoverflow.com/a/41207820
     pub fn ts_add(&self, rhs: &TimeSeries1D) -> Self {
-        let (lhs, rhs) = self.intersect(&rhs);
-        let values: Vec<DataPointValue> = zip(lhs.values(), rhs.values())
-            .map(|(l, r)| l + r)
-            .collect();
-        TimeSeries1D::from_vec(lhs.index(), values)
+        self.zip_with(rhs, Join::Inner, |l, r| l + r)
     }
-    // taken from https://stackoverflow.com/a/53825685
-    // generic solution https://stackoverflow.com/a/41207820
     pub fn ts_sub(&self, rhs: &TimeSeries1D) -> Self {
-        let (lhs, rhs) = self.intersect(&rhs);
-        let values: Vec<DataPointValue> = zip(lhs.values(), rhs.values())
-            .map(|(l, r)| l - r)
-            .collect();
-        TimeSeries1D::from_vec(lhs.index(), values)
+        self.zip_with(rhs, Join::Inner, |l, r| l - r)
     }
-    // taken from https://stackoverflow.com/a/53825685
-    // generic solution https://stackoverflow.com/a/41207820
     pub fn ts_mul(&self, rhs: &TimeSeries1D) -> Self {
-        let (lhs, rhs) = self.intersect(&rhs);
-        let values: Vec<DataPointValue> = zip(lhs.values(), rhs.values())
-            .map(|(l, r)| l * r)
-            .collect();
-        TimeSeries1D::from_vec(lhs.index(), values)
+        self.zip_with(rhs, Join::Inner, |l, r| l * r)
     }
-    // taken from https://stackoverflow.com/a/53825685
-    // generic solution https://stackoverflow.com/a/41207820
     pub fn ts_div(&self, rhs: &TimeSeries1D) -> Self {
-        let (lhs, rhs) = self.intersect(&rhs);
-        let values: Vec<DataPointValue> = zip(lhs.values(), rhs.values())
-            .map(|(l, r)| l / r)
-            .collect();
-        TimeSeries1D::from_vec(lhs.index(), values)
+        self.zip_with(rhs, Join::Inner, |l, r| l / r)
+    }
+    /// `ts_div` failing on the first zero denominator instead of producing infinities.
+    pub fn checked_ts_div(&self, rhs: &TimeSeries1D) -> GenResult<Self> {
+        let (lhs, rhs) = self.intersect(rhs);
+        check_denominators(&rhs.index, &rhs.values)?;
+        Ok(lhs.ts_div(&rhs))
     }
+    /// Simple moving average, empty when `window_size` is 0 or exceeds `len()`.
     pub fn sma(&self, window_size: usize) -> Self {
-        let mut index = self.index().clone();
-        index.reverse();
-        // FIXME handle window_size > self.len()
-        index.truncate(self.len() - window_size + 1);
-        index.reverse();
+        if window_size == 0 || window_size > self.len() {
+            return TimeSeries1D::new(BTreeMap::new());
+        }
+        let index = self.index[window_size - 1..].to_vec();
         let values = self
-            .values()
+            .values
             .windows(window_size)
             .map(|x| x.iter().sum::<DataPointValue>())
             .map(|x| x.div(window_size as DataPointValue))
             .collect();
-        TimeSeries1D::from_vec(index, values)
+        TimeSeries1D { index, values }
+    }
+    /// `sma` failing when the series is too short for a single window.
+    pub fn checked_sma(&self, window_size: usize) -> GenResult<Self> {
+        if window_size == 0 || window_size > self.len() {
+            Err(TimeSeriesError::new(format!(
+                "sma window of {} does not fit {} values",
+                window_size,
+                self.len()
+            )))
+        } else {
+            Ok(self.sma(window_size))
+        }
+    }
+    /// Rolling population standard deviation, empty when `window_size` is 0 or exceeds `len()`.
+    pub fn rolling_std(&self, window_size: usize) -> Self {
+        let sma = self.sma(window_size);
+        let values = self
+            .values
+            .windows(window_size.max(1))
+            .zip(sma.values.iter())
+            .map(|(window, mean)| {
+                let variance = window
+                    .iter()
+                    .map(|x| (x - mean).powi(2))
+                    .sum::<DataPointValue>()
+                    / window_size as DataPointValue;
+                variance.sqrt()
+            })
+            .collect();
+        sma.with_values(values)
+    }
+    /// Bollinger bands: the `middle` sma and the `upper` and `lower` bands `num_std` rolling
+    /// standard deviations away.
+    /// # Example
+    /// ```
+    /// use luckless::time_series::TimeSeries1D;
+    /// let ts = TimeSeries1D::from_values(vec![1., 3., 1., 3.]);
+    /// let bands = ts.bollinger_bands(2, 2.);
+    /// assert_eq!(bands.column_names(), vec!["middle", "upper", "lower"]);
+    /// assert_eq!(bands.values("upper").unwrap(), &[4., 4., 4.]);
+    /// assert_eq!(bands.values("lower").unwrap(), &[0., 0., 0.]);
+    /// ```
+    pub fn bollinger_bands(&self, window_size: usize, num_std: DataPointValue) -> TimeSeriesFrame {
+        let middle = self.sma(window_size);
+        let width = self.rolling_std(window_size).mul(num_std);
+        let mut bands = TimeSeriesFrame::new(middle.index.clone());
+        for (name, band) in [
+            ("middle", middle.clone()),
+            ("upper", middle.ts_add(&width)),
+            ("lower", middle.ts_sub(&width)),
+        ] {
+            bands
+                .insert(name.to_string(), &band)
+                .expect("distinct band names");
+        }
+        bands
     }
     /// Compute element-wise slope with window length 2
     /// ```text
@@ -207,15 +418,18 @@ impl TimeSeries1D {
     /// assert_eq!(slope.values(), vec![3.,-0.25,1./3.]);
     /// ```
     pub fn slope(&self) -> Self {
-        let mut index = self.index().clone();
-        index.drain(..1);
+        let index = self.index.iter().skip(1).cloned().collect();
         let values = self
-            .values()
+            .values
             .windows(2)
-            // TODO handle divide by 0
             .map(|x| (x[1] - x[0]) / x[0])
             .collect();
-        TimeSeries1D::from_vec(index, values)
+        TimeSeries1D { index, values }
+    }
+    /// `slope` failing on a zero initial value instead of producing infinities.
+    pub fn checked_slope(&self) -> GenResult<Self> {
+        self.check_initial_values()?;
+        Ok(self.slope())
     }
 
     /// Compute element-wise slope with window length 2
@@ -240,93 +454,631 @@ impl TimeSeries1D {
     ///  assert_eq!(ts.values().last().unwrap() / overall_change, *ts.values().first().unwrap());
     ///  ```
     pub fn relative_change(&self) -> Self {
-        let mut index = self.index().clone();
-        index.drain(..1);
-        let values = self
-            .values()
-            .windows(2)
-            // TODO handle divide by 0
-            .map(|x| x[1] / x[0])
-            .collect();
-        TimeSeries1D::from_vec(index, values)
+        let index = self.index.iter().skip(1).cloned().collect();
+        let values = self.values.windows(2).map(|x| x[1] / x[0]).collect();
+        TimeSeries1D { index, values }
+    }
+    /// `relative_change` failing on a zero initial value instead of producing infinities.
+    pub fn checked_relative_change(&self) -> GenResult<Self> {
+        self.check_initial_values()?;
+        Ok(self.relative_change())
+    }
+    /// Every value but the last is the denominator of a window of `slope` and `relative_change`.
+    fn check_initial_values(&self) -> GenResult<()> {
+        let initial = self.len().saturating_sub(1);
+        check_denominators(&self.index[..initial], &self.values[..initial])
+    }
+    /// Number of NaN and infinite values.
+    pub fn count_non_finite(&self) -> usize {
+        self.values
+            .iter()
+            .filter(|value| !value.is_finite())
+            .count()
+    }
+    /// Fail on the first NaN or infinite value.
+    pub fn check_finite(&self) -> GenResult<()> {
+        match self.iter().find(|(_, value)| !value.is_finite()) {
+            Some((timestamp, value)) => Err(TimeSeriesError::new(format!(
+                "{} of {} values are not finite, the first is {} at {}",
+                self.count_non_finite(),
+                self.len(),
+                value,
+                timestamp
+            ))),
+            None => Ok(()),
+        }
+    }
+    /// Remove NaN and infinite values.
+    pub fn mask_invalid(&self) -> Self {
+        self.filter_values(|value| value.is_finite())
+    }
+    /// Keep the values satisfying `predicate`
+    fn filter_values(&self, predicate: impl Fn(DataPointValue) -> bool) -> Self {
+        let (index, values) = self
+            .iter()
+            .filter(|(_, value)| predicate(**value))
+            .map(|(timestamp, value)| (*timestamp, *value))
+            .unzip();
+        TimeSeries1D { index, values }
+    }
+    /// Apply the `invalid_values` policy to the NaN and infinite values of this series.
+    pub fn validate(&self, invalid_values: InvalidValues) -> GenResult<Self> {
+        match invalid_values {
+            InvalidValues::Keep => Ok(self.clone()),
+            InvalidValues::Mask => Ok(self.mask_invalid()),
+            InvalidValues::Error => {
+                self.check_finite()?;
+                Ok(self.clone())
+            }
+        }
     }
     pub fn zero_negatives(&self) -> Self {
-        TimeSeries1D::from_vec(
-            self.index().clone(),
-            self.values()
-                .clone()
-                .into_iter()
-                .map(|value| if value < 0f64 { 0f64 } else { value })
+        self.with_values(
+            self.values
+                .iter()
+                .map(|value| if *value < 0f64 { 0f64 } else { *value })
                 .collect(),
         )
     }
     pub fn filter_le(&self, timestamp: &TimeStamp) -> Self {
-        let tree: BTreeMap<TimeStamp, DataPointValue> = self
-            .data
-            .range(..=timestamp)
-            .map(|(timestamp, value)| (timestamp.clone(), value.clone()))
-            .collect();
-        TimeSeries1D::new(tree)
+        self.range(..=*timestamp).to_time_series()
     }
     pub fn filter_lt(&self, timestamp: &TimeStamp) -> Self {
-        let tree: BTreeMap<TimeStamp, DataPointValue> = self
-            .data
-            .range(..timestamp)
-            .map(|(timestamp, value)| (timestamp.clone(), value.clone()))
-            .collect();
-        TimeSeries1D::new(tree)
+        self.range(..*timestamp).to_time_series()
     }
     pub fn filter_ge(&self, timestamp: &TimeStamp) -> Self {
-        let tree: BTreeMap<TimeStamp, DataPointValue> = self
-            .data
-            .range(timestamp..)
-            .map(|(timestamp, value)| (timestamp.clone(), value.clone()))
-            .collect();
-        TimeSeries1D::new(tree)
+        self.range(*timestamp..).to_time_series()
     }
     pub fn filter_gt(&self, timestamp: &TimeStamp) -> Self {
-        let tree: BTreeMap<TimeStamp, DataPointValue> = self
-            .data
-            .range(timestamp..)
-            .skip(1)
-            .map(|(timestamp, value)| (timestamp.clone(), value.clone()))
+        self.range((Bound::Excluded(*timestamp), Bound::Unbounded))
+            .to_time_series()
+    }
+    /// Align the indices of 2 `TimeSeries` according to `missing_data`.
+    ///
+    /// `MissingData::Drop` is `intersect`, other policies keep the union of both indices.
+    /// # Example
+    /// ```
+    /// use luckless::time_series::{MissingData, TimeSeries1D};
+    /// let lhs = TimeSeries1D::from_values(vec![1., 2., 3.]);
+    /// let rhs = TimeSeries1D::from_vec(vec![lhs.index()[0], lhs.index()[2]], vec![10., 30.]);
+    /// let (_, interpolated) = lhs.align(&rhs, MissingData::Interpolate);
+    /// assert_eq!(interpolated.values(), vec![10., 20., 30.]);
+    /// let (dropped, _) = lhs.align(&rhs, MissingData::Drop);
+    /// assert_eq!(dropped.values(), vec![1., 3.]);
+    /// ```
+    pub fn align(&self, rhs: &TimeSeries1D, missing_data: MissingData) -> (Self, Self) {
+        match missing_data {
+            MissingData::Drop => self.intersect(rhs),
+            _ => {
+                let index = union(&self.index, &rhs.index);
+                (
+                    self.reindex(&index, missing_data),
+                    rhs.reindex(&index, missing_data),
+                )
+            }
+        }
+    }
+    /// Place this series on `index`, filling timestamps it lacks according to `missing_data`.
+    ///
+    /// Timestamps of this series not in `index` only serve to interpolate or forward fill.
+    pub fn reindex(&self, index: &[TimeStamp], missing_data: MissingData) -> Self {
+        let sorted: Index;
+        let index = if index.windows(2).all(|pair| pair[0] < pair[1]) {
+            index
+        } else {
+            let mut index = index.to_vec();
+            index.sort();
+            index.dedup();
+            sorted = index;
+            &sorted
+        };
+        let all = union(&self.index, index);
+        let mut values: Vec<DataPointValue> = all
+            .iter()
+            .map(|timestamp| *self.get(timestamp).unwrap_or(&DataPointValue::NAN))
+            .collect();
+        fill(&all, &mut values, missing_data);
+        let filled = TimeSeries1D { index: all, values };
+        let reindexed = TimeSeries1D {
+            index: index.to_vec(),
+            values: index
+                .iter()
+                .map(|timestamp| *filled.get(timestamp).expect("index is in the union"))
+                .collect(),
+        };
+        match missing_data {
+            MissingData::Drop => reindexed.dropna(),
+            _ => reindexed,
+        }
+    }
+    /// Replace NaN values according to `missing_data`, `MissingData::Drop` removes them.
+    pub fn fillna(&self, missing_data: MissingData) -> Self {
+        self.reindex(&self.index, missing_data)
+    }
+    /// Replace NaN values by `value`.
+    pub fn fillna_value(&self, value: DataPointValue) -> Self {
+        self.with_values(
+            self.values
+                .iter()
+                .map(|v| if v.is_nan() { value } else { *v })
+                .collect(),
+        )
+    }
+    /// Remove NaN values.
+    pub fn dropna(&self) -> Self {
+        self.filter_values(|value| !value.is_nan())
+    }
+    /// Aggregate the values of each `frequency` period into a single value.
+    ///
+    /// Each period is labelled by the timestamp of its last value, so a weekly or monthly value is
+    /// only known once its period has ended, or as of the latest value of a partial period.
+    /// # Example
+    /// ```
+    /// use luckless::time_series::{Aggregation, Frequency, TimeSeries1D};
+    /// // 2010-01-01 is a Friday
+    /// let ts = TimeSeries1D::from_values(vec![1., 2., 3., 4.]);
+    /// let weekly = ts.resample(Frequency::Weekly, Aggregation::Max);
+    /// assert_eq!(weekly.values(), vec![3., 4.]);
+    /// assert_eq!(weekly.index(), vec![ts.index()[2], ts.index()[3]]);
+    /// ```
+    pub fn resample(&self, frequency: Frequency, aggregation: Aggregation) -> Self {
+        let mut index: Index = vec![];
+        let mut values: Vec<DataPointValue> = vec![];
+        let mut period_values: Vec<DataPointValue> = vec![];
+        let mut data = self.iter().peekable();
+        while let Some((timestamp, value)) = data.next() {
+            period_values.push(*value);
+            let period_ends = match data.peek() {
+                Some((next, _)) => frequency.period(next) != frequency.period(timestamp),
+                None => true,
+            };
+            if period_ends {
+                index.push(*timestamp);
+                values.push(aggregation.apply(&period_values));
+                period_values.clear();
+            }
+        }
+        TimeSeries1D::from_vec(index, values)
+    }
+    /// Place this series on `index`, each timestamp taking the latest value at or before it.
+    ///
+    /// Timestamps before the first value are dropped.
+    /// # Example
+    /// ```
+    /// use luckless::time_series::{Aggregation, Frequency, TimeSeries1D};
+    /// // Friday 2010-01-01 to Sunday 2010-01-10
+    /// let daily = TimeSeries1D::from_values((1..=10).map(|x| x as f64).collect());
+    /// let weekly = daily.resample(Frequency::Weekly, Aggregation::Last);
+    /// assert_eq!(weekly.values(), vec![3., 10.]);
+    /// let aligned = weekly.asof(&daily.index());
+    /// assert_eq!(aligned.values(), vec![3., 3., 3., 3., 3., 3., 3., 10.]);
+    /// ```
+    pub fn asof(&self, index: &[TimeStamp]) -> Self {
+        let tree: BTreeMap<TimeStamp, DataPointValue> = index
+            .iter()
+            .flat_map(
+                |timestamp| match self.index.partition_point(|t| t <= timestamp) {
+                    0 => None,
+                    known => Some((*timestamp, self.values[known - 1])),
+                },
+            )
             .collect();
         TimeSeries1D::new(tree)
     }
 }
 
-pub fn apply(
+impl<'a> TimeSeriesView<'a> {
+    pub fn index(&self) -> &'a [TimeStamp] {
+        self.index
+    }
+    pub fn values(&self) -> &'a [DataPointValue] {
+        self.values
+    }
+    pub fn iter(&self) -> impl Iterator<Item = (&'a TimeStamp, &'a DataPointValue)> {
+        self.index.iter().zip(self.values.iter())
+    }
+    pub fn is_empty(&self) -> bool {
+        self.index.is_empty()
+    }
+    pub fn len(&self) -> usize {
+        self.index.len()
+    }
+    pub fn get(&self, timestamp: &TimeStamp) -> Option<&'a DataPointValue> {
+        self.index
+            .binary_search(timestamp)
+            .ok()
+            .map(|position| &self.values[position])
+    }
+    /// Latest timestamp and value
+    pub fn last(&self) -> Option<(&'a TimeStamp, &'a DataPointValue)> {
+        self.index.last().zip(self.values.last())
+    }
+    /// Narrow this view to the timestamps in `range`, found by binary search.
+    pub fn range<R: RangeBounds<TimeStamp>>(&self, range: R) -> TimeSeriesView<'a> {
+        let start = match range.start_bound() {
+            Bound::Included(first) => self.index.partition_point(|t| t < first),
+            Bound::Excluded(first) => self.index.partition_point(|t| t <= first),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(last) => self.index.partition_point(|t| t <= last),
+            Bound::Excluded(last) => self.index.partition_point(|t| t < last),
+            Bound::Unbounded => self.index.len(),
+        }
+        .max(start);
+        TimeSeriesView {
+            index: &self.index[start..end],
+            values: &self.values[start..end],
+        }
+    }
+    /// Copy the viewed range into a new `TimeSeries1D`
+    pub fn to_time_series(&self) -> TimeSeries1D {
+        TimeSeries1D {
+            index: self.index.to_vec(),
+            values: self.values.to_vec(),
+        }
+    }
+}
+
+/// Element-wise operators: series with series is `Join::Inner` like `ts_add` and friends, series
+/// with scalar applies the scalar to every value.  Owned series reuse their buffer.
+macro_rules! impl_binary_op {
+    ($Op:ident, $op:ident, $ts_op:ident, $sym:tt) => {
+        impl $Op<&TimeSeries1D> for &TimeSeries1D {
+            type Output = TimeSeries1D;
+            fn $op(self, rhs: &TimeSeries1D) -> TimeSeries1D {
+                self.$ts_op(rhs)
+            }
+        }
+        impl $Op<TimeSeries1D> for &TimeSeries1D {
+            type Output = TimeSeries1D;
+            fn $op(self, rhs: TimeSeries1D) -> TimeSeries1D {
+                self.$ts_op(&rhs)
+            }
+        }
+        impl $Op<&TimeSeries1D> for TimeSeries1D {
+            type Output = TimeSeries1D;
+            fn $op(self, rhs: &TimeSeries1D) -> TimeSeries1D {
+                self.$ts_op(rhs)
+            }
+        }
+        impl $Op<TimeSeries1D> for TimeSeries1D {
+            type Output = TimeSeries1D;
+            fn $op(self, rhs: TimeSeries1D) -> TimeSeries1D {
+                self.$ts_op(&rhs)
+            }
+        }
+        impl $Op<DataPointValue> for &TimeSeries1D {
+            type Output = TimeSeries1D;
+            fn $op(self, rhs: DataPointValue) -> TimeSeries1D {
+                self.map(|value| value $sym rhs)
+            }
+        }
+        impl $Op<DataPointValue> for TimeSeries1D {
+            type Output = TimeSeries1D;
+            fn $op(self, rhs: DataPointValue) -> TimeSeries1D {
+                self.map_in_place(|value| value $sym rhs)
+            }
+        }
+        impl $Op<&TimeSeries1D> for DataPointValue {
+            type Output = TimeSeries1D;
+            fn $op(self, rhs: &TimeSeries1D) -> TimeSeries1D {
+                rhs.map(|value| self $sym value)
+            }
+        }
+        impl $Op<TimeSeries1D> for DataPointValue {
+            type Output = TimeSeries1D;
+            fn $op(self, rhs: TimeSeries1D) -> TimeSeries1D {
+                rhs.map_in_place(|value| self $sym value)
+            }
+        }
+    };
+}
+
+impl_binary_op!(Add, add, ts_add, +);
+impl_binary_op!(Sub, sub, ts_sub, -);
+impl_binary_op!(Mul, mul, ts_mul, *);
+impl_binary_op!(Div, div, ts_div, /);
+
+impl Neg for &TimeSeries1D {
+    type Output = TimeSeries1D;
+    fn neg(self) -> TimeSeries1D {
+        self.map(|value| -value)
+    }
+}
+
+impl Neg for TimeSeries1D {
+    type Output = TimeSeries1D;
+    fn neg(self) -> TimeSeries1D {
+        self.map_in_place(|value| -value)
+    }
+}
+
+/// Sorted timestamps of both sorted indices, merged in a single pass.
+fn union(lhs: &[TimeStamp], rhs: &[TimeStamp]) -> Index {
+    let mut index: Index = Vec::with_capacity(lhs.len().max(rhs.len()));
+    let (mut l_i, mut r_i) = (0, 0);
+    while l_i < lhs.len() || r_i < rhs.len() {
+        let next = match (lhs.get(l_i), rhs.get(r_i)) {
+            (Some(l), Some(r)) => match l.cmp(r) {
+                Ordering::Less => {
+                    l_i += 1;
+                    *l
+                }
+                Ordering::Equal => {
+                    l_i += 1;
+                    r_i += 1;
+                    *l
+                }
+                Ordering::Greater => {
+                    r_i += 1;
+                    *r
+                }
+            },
+            (Some(l), None) => {
+                l_i += 1;
+                *l
+            }
+            (None, Some(r)) => {
+                r_i += 1;
+                *r
+            }
+            (None, None) => unreachable!(),
+        };
+        if index.last() != Some(&next) {
+            index.push(next);
+        }
+    }
+    index
+}
+
+/// Timestamps kept when combining 2 series with `zip_with`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Join {
+    /// Timestamps of both series
+    Inner,
+    /// Timestamps of the left series
+    Left,
+    /// Timestamps of either series
+    Outer,
+}
+
+/// How values missing from a series, or NaN, are treated when aligning or filling it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(tag = "policy", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MissingData {
+    /// Keep only timestamps present in both series
+    #[default]
+    Drop,
+    /// Keep every timestamp, missing values are NaN
+    Outer,
+    /// Keep every timestamp, missing values repeat the latest value for at most `limit` timestamps
+    ForwardFill { limit: Option<usize> },
+    /// Keep every timestamp, missing values are interpolated in time between their neighbours
+    Interpolate,
+}
+
+impl TryFrom<&str> for MissingData {
+    type Error = GenError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.to_uppercase().as_str() {
+            "DROP" => Ok(MissingData::Drop),
+            "OUTER" => Ok(MissingData::Outer),
+            "FORWARD_FILL" => Ok(MissingData::ForwardFill { limit: None }),
+            "INTERPOLATE" => Ok(MissingData::Interpolate),
+            _ => Err(TimeSeriesError::new(format!(
+                "unknown missing data policy {}",
+                value
+            ))),
+        }
+    }
+}
+
+/// Fail on the first zero of `denominators` observed at `index`.
+fn check_denominators(index: &[TimeStamp], denominators: &[DataPointValue]) -> GenResult<()> {
+    match index
+        .iter()
+        .zip(denominators)
+        .find(|(_, denominator)| **denominator == 0.)
+    {
+        Some((timestamp, _)) => Err(TimeSeriesError::new(format!(
+            "division by zero at {}",
+            timestamp
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// How NaN and infinite values in the output of a calc are treated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum InvalidValues {
+    /// Pass them on to dependent calcs
+    #[default]
+    Keep,
+    /// Remove them from the output
+    Mask,
+    /// Fail the calc
+    Error,
+}
+
+impl TryFrom<&str> for InvalidValues {
+    type Error = GenError;
+
+    fn try_from(value: &str) -> GenResult<Self> {
+        match value.to_uppercase().as_str() {
+            "KEEP" => Ok(InvalidValues::Keep),
+            "MASK" => Ok(InvalidValues::Mask),
+            "ERROR" => Ok(InvalidValues::Error),
+            _ => Err(TimeSeriesError::new(format!(
+                "unknown invalid values policy {}",
+                value
+            ))),
+        }
+    }
+}
+
+/// Fill the NaN `values` observed at `index` according to `missing_data`.
+///
+/// Values before the first and after the last valid value are never interpolated.
+fn fill(index: &[TimeStamp], values: &mut [DataPointValue], missing_data: MissingData) {
+    match missing_data {
+        MissingData::Drop | MissingData::Outer => {}
+        MissingData::ForwardFill { limit } => {
+            let mut latest: Option<DataPointValue> = None;
+            let mut filled = 0usize;
+            for value in values.iter_mut() {
+                match (value.is_nan(), latest) {
+                    (false, _) => {
+                        latest = Some(*value);
+                        filled = 0;
+                    }
+                    (true, Some(latest)) if limit.filter(|limit| filled >= *limit).is_none() => {
+                        *value = latest;
+                        filled += 1;
+                    }
+                    (true, _) => {}
+                }
+            }
+        }
+        MissingData::Interpolate => {
+            let mut previous: Option<usize> = None;
+            for i in 0..values.len() {
+                if values[i].is_nan() {
+                    continue;
+                }
+                if let Some(p) = previous {
+                    let span = (index[i] - index[p]).num_seconds() as DataPointValue;
+                    for j in (p + 1)..i {
+                        let elapsed = (index[j] - index[p]).num_seconds() as DataPointValue;
+                        values[j] = values[p] + (values[i] - values[p]) * elapsed / span;
+                    }
+                }
+                previous = Some(i);
+            }
+        }
+    }
+}
+
+/// Target frequency of `TimeSeries1D::resample`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Quarterly,
+    Yearly,
+}
+
+impl Frequency {
+    /// Identifies the period `timestamp` falls in, weeks start on Monday.
+    pub fn period(&self, timestamp: &TimeStamp) -> (i32, u32) {
+        match self {
+            Frequency::Daily => (timestamp.year(), timestamp.ordinal()),
+            Frequency::Weekly => {
+                let week = timestamp.iso_week();
+                (week.year(), week.week())
+            }
+            Frequency::Monthly => (timestamp.year(), timestamp.month()),
+            Frequency::Quarterly => (timestamp.year(), timestamp.month0() / 3),
+            Frequency::Yearly => (timestamp.year(), 0),
+        }
+    }
+}
+
+impl TryFrom<&str> for Frequency {
+    type Error = GenError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.to_uppercase().as_str() {
+            "DAILY" => Ok(Frequency::Daily),
+            "WEEKLY" => Ok(Frequency::Weekly),
+            "MONTHLY" => Ok(Frequency::Monthly),
+            "QUARTERLY" => Ok(Frequency::Quarterly),
+            "YEARLY" => Ok(Frequency::Yearly),
+            _ => Err(TimeSeriesError::new(format!("unknown frequency {}", value))),
+        }
+    }
+}
+
+/// How `TimeSeries1D::resample` reduces the values of a period.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Aggregation {
+    Last,
+    First,
+    Mean,
+    Sum,
+    Max,
+    Min,
+}
+
+impl Aggregation {
+    /// Reduce the non empty `values` of a period
+    pub fn apply(&self, values: &[DataPointValue]) -> DataPointValue {
+        match self {
+            Aggregation::Last => *values.last().expect("empty period"),
+            Aggregation::First => *values.first().expect("empty period"),
+            Aggregation::Mean => values.iter().sum::<DataPointValue>() / values.len() as f64,
+            Aggregation::Sum => values.iter().sum(),
+            Aggregation::Max => values.iter().cloned().fold(f64::NEG_INFINITY, f64::max),
+            Aggregation::Min => values.iter().cloned().fold(f64::INFINITY, f64::min),
+        }
+    }
+}
+
+impl TryFrom<&str> for Aggregation {
+    type Error = GenError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.to_uppercase().as_str() {
+            "LAST" => Ok(Aggregation::Last),
+            "FIRST" => Ok(Aggregation::First),
+            "MEAN" => Ok(Aggregation::Mean),
+            "SUM" => Ok(Aggregation::Sum),
+            "MAX" => Ok(Aggregation::Max),
+            "MIN" => Ok(Aggregation::Min),
+            _ => Err(TimeSeriesError::new(format!(
+                "unknown aggregation {}",
+                value
+            ))),
+        }
+    }
+}
+
+/// Apply `func` to the values of every series at each timestamp of the first series, series
+/// missing a timestamp contribute NaN.
+pub fn apply<F: Fn(Vec<DataPointValue>) -> DataPointValue>(
     ts_vec: Vec<&TimeSeries1D>,
-    func: fn(Vec<DataPointValue>) -> DataPointValue,
+    func: F,
 ) -> TimeSeries1D {
     assert!(ts_vec.len() > 1);
-    let index = ts_vec.get(0).unwrap().index().clone();
-    let ts_len = index.len();
-    TimeSeries1D::from_vec(
-        index,
-        (0..ts_len)
-            .map(|idx| {
-                func(
-                    ts_vec
-                        .iter()
-                        .map(|ts| {
-                            let values = ts.values();
-                            let value = values.get(idx);
-                            value.unwrap_or(&0f64).clone()
-                        })
-                        .collect(),
-                )
-            })
-            .collect(),
-    )
+    let index = ts_vec[0].index();
+    let values = index
+        .iter()
+        .map(|timestamp| {
+            func(
+                ts_vec
+                    .iter()
+                    .map(|ts| *ts.get(timestamp).unwrap_or(&DataPointValue::NAN))
+                    .collect(),
+            )
+        })
+        .collect();
+    TimeSeries1D { index, values }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::convert::TryFrom;
+
     use chrono::prelude::*;
     use chrono::Duration;
 
-    use crate::time_series::TimeSeries1D;
+    use crate::time_series::{
+        apply, Aggregation, Frequency, InvalidValues, Join, MissingData, TimeSeries1D,
+        TimeSeriesView,
+    };
 
     #[test]
     fn new() {
@@ -822,4 +1574,315 @@ mod tests {
         let actual = ts.filter_gt(&(TimeSeries1D::epoch() + TimeSeries1D::index_unit() * 7));
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn resample_monthly() {
+        // 2010-01-01 to 2010-03-01
+        let ts = TimeSeries1D::from_values((0..60).map(|x| x as f64).collect());
+        let monthly = ts.resample(Frequency::Monthly, Aggregation::Mean);
+        assert_eq!(monthly.values(), vec![15., 44.5, 59.]);
+        assert_eq!(
+            monthly.index(),
+            vec![
+                Utc.ymd(2010, 1, 31).and_hms(0, 0, 0),
+                Utc.ymd(2010, 2, 28).and_hms(0, 0, 0),
+                Utc.ymd(2010, 3, 1).and_hms(0, 0, 0),
+            ]
+        );
+        assert_eq!(
+            ts.resample(Frequency::Monthly, Aggregation::Sum).values()[0],
+            (0..31).sum::<i32>() as f64
+        );
+        assert_eq!(
+            ts.resample(Frequency::Monthly, Aggregation::First).values(),
+            vec![0., 31., 59.]
+        );
+        assert_eq!(
+            ts.resample(Frequency::Monthly, Aggregation::Min).values(),
+            vec![0., 31., 59.]
+        );
+        assert_eq!(ts.resample(Frequency::Daily, Aggregation::Last), ts);
+        assert_eq!(
+            ts.resample(Frequency::Yearly, Aggregation::Max).values(),
+            vec![59.]
+        );
+        assert!(TimeSeries1D::from_values(vec![])
+            .resample(Frequency::Weekly, Aggregation::Last)
+            .is_empty());
+    }
+
+    #[test]
+    fn parse_frequency_and_aggregation() {
+        assert_eq!(Frequency::try_from("weekly").unwrap(), Frequency::Weekly);
+        assert_eq!(
+            Frequency::try_from("QUARTERLY").unwrap(),
+            Frequency::Quarterly
+        );
+        assert!(Frequency::try_from("hourly").is_err());
+        assert_eq!(Aggregation::try_from("Mean").unwrap(), Aggregation::Mean);
+        assert!(Aggregation::try_from("median").is_err());
+    }
+
+    #[test]
+    fn asof() {
+        let ts = TimeSeries1D::from_vec(
+            vec![
+                TimeSeries1D::epoch() + TimeSeries1D::index_unit() * 1,
+                TimeSeries1D::epoch() + TimeSeries1D::index_unit() * 4,
+            ],
+            vec![1., 4.],
+        );
+        let index: Vec<_> = (0..6)
+            .map(|x| TimeSeries1D::epoch() + TimeSeries1D::index_unit() * x)
+            .collect();
+        let aligned = ts.asof(&index);
+        assert_eq!(aligned.index(), index[1..].to_vec());
+        assert_eq!(aligned.values(), vec![1., 1., 1., 4., 4.]);
+    }
+
+    fn gappy() -> TimeSeries1D {
+        TimeSeries1D::from_values(vec![1., f64::NAN, f64::NAN, f64::NAN, 5., f64::NAN])
+    }
+
+    #[test]
+    fn fillna() {
+        let ts = gappy();
+        let filled = ts.fillna(MissingData::ForwardFill { limit: Some(2) });
+        assert_eq!(filled.index(), ts.index());
+        assert_eq!(filled.values()[..3], [1., 1., 1.]);
+        assert!(filled.values()[3].is_nan());
+        assert_eq!(filled.values()[4..], [5., 5.]);
+        let filled = ts.fillna(MissingData::ForwardFill { limit: None });
+        assert_eq!(filled.values(), vec![1., 1., 1., 1., 5., 5.]);
+        let interpolated = ts.fillna(MissingData::Interpolate);
+        assert_eq!(interpolated.values()[..5], [1., 2., 3., 4., 5.]);
+        // nothing to interpolate towards
+        assert!(interpolated.values()[5].is_nan());
+        assert_eq!(ts.fillna(MissingData::Drop).values(), vec![1., 5.]);
+        assert_eq!(ts.dropna(), ts.fillna(MissingData::Drop));
+        assert_eq!(ts.fillna(MissingData::Outer).len(), ts.len());
+        assert_eq!(ts.fillna_value(0.).values(), vec![1., 0., 0., 0., 5., 0.]);
+    }
+
+    #[test]
+    fn reindex_unsorted() {
+        let ts = gappy();
+        let at = |i: i32| TimeSeries1D::epoch() + TimeSeries1D::index_unit() * i;
+        let reindexed = ts.reindex(
+            &[at(4), at(1), at(6), at(4), at(0)],
+            MissingData::ForwardFill { limit: None },
+        );
+        assert_eq!(reindexed.index(), vec![at(0), at(1), at(4), at(6)]);
+        assert_eq!(reindexed.values(), vec![1., 1., 5., 5.]);
+    }
+
+    #[test]
+    fn align_outer() {
+        let lhs = TimeSeries1D::from_vec(
+            vec![
+                TimeSeries1D::epoch(),
+                TimeSeries1D::epoch() + TimeSeries1D::index_unit() * 2,
+            ],
+            vec![1., 3.],
+        );
+        let rhs = TimeSeries1D::from_vec(
+            vec![
+                TimeSeries1D::epoch() + TimeSeries1D::index_unit() * 1,
+                TimeSeries1D::epoch() + TimeSeries1D::index_unit() * 2,
+            ],
+            vec![20., 30.],
+        );
+        let (l, r) = lhs.align(&rhs, MissingData::Outer);
+        assert_eq!(l.index(), r.index());
+        assert_eq!(l.len(), 3);
+        assert!(l.values()[1].is_nan());
+        assert!(r.values()[0].is_nan());
+        let sum = l.ts_add(&r);
+        assert!(sum.values()[0].is_nan() && sum.values()[1].is_nan());
+        assert_eq!(sum.values()[2], 33.);
+        let (l, r) = lhs.align(&rhs, MissingData::ForwardFill { limit: None });
+        assert_eq!(l.values(), vec![1., 1., 3.]);
+        assert!(r.values()[0].is_nan());
+        assert_eq!(r.values()[1..], [20., 30.]);
+        assert_eq!(lhs.align(&rhs, MissingData::Drop), lhs.intersect(&rhs));
+    }
+
+    #[test]
+    fn apply_missing_is_nan() {
+        let lhs = TimeSeries1D::from_values(vec![1., 2.]);
+        let rhs = TimeSeries1D::from_values(vec![10.]);
+        let sum = apply(vec![&lhs, &rhs], |values| values.iter().sum());
+        assert_eq!(sum.values()[0], 11.);
+        assert!(sum.values()[1].is_nan());
+    }
+
+    #[test]
+    fn parse_missing_data() {
+        assert_eq!(
+            MissingData::try_from("forward_fill").unwrap(),
+            MissingData::ForwardFill { limit: None }
+        );
+        assert!(MissingData::try_from("zero").is_err());
+        let policy: MissingData = serde_yaml::from_str("policy: FORWARD_FILL\nlimit: 3").unwrap();
+        assert_eq!(policy, MissingData::ForwardFill { limit: Some(3) });
+        let policy: MissingData = serde_yaml::from_str("policy: INTERPOLATE").unwrap();
+        assert_eq!(policy, MissingData::Interpolate);
+    }
+
+    #[test]
+    fn sma_short_input() {
+        let ts = TimeSeries1D::from_values(vec![1., 2., 3.]);
+        assert_eq!(ts.sma(3).values(), vec![2.]);
+        assert!(ts.sma(4).is_empty());
+        assert!(ts.sma(0).is_empty());
+        assert!(ts.checked_sma(4).is_err());
+        assert!(ts.checked_sma(0).is_err());
+        assert_eq!(ts.checked_sma(3).unwrap(), ts.sma(3));
+    }
+
+    #[test]
+    fn checked_division() {
+        let ts = TimeSeries1D::from_values(vec![2., 0., 4.]);
+        assert!(ts.checked_div(0.).is_err());
+        assert_eq!(ts.checked_div(2.).unwrap().values(), vec![1., 0., 2.]);
+        let error = TimeSeries1D::from_values(vec![1., 1., 1.])
+            .checked_ts_div(&ts)
+            .unwrap_err();
+        assert!(error.to_string().contains(&ts.index()[1].to_string()));
+        let rhs = TimeSeries1D::from_vec(vec![ts.index()[0], ts.index()[2]], vec![1., 2.]);
+        assert_eq!(ts.checked_ts_div(&rhs).unwrap().values(), vec![2., 2.]);
+        // 0 is the initial value of the second window only
+        assert!(ts.checked_slope().is_err());
+        assert!(ts.checked_relative_change().is_err());
+        let ts = TimeSeries1D::from_values(vec![2., 4., 0.]);
+        assert_eq!(ts.checked_slope().unwrap().values(), vec![1., -1.]);
+        assert_eq!(ts.checked_relative_change().unwrap().values(), vec![2., 0.]);
+        assert!(TimeSeries1D::from_values(vec![0.])
+            .checked_slope()
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn validate() {
+        let ts = TimeSeries1D::from_values(vec![1., f64::NAN, f64::INFINITY, 4.]);
+        assert_eq!(ts.count_non_finite(), 2);
+        assert!(ts.check_finite().is_err());
+        assert_eq!(ts.mask_invalid().values(), vec![1., 4.]);
+        assert_eq!(ts.validate(InvalidValues::Mask).unwrap(), ts.mask_invalid());
+        assert_eq!(ts.validate(InvalidValues::Keep).unwrap().len(), 4);
+        assert!(ts.validate(InvalidValues::Error).is_err());
+        let finite = ts.mask_invalid();
+        assert_eq!(finite.validate(InvalidValues::Error).unwrap(), finite);
+        assert_eq!(
+            InvalidValues::try_from("mask").unwrap(),
+            InvalidValues::Mask
+        );
+        assert!(InvalidValues::try_from("ignore").is_err());
+    }
+
+    #[test]
+    fn from_unsorted_vec() {
+        let index = vec![
+            TimeSeries1D::epoch() + TimeSeries1D::index_unit() * 2,
+            TimeSeries1D::epoch(),
+            TimeSeries1D::epoch() + TimeSeries1D::index_unit() * 2,
+        ];
+        let ts = TimeSeries1D::from_vec(index, vec![1., 2., 3.]);
+        assert_eq!(ts.values_slice(), &[2., 3.]);
+        assert_eq!(
+            ts.index_slice(),
+            &[
+                TimeSeries1D::epoch(),
+                TimeSeries1D::epoch() + TimeSeries1D::index_unit() * 2
+            ]
+        );
+    }
+
+    #[test]
+    fn range_views() {
+        let ts = TimeSeries1D::from_values(vec![1., 2., 3., 4., 5.]);
+        let index = ts.index();
+        let view: TimeSeriesView = ts.range(index[1]..=index[3]);
+        assert_eq!(view.values(), &[2., 3., 4.]);
+        assert_eq!(view.index(), &index[1..4]);
+        assert_eq!(view.get(&index[2]), Some(&3.));
+        assert_eq!(view.get(&index[4]), None);
+        assert_eq!(view.range(index[3]..).values(), &[4.]);
+        assert_eq!(view.last(), Some((&index[3], &4.)));
+        assert!(ts.range(index[3]..index[1]).is_empty());
+        assert!(ts.range(..index[0]).is_empty());
+        assert_eq!(ts.range(..).to_time_series(), ts);
+        // timestamps between index values
+        let noon = index[1] + Duration::hours(12);
+        assert_eq!(ts.range(noon..).values(), &[3., 4., 5.]);
+        assert_eq!(ts.range(..noon).values(), &[1., 2.]);
+    }
+
+    #[test]
+    fn operators() {
+        let lhs = TimeSeries1D::from_values(vec![1., 2., 4.]);
+        let rhs = TimeSeries1D::from_vec(lhs.index()[1..].to_vec(), vec![1., 2.]);
+        assert_eq!(&lhs + &rhs, lhs.ts_add(&rhs));
+        assert_eq!(&lhs - &rhs, lhs.ts_sub(&rhs));
+        assert_eq!(&lhs * &rhs, lhs.ts_mul(&rhs));
+        assert_eq!(lhs.clone() / rhs.clone(), lhs.ts_div(&rhs));
+        assert_eq!((&lhs + 1.).values(), vec![2., 3., 5.]);
+        assert_eq!((lhs.clone() - 1.).values(), vec![0., 1., 3.]);
+        assert_eq!((2. * &lhs).values(), vec![2., 4., 8.]);
+        assert_eq!((4. / lhs.clone()).values(), vec![4., 2., 1.]);
+        assert_eq!((1. - &lhs).values(), vec![0., -1., -3.]);
+        assert_eq!((-&lhs).values(), vec![-1., -2., -4.]);
+        assert_eq!(-lhs.clone(), &lhs * -1.);
+        assert_eq!((&lhs + &lhs) / 2., lhs);
+    }
+
+    #[test]
+    fn zip_with_joins() {
+        let lhs = TimeSeries1D::from_values(vec![1., 2., 3., 4.]);
+        let index = lhs.index();
+        let rhs = TimeSeries1D::from_vec(
+            vec![index[1], index[3], index[3] + Duration::days(1)],
+            vec![10., 20., 30.],
+        );
+        let sum = |l: f64, r: f64| l + r;
+        let inner = lhs.zip_with(&rhs, Join::Inner, sum);
+        assert_eq!(inner.index(), vec![index[1], index[3]]);
+        assert_eq!(inner.values(), vec![12., 24.]);
+        let left = lhs.zip_with(&rhs, Join::Left, sum);
+        assert_eq!(left.index(), index);
+        assert_eq!(left.values()[1], 12.);
+        assert!(left.values()[0].is_nan());
+        let outer = lhs.zip_with(&rhs, Join::Outer, |l, r| if r.is_nan() { l } else { r });
+        assert_eq!(outer.values(), vec![1., 10., 3., 20., 30.]);
+        let empty = TimeSeries1D::from_values(vec![]);
+        let unmatched = lhs.zip_with(&empty, Join::Outer, sum);
+        assert_eq!(unmatched.index(), index);
+        assert!(unmatched.values().iter().all(|value| value.is_nan()));
+        assert_eq!(
+            lhs.map(|value| value * value)
+                .fold(0., |sum, value| sum + value),
+            30.
+        );
+        let threshold = 2.5;
+        let above = apply(vec![&lhs, &rhs], |values| {
+            (values[0] > threshold) as u8 as f64
+        });
+        assert_eq!(above.values(), vec![0., 0., 1., 1.]);
+    }
+
+    #[test]
+    fn serde_round_trip() {
+        let ts = TimeSeries1D::from_values(vec![1., f64::NAN, 3.]);
+        let json = serde_json::to_string(&ts).unwrap();
+        let parsed: TimeSeries1D = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.index(), ts.index());
+        assert_eq!(parsed.values()[2], 3.);
+        assert!(parsed.values()[1].is_nan());
+        let yaml = serde_yaml::to_string(&ts.dropna()).unwrap();
+        assert_eq!(
+            serde_yaml::from_str::<TimeSeries1D>(&yaml).unwrap(),
+            ts.dropna()
+        );
+    }
 }
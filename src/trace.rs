@@ -0,0 +1,414 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::Write;
+use std::time::Duration;
+
+use serde::{Serialize, Serializer};
+
+use crate::bot::asset_score::{AssetScoreStatus, CalculationStatus};
+use crate::data::Symbol;
+use crate::dto::strategy::{TimeSeriesName, TimeSeriesReference};
+use crate::errors::GenResult;
+use crate::frame::TimeSeriesFrame;
+use crate::time_series::{TimeSeries1D, TimeStamp};
+
+/// What happened when a calc ran on an asset.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CalcTrace {
+    pub calc: TimeSeriesName,
+    pub operation: String,
+    pub status: CalculationStatus,
+    /// Wall clock time the calc started
+    pub started_at: Option<TimeStamp>,
+    pub ended_at: Option<TimeStamp>,
+    #[serde(rename = "duration_ms", serialize_with = "serialize_optional_millis")]
+    pub duration: Option<Duration>,
+    /// Lengths of the upstream series referenced by the calc
+    pub input_lens: BTreeMap<TimeSeriesReference, usize>,
+    /// Length of the output series, or rows of the output frame
+    pub output_len: Option<usize>,
+    /// First and last timestamps of the output
+    pub first: Option<TimeStamp>,
+    pub last: Option<TimeStamp>,
+    /// NaN and infinite values in the output, after the invalid values policy
+    pub non_finite: Option<usize>,
+    pub error: Option<String>,
+}
+
+impl CalcTrace {
+    pub fn not_started(calc: &str, operation: &str) -> Self {
+        CalcTrace {
+            calc: calc.to_string(),
+            operation: operation.to_string(),
+            status: CalculationStatus::NotStarted,
+            started_at: None,
+            ended_at: None,
+            duration: None,
+            input_lens: BTreeMap::new(),
+            output_len: None,
+            first: None,
+            last: None,
+            non_finite: None,
+            error: None,
+        }
+    }
+
+    pub(crate) fn series_output(&mut self, time_series: &TimeSeries1D) {
+        let index = time_series.index_slice();
+        self.output_len = Some(time_series.len());
+        self.first = index.first().cloned();
+        self.last = index.last().cloned();
+        self.non_finite = Some(time_series.count_non_finite());
+    }
+
+    pub(crate) fn frame_output(&mut self, frame: &TimeSeriesFrame) {
+        self.output_len = Some(frame.len());
+        self.first = frame.index().first().cloned();
+        self.last = frame.index().last().cloned();
+        self.non_finite = Some(frame.count_non_finite());
+    }
+
+    /// Records the end of a calc started `duration` ago
+    pub(crate) fn finish(&mut self, status: CalculationStatus, duration: Duration) {
+        self.status = status;
+        self.duration = Some(duration);
+        self.ended_at = self.started_at.and_then(|started_at| {
+            chrono::Duration::from_std(duration)
+                .ok()
+                .map(|d| started_at + d)
+        });
+    }
+}
+
+/// Calcs run to score an asset as of a timestamp, in execution order.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AssetTrace {
+    pub symbol: Symbol,
+    /// As of timestamp of the score
+    pub timestamp: TimeStamp,
+    pub status: AssetScoreStatus,
+    pub started_at: TimeStamp,
+    #[serde(rename = "duration_ms", serialize_with = "serialize_millis")]
+    pub duration: Duration,
+    pub error: Option<String>,
+    pub calcs: Vec<CalcTrace>,
+}
+
+/// Traces of every asset scored by a run, or at every rebalance day of a back test.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RunTrace {
+    pub strategy: String,
+    pub assets: Vec<AssetTrace>,
+}
+
+/// Calc aggregated over the assets of a run
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalcProfile {
+    pub calc: TimeSeriesName,
+    pub operation: String,
+    /// Times the calc started
+    pub runs: usize,
+    pub errors: usize,
+    pub non_finite: usize,
+    pub total: Duration,
+    pub max: Duration,
+    pub first_error: Option<String>,
+}
+
+impl CalcProfile {
+    pub fn mean(&self) -> Duration {
+        match self.runs {
+            0 => Duration::default(),
+            runs => self.total / runs as u32,
+        }
+    }
+}
+
+impl RunTrace {
+    pub fn new(strategy: &str) -> Self {
+        RunTrace {
+            strategy: strategy.to_string(),
+            assets: vec![],
+        }
+    }
+
+    /// Symbols of the assets failing to score, at any rebalance day of a back test
+    pub fn failed_symbols(&self) -> BTreeSet<&str> {
+        self.assets
+            .iter()
+            .filter(|asset| asset.status == AssetScoreStatus::Error)
+            .map(|asset| asset.symbol.as_str())
+            .collect()
+    }
+
+    pub fn write_json<W: Write>(&self, mut writer: W) -> GenResult<()> {
+        serde_json::to_writer_pretty(&mut writer, self)?;
+        writer.write_all(b"\n")?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Calcs ordered by total execution time, slowest first
+    pub fn profile(&self) -> Vec<CalcProfile> {
+        let mut profiles: BTreeMap<&str, CalcProfile> = BTreeMap::new();
+        for calc_trace in self.assets.iter().flat_map(|asset| asset.calcs.iter()) {
+            let profile = profiles
+                .entry(&calc_trace.calc)
+                .or_insert_with(|| CalcProfile {
+                    calc: calc_trace.calc.clone(),
+                    operation: calc_trace.operation.clone(),
+                    runs: 0,
+                    errors: 0,
+                    non_finite: 0,
+                    total: Duration::default(),
+                    max: Duration::default(),
+                    first_error: None,
+                });
+            if calc_trace.status == CalculationStatus::NotStarted {
+                continue;
+            }
+            let duration = calc_trace.duration.unwrap_or_default();
+            profile.runs += 1;
+            profile.total += duration;
+            profile.max = profile.max.max(duration);
+            profile.non_finite += calc_trace.non_finite.unwrap_or(0);
+            if calc_trace.status == CalculationStatus::Error {
+                profile.errors += 1;
+                if profile.first_error.is_none() {
+                    profile.first_error = calc_trace.error.clone();
+                }
+            }
+        }
+        let mut profiles: Vec<CalcProfile> = profiles.into_values().collect();
+        profiles.sort_by_key(|profile| std::cmp::Reverse(profile.total));
+        profiles
+    }
+
+    /// Tables of the `limit` slowest calcs and of the `limit` most failing calcs
+    pub fn write_profile<W: Write>(&self, mut writer: W, limit: usize) -> GenResult<()> {
+        let profiles = self.profile();
+        let failed = self
+            .assets
+            .iter()
+            .filter(|asset| asset.status == AssetScoreStatus::Error)
+            .count();
+        writeln!(
+            writer,
+            "{}: {} assets traced, {} failed\n",
+            self.strategy,
+            self.assets.len(),
+            failed
+        )?;
+        writeln!(writer, "slowest calcs")?;
+        let rows: Vec<Vec<String>> = profiles
+            .iter()
+            .take(limit)
+            .map(|profile| {
+                vec![
+                    profile.calc.clone(),
+                    profile.operation.clone(),
+                    profile.runs.to_string(),
+                    millis(profile.total),
+                    millis(profile.mean()),
+                    millis(profile.max),
+                    profile.non_finite.to_string(),
+                    profile.errors.to_string(),
+                ]
+            })
+            .collect();
+        write_table(
+            &mut writer,
+            &[
+                "CALC",
+                "OPERATION",
+                "RUNS",
+                "TOTAL MS",
+                "MEAN MS",
+                "MAX MS",
+                "NON-FINITE",
+                "ERRORS",
+            ],
+            &rows,
+            &[2, 3, 4, 5, 6, 7],
+        )?;
+        writeln!(writer, "\nmost failing calcs")?;
+        let mut failing: Vec<&CalcProfile> = profiles
+            .iter()
+            .filter(|profile| profile.errors > 0)
+            .collect();
+        failing.sort_by_key(|profile| std::cmp::Reverse(profile.errors));
+        if failing.is_empty() {
+            writeln!(writer, "none")?;
+        } else {
+            let rows: Vec<Vec<String>> = failing
+                .iter()
+                .take(limit)
+                .map(|profile| {
+                    vec![
+                        profile.calc.clone(),
+                        profile.operation.clone(),
+                        profile.runs.to_string(),
+                        profile.errors.to_string(),
+                        profile.first_error.clone().unwrap_or_default(),
+                    ]
+                })
+                .collect();
+            write_table(
+                &mut writer,
+                &["CALC", "OPERATION", "RUNS", "ERRORS", "FIRST ERROR"],
+                &rows,
+                &[2, 3],
+            )?;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+fn millis(duration: Duration) -> String {
+    format!("{:.3}", duration.as_secs_f64() * 1000.)
+}
+
+/// Aligned columns, numbers are right aligned
+fn write_table<W: Write>(
+    writer: &mut W,
+    header: &[&str],
+    rows: &[Vec<String>],
+    number_columns: &[usize],
+) -> GenResult<()> {
+    let widths: Vec<usize> = (0..header.len())
+        .map(|column| {
+            rows.iter()
+                .map(|row| row[column].len())
+                .chain(std::iter::once(header[column].len()))
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    let header: Vec<String> = header.iter().map(|h| h.to_string()).collect();
+    for row in std::iter::once(&header).chain(rows.iter()) {
+        let line = row
+            .iter()
+            .zip(&widths)
+            .enumerate()
+            .map(|(column, (cell, width))| {
+                if number_columns.contains(&column) {
+                    format!("{:>width$}", cell, width = width)
+                } else {
+                    format!("{:<width$}", cell, width = width)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("  ");
+        writeln!(writer, "{}", line.trim_end())?;
+    }
+    Ok(())
+}
+
+fn serialize_millis<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_f64(duration.as_secs_f64() * 1000.)
+}
+
+fn serialize_optional_millis<S: Serializer>(
+    duration: &Option<Duration>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match duration {
+        Some(duration) => serialize_millis(duration, serializer),
+        None => serializer.serialize_none(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::bot::asset_score::{AssetScoreStatus, CalculationStatus, RunnableStrategy};
+    use crate::data::Asset;
+    use crate::dto::strategy::from_path;
+    use crate::errors::GenResult;
+    use crate::mock_client::{MockDataClient, DATA_SIZE};
+    use crate::trace::RunTrace;
+
+    fn run_trace_fixture() -> GenResult<RunTrace> {
+        let strategy = from_path(Path::new("strategy.yaml"))?;
+        let runnable_strategy = RunnableStrategy::new(strategy, Box::new(MockDataClient::new()))?;
+        let assets = vec![Asset::new(String::from("A")), Asset::new(String::from("Z"))];
+        let (asset_scores, run_trace) =
+            runnable_strategy.trace_assets(assets, MockDataClient::today())?;
+        assert_eq!(asset_scores.len(), 2);
+        assert_eq!(
+            asset_scores[&Asset::new(String::from("Z"))].status(),
+            &AssetScoreStatus::Error
+        );
+        Ok(run_trace)
+    }
+
+    #[test]
+    fn asset_traces() -> GenResult<()> {
+        let run_trace = run_trace_fixture()?;
+        let traced = &run_trace.assets[0];
+        assert_eq!(traced.symbol, "A");
+        assert_eq!(traced.status, AssetScoreStatus::Complete);
+        let calcs: Vec<&str> = traced.calcs.iter().map(|c| c.calc.as_str()).collect();
+        assert_eq!(calcs.first(), Some(&"price"));
+        assert_eq!(calcs.last(), Some(&"sma_gap"));
+        let price = &traced.calcs[0];
+        assert_eq!(price.output_len, Some(DATA_SIZE));
+        assert_eq!(price.last, Some(MockDataClient::today()));
+        assert_eq!(price.non_finite, Some(0));
+        assert!(price.started_at <= price.ended_at);
+        let sma_gap = &traced.calcs[4];
+        assert_eq!(sma_gap.input_lens.len(), 2);
+        assert_eq!(sma_gap.input_lens["sma50"], DATA_SIZE - 49);
+
+        let failed = &run_trace.assets[1];
+        assert_eq!(failed.status, AssetScoreStatus::Error);
+        assert!(failed.error.is_some());
+        assert_eq!(failed.calcs[0].status, CalculationStatus::Error);
+        assert!(failed.calcs[0].error.is_some());
+        assert_eq!(failed.calcs[1].status, CalculationStatus::NotStarted);
+        assert_eq!(failed.calcs[1].duration, None);
+        assert_eq!(
+            run_trace.failed_symbols().into_iter().collect::<Vec<_>>(),
+            vec!["Z"]
+        );
+
+        let mut buffer = vec![];
+        run_trace.write_json(&mut buffer)?;
+        let json: serde_json::Value = serde_json::from_slice(&buffer)?;
+        assert_eq!(json["strategy"], "Example Strategy Document");
+        assert!(json["assets"][0]["calcs"][0]["duration_ms"].is_f64());
+        assert!(json["assets"][1]["calcs"][1]["duration_ms"].is_null());
+        Ok(())
+    }
+
+    #[test]
+    fn profile() -> GenResult<()> {
+        let run_trace = run_trace_fixture()?;
+        let profiles = run_trace.profile();
+        assert_eq!(profiles.len(), 5);
+        assert!(profiles.windows(2).all(|p| p[0].total >= p[1].total));
+        let price = profiles.iter().find(|p| p.calc == "price").expect("price");
+        assert_eq!((price.runs, price.errors), (2, 1));
+        let sma_gap = profiles
+            .iter()
+            .find(|p| p.calc == "sma_gap")
+            .expect("sma_gap");
+        assert_eq!((sma_gap.runs, sma_gap.errors), (1, 0));
+
+        let mut buffer = vec![];
+        run_trace.write_profile(&mut buffer, 3)?;
+        let table = String::from_utf8(buffer)?;
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(
+            lines[0],
+            "Example Strategy Document: 2 assets traced, 1 failed"
+        );
+        assert_eq!(lines[2], "slowest calcs");
+        assert!(lines[3].starts_with("CALC "));
+        // header and the 3 slowest calcs
+        assert_eq!(lines[8], "most failing calcs");
+        assert!(lines[10].starts_with("price  QUERY"));
+        Ok(())
+    }
+}
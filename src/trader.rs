@@ -0,0 +1,450 @@
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use chrono::{Duration, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::bot::asset_score::RunnableStrategy;
+use crate::broker::{BrokerClient, OrderId, OrderStatus};
+use crate::errors::GenResult;
+use crate::orders::{generate_orders, target_weights, Order, OrderConfig};
+use crate::time_series::TimeStamp;
+
+/// Source of time for the trading loop, simulated in tests.
+pub trait Clock {
+    fn now(&self) -> TimeStamp;
+    fn sleep(&self, duration: Duration);
+}
+
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> TimeStamp {
+        Utc::now()
+    }
+    fn sleep(&self, duration: Duration) {
+        thread::sleep(duration.to_std().unwrap_or_default());
+    }
+}
+
+/// Clock that only advances when slept on.  Clones share the same time.
+#[derive(Debug, Clone)]
+pub struct SimulatedClock {
+    now: Arc<Mutex<TimeStamp>>,
+}
+
+impl SimulatedClock {
+    pub fn new(start: TimeStamp) -> Self {
+        SimulatedClock {
+            now: Arc::new(Mutex::new(start)),
+        }
+    }
+}
+
+impl Clock for SimulatedClock {
+    fn now(&self) -> TimeStamp {
+        *self.now.lock().expect("clock")
+    }
+    fn sleep(&self, duration: Duration) {
+        let mut now = self.now.lock().expect("clock");
+        *now += duration;
+    }
+}
+
+/// When orders may be submitted, see `calendar::TradingCalendar`.
+pub trait MarketCalendar {
+    fn is_open(&self, timestamp: &TimeStamp) -> bool;
+}
+
+pub struct AlwaysOpen;
+
+impl MarketCalendar for AlwaysOpen {
+    fn is_open(&self, _timestamp: &TimeStamp) -> bool {
+        true
+    }
+}
+
+/// States of the execution model in `docs/execution-model.puml`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum TraderState {
+    /// Waiting for the next rebalance
+    Idle,
+    /// Scoring assets and generating orders
+    Computing,
+    /// Submitting `pending` orders once the market is open
+    Submitting {
+        pending: Vec<Order>,
+        submitted: Vec<OrderId>,
+    },
+    /// Polling submitted orders until none is pending
+    AwaitingFills { order_ids: Vec<OrderId> },
+}
+
+impl TraderState {
+    pub fn name(&self) -> &'static str {
+        match self {
+            TraderState::Idle => "Idle",
+            TraderState::Computing => "Computing",
+            TraderState::Submitting { .. } => "Submitting",
+            TraderState::AwaitingFills { .. } => "AwaitingFills",
+        }
+    }
+}
+
+/// Everything needed to resume the loop after a crash.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Checkpoint {
+    pub state: TraderState,
+    /// Exchange-local day of the last completed rebalance
+    pub last_rebalance: Option<NaiveDate>,
+}
+
+impl Default for Checkpoint {
+    fn default() -> Self {
+        Checkpoint {
+            state: TraderState::Idle,
+            last_rebalance: None,
+        }
+    }
+}
+
+/// Structured log line of a state transition.
+#[derive(Debug, Serialize)]
+struct Transition<'a> {
+    timestamp: TimeStamp,
+    from: &'a str,
+    to: &'a str,
+    detail: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct TraderConfig {
+    /// Sleep between steps that do not change state
+    pub poll_interval: Duration,
+    pub order_config: OrderConfig,
+    /// Checkpoint file, nothing is persisted when `None`
+    pub state_path: Option<PathBuf>,
+}
+
+impl Default for TraderConfig {
+    fn default() -> Self {
+        TraderConfig {
+            poll_interval: Duration::seconds(60),
+            order_config: OrderConfig::default(),
+            state_path: None,
+        }
+    }
+}
+
+/// Trading loop rebalancing the broker account to the strategy's scores once a day.
+///
+/// Orders are computed whenever a rebalance is due, submitted once the market is open and then
+/// polled until filled or rejected.  The checkpoint is saved after every transition and every
+/// submitted order, so a restarted trader resumes where it stopped.  An order whose submission
+/// was not yet checkpointed when the trader stopped is submitted again.
+pub struct Trader {
+    runnable_strategy: RunnableStrategy,
+    broker: Box<dyn BrokerClient>,
+    clock: Box<dyn Clock>,
+    calendar: Box<dyn MarketCalendar>,
+    config: TraderConfig,
+    checkpoint: Checkpoint,
+}
+
+impl Trader {
+    /// Resume from `config.state_path` if it exists.
+    pub fn new(
+        runnable_strategy: RunnableStrategy,
+        broker: Box<dyn BrokerClient>,
+        clock: Box<dyn Clock>,
+        calendar: Box<dyn MarketCalendar>,
+        config: TraderConfig,
+    ) -> GenResult<Self> {
+        let checkpoint = match &config.state_path {
+            Some(path) if path.exists() => serde_json::from_str(&fs::read_to_string(path)?)?,
+            _ => Checkpoint::default(),
+        };
+        Ok(Trader {
+            runnable_strategy,
+            broker,
+            clock,
+            calendar,
+            config,
+            checkpoint,
+        })
+    }
+
+    pub fn checkpoint(&self) -> &Checkpoint {
+        &self.checkpoint
+    }
+
+    pub fn broker(&self) -> &dyn BrokerClient {
+        &*self.broker
+    }
+
+    /// Step until `max_steps` (forever if `None`), sleeping whenever the state is unchanged.
+    ///
+    /// Failed steps are logged and retried after `poll_interval`.
+    pub fn run(&mut self, max_steps: Option<usize>) -> GenResult<()> {
+        let mut steps = 0usize;
+        while max_steps.is_none_or(|max_steps| steps < max_steps) {
+            let before = self.checkpoint.state.name();
+            let changed = match self.step() {
+                Ok(()) => before != self.checkpoint.state.name(),
+                Err(e) => {
+                    self.log(before, before, format!("error: {}", e));
+                    false
+                }
+            };
+            if !changed {
+                self.clock.sleep(self.config.poll_interval);
+            }
+            steps += 1;
+        }
+        Ok(())
+    }
+
+    /// Perform a single transition of the state machine.
+    pub fn step(&mut self) -> GenResult<()> {
+        let now = self.clock.now();
+        let today = self.runnable_strategy.calendar().local_date(&now);
+        let (next, detail) = match self.checkpoint.state.clone() {
+            TraderState::Idle => {
+                if self.checkpoint.last_rebalance == Some(today) {
+                    (TraderState::Idle, String::from("rebalanced today"))
+                } else {
+                    (TraderState::Computing, String::from("rebalance due"))
+                }
+            }
+            TraderState::Computing => {
+                let orders = self.compute_orders(&now)?;
+                if orders.is_empty() {
+                    self.checkpoint.last_rebalance = Some(today);
+                    (TraderState::Idle, String::from("no orders"))
+                } else {
+                    let detail = format!("{} orders", orders.len());
+                    let next = TraderState::Submitting {
+                        pending: orders,
+                        submitted: vec![],
+                    };
+                    (next, detail)
+                }
+            }
+            TraderState::Submitting { pending, submitted } => {
+                if self.calendar.is_open(&now) {
+                    let submitted = self.submit(pending, submitted)?;
+                    let detail = format!("{} orders submitted", submitted.len());
+                    (
+                        TraderState::AwaitingFills {
+                            order_ids: submitted,
+                        },
+                        detail,
+                    )
+                } else {
+                    (
+                        TraderState::Submitting { pending, submitted },
+                        String::from("market closed"),
+                    )
+                }
+            }
+            TraderState::AwaitingFills { order_ids } => {
+                let mut pending = 0usize;
+                let mut rejected = 0usize;
+                for order_id in order_ids.iter() {
+                    match self.broker.order_status(order_id)? {
+                        OrderStatus::Pending => pending += 1,
+                        OrderStatus::Rejected { .. } => rejected += 1,
+                        OrderStatus::Filled => (),
+                    }
+                }
+                match pending {
+                    0 => {
+                        self.checkpoint.last_rebalance = Some(today);
+                        let detail = format!(
+                            "{} filled, {} rejected",
+                            order_ids.len() - rejected,
+                            rejected
+                        );
+                        (TraderState::Idle, detail)
+                    }
+                    _ => (
+                        TraderState::AwaitingFills { order_ids },
+                        format!("{} pending", pending),
+                    ),
+                }
+            }
+        };
+        let from = self.checkpoint.state.name();
+        if from != next.name() {
+            self.log(from, next.name(), detail);
+        }
+        self.checkpoint.state = next;
+        self.save()
+    }
+
+    fn compute_orders(&self, now: &TimeStamp) -> GenResult<Vec<Order>> {
+        let asset_scores = self.runnable_strategy.run_on_universe(*now)?;
+        let targets = target_weights(&asset_scores);
+        let positions = self.broker.positions()?;
+        generate_orders(
+            &targets,
+            &positions,
+            self.runnable_strategy.data_client(),
+            now,
+            &self.config.order_config,
+        )
+    }
+
+    /// Submit orders one at a time, checkpointing after each.
+    fn submit(
+        &mut self,
+        mut pending: Vec<Order>,
+        mut submitted: Vec<OrderId>,
+    ) -> GenResult<Vec<OrderId>> {
+        while !pending.is_empty() {
+            let order = pending.remove(0);
+            submitted.push(self.broker.submit_order(order)?);
+            self.checkpoint.state = TraderState::Submitting {
+                pending: pending.clone(),
+                submitted: submitted.clone(),
+            };
+            self.save()?;
+        }
+        Ok(submitted)
+    }
+
+    fn log(&self, from: &str, to: &str, detail: String) {
+        let transition = Transition {
+            timestamp: self.clock.now(),
+            from,
+            to,
+            detail,
+        };
+        match serde_json::to_string(&transition) {
+            Ok(line) => println!("{}", line),
+            Err(e) => println!("unable to log transition: {}", e),
+        }
+    }
+
+    /// Write to a temporary file first so a crash never leaves a partial checkpoint behind.
+    fn save(&self) -> GenResult<()> {
+        if let Some(path) = &self.config.state_path {
+            let tmp_path = path.with_extension("tmp");
+            fs::write(&tmp_path, serde_json::to_string_pretty(&self.checkpoint)?)?;
+            fs::rename(&tmp_path, path)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+    use std::path::Path;
+
+    use chrono::{Datelike, Duration, Weekday};
+
+    use crate::bot::asset_score::RunnableStrategy;
+    use crate::broker::BrokerClient;
+    use crate::calendar::TradingCalendar;
+    use crate::dto::strategy::from_path;
+    use crate::errors::GenResult;
+    use crate::mock_client::MockDataClient;
+    use crate::paper_broker::{PaperBroker, PaperBrokerConfig};
+    use crate::trader::{
+        AlwaysOpen, Clock, MarketCalendar, SimulatedClock, Trader, TraderConfig, TraderState,
+    };
+
+    fn trader_fixture(
+        clock: &SimulatedClock,
+        calendar: Box<dyn MarketCalendar>,
+        config: TraderConfig,
+    ) -> GenResult<Trader> {
+        let strategy = from_path(Path::new("strategy.yaml")).expect("unable to load strategy");
+        let runnable_strategy = RunnableStrategy::new(strategy, Box::new(MockDataClient::new()))?;
+        let broker = PaperBroker::new(
+            Box::new(MockDataClient::new()),
+            PaperBrokerConfig::default(),
+        );
+        Trader::new(
+            runnable_strategy,
+            Box::new(broker),
+            Box::new(clock.clone()),
+            calendar,
+            config,
+        )
+    }
+
+    #[test]
+    fn rebalance_when_open() -> GenResult<()> {
+        let clock = SimulatedClock::new(MockDataClient::today());
+        let mut trader = trader_fixture(&clock, Box::new(AlwaysOpen), TraderConfig::default())?;
+        let mut states = vec![];
+        for _ in 0..4 {
+            trader.step()?;
+            states.push(trader.checkpoint().state.name());
+        }
+        assert_eq!(
+            states,
+            vec!["Computing", "Submitting", "AwaitingFills", "Idle"]
+        );
+        assert!(!trader.broker().fills()?.is_empty());
+        assert_eq!(
+            trader.checkpoint().last_rebalance,
+            Some(TradingCalendar::default().local_date(&clock.now()))
+        );
+        // rebalanced already today
+        trader.step()?;
+        assert_eq!(trader.checkpoint().state, TraderState::Idle);
+        Ok(())
+    }
+
+    #[test]
+    fn submit_waits_for_open() -> GenResult<()> {
+        // MockDataClient::today() is a Thursday, at the close
+        let clock = SimulatedClock::new(MockDataClient::today() + Duration::days(2));
+        assert_eq!(clock.now().weekday(), Weekday::Sat);
+        let config = TraderConfig {
+            poll_interval: Duration::hours(1),
+            ..TraderConfig::default()
+        };
+        let mut trader = trader_fixture(&clock, Box::new(TradingCalendar::default()), config)?;
+        trader.run(Some(10))?;
+        assert_eq!(trader.checkpoint().state.name(), "Submitting");
+        assert!(trader.broker().fills()?.is_empty());
+        // Monday 14:30 UTC is 31.5 hours away
+        trader.run(Some(40))?;
+        assert_eq!(trader.checkpoint().state, TraderState::Idle);
+        assert!(clock.now().weekday() == Weekday::Mon);
+        assert!(!trader.broker().fills()?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn resume_from_checkpoint() -> GenResult<()> {
+        let path = env::temp_dir().join(format!("trader_{}.json", std::process::id()));
+        let _ = fs::remove_file(&path);
+        let clock = SimulatedClock::new(MockDataClient::today());
+        let config = TraderConfig {
+            state_path: Some(path.clone()),
+            ..TraderConfig::default()
+        };
+        let calendar = Box::new(TradingCalendar::default());
+        let mut trader = trader_fixture(&clock, calendar, config.clone())?;
+        trader.step()?;
+        trader.step()?;
+        let resumed = trader_fixture(&clock, Box::new(TradingCalendar::default()), config)?;
+        match &resumed.checkpoint().state {
+            TraderState::Submitting { pending, submitted } => {
+                assert!(!pending.is_empty());
+                assert!(submitted.is_empty());
+            }
+            state => panic!("unexpected state {:?}", state),
+        }
+        fs::remove_file(&path)?;
+        Ok(())
+    }
+}
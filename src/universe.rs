@@ -0,0 +1,342 @@
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+
+use log::warn;
+use serde::{Deserialize, Serialize};
+
+use crate::data::{epoch, Asset, DataClient, Query, Series, Symbol};
+use crate::errors::{GenResult, InvalidStrategyError};
+use crate::time_series::{DataPointValue, TimeStamp};
+
+/// Assets a strategy is scored on, the `universe` section of a strategy yaml.
+///
+/// Symbols are resolved against `DataClient::assets`, then filtered by their data as of the
+/// scoring timestamp.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Universe {
+    /// Symbols to score, every asset when empty
+    pub include: Vec<Symbol>,
+    /// Symbols never scored
+    pub exclude: Vec<Symbol>,
+    /// Minimum number of values of `history_series` up to the scoring timestamp
+    pub min_history: Option<usize>,
+    pub history_series: Series,
+    pub min_average_volume: Option<VolumeFilter>,
+}
+
+impl Default for Universe {
+    fn default() -> Self {
+        Universe {
+            include: vec![],
+            exclude: vec![],
+            min_history: None,
+            history_series: String::from("close"),
+            min_average_volume: None,
+        }
+    }
+}
+
+/// Mean of the latest `window` values of `series` must be at least `min`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct VolumeFilter {
+    pub series: Series,
+    pub window: usize,
+    pub min: DataPointValue,
+}
+
+impl Default for VolumeFilter {
+    fn default() -> Self {
+        VolumeFilter {
+            series: String::from("volume"),
+            window: 20,
+            min: 0.,
+        }
+    }
+}
+
+impl Universe {
+    /// Universe of the given symbols
+    pub fn of(symbols: Vec<Symbol>) -> Self {
+        Universe {
+            include: symbols,
+            ..Universe::default()
+        }
+    }
+
+    /// Replaces the included symbols, unless `symbols` is empty, eg. with symbols given on the
+    /// command line. Excluded symbols and filters still apply.
+    pub fn with_include(mut self, symbols: Vec<Symbol>) -> Self {
+        if !symbols.is_empty() {
+            self.include = symbols;
+        }
+        self
+    }
+
+    pub(crate) fn check(&self, strategy_name: &str) -> GenResult<()> {
+        if let Some(volume_filter) = &self.min_average_volume {
+            if volume_filter.window == 0 {
+                return Err(InvalidStrategyError::new(
+                    strategy_name.to_string(),
+                    String::from("universe min_average_volume window must be positive"),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Included assets, less the excluded ones, passing the filters as of `timestamp`, ordered by
+    /// symbol. Assets whose filter series fail to load are left out, unknown symbols fail.
+    pub fn resolve(
+        &self,
+        data_client: &dyn DataClient,
+        timestamp: TimeStamp,
+    ) -> GenResult<Vec<Asset>> {
+        let symbols: BTreeSet<Symbol> = if self.include.is_empty() {
+            data_client.assets().keys().cloned().collect()
+        } else {
+            self.include.iter().cloned().collect()
+        };
+        let mut candidates = vec![];
+        for symbol in symbols {
+            if self.exclude.contains(&symbol) {
+                continue;
+            }
+            candidates.push(data_client.asset(&symbol)?.clone());
+        }
+        let queries: Vec<Query> = candidates
+            .iter()
+            .flat_map(|asset| self.filter_queries(asset, timestamp))
+            .collect();
+        if !queries.is_empty() {
+            if let Err(e) = data_client.prefetch(queries) {
+                warn!("prefetch failed, filtering assets one at a time: {}", e);
+            }
+        }
+        let mut assets = vec![];
+        for asset in candidates {
+            match self.passes_filters(data_client, &asset, timestamp) {
+                Ok(true) => assets.push(asset),
+                Ok(false) => {}
+                Err(e) => warn!("universe excludes {}: {}", asset, e),
+            }
+        }
+        Ok(assets)
+    }
+
+    /// Queries of the series the filters of `asset` run on
+    fn filter_queries(&self, asset: &Asset, timestamp: TimeStamp) -> Vec<Query> {
+        let query = |series: &Series| {
+            Query::new(
+                asset.symbol().to_string(),
+                series.clone(),
+                epoch(),
+                timestamp,
+            )
+        };
+        self.min_history
+            .map(|_| query(&self.history_series))
+            .into_iter()
+            .chain(
+                self.min_average_volume
+                    .as_ref()
+                    .map(|volume_filter| query(&volume_filter.series)),
+            )
+            .collect()
+    }
+
+    fn passes_filters(
+        &self,
+        data_client: &dyn DataClient,
+        asset: &Asset,
+        timestamp: TimeStamp,
+    ) -> GenResult<bool> {
+        let query = |series: &Series| {
+            data_client.query(Query::new(
+                asset.symbol().to_string(),
+                series.clone(),
+                epoch(),
+                timestamp,
+            ))
+        };
+        if let Some(min_history) = self.min_history {
+            let history = query(&self.history_series)?.filter_le(&timestamp).dropna();
+            if history.len() < min_history {
+                return Ok(false);
+            }
+        }
+        if let Some(volume_filter) = &self.min_average_volume {
+            let volume = query(&volume_filter.series)?.filter_le(&timestamp).dropna();
+            let latest =
+                &volume.values_slice()[volume.len().saturating_sub(volume_filter.window)..];
+            if latest.len() < volume_filter.window {
+                return Ok(false);
+            }
+            let average = latest.iter().sum::<DataPointValue>() / latest.len() as DataPointValue;
+            if average < volume_filter.min {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+}
+
+/// Symbols listed in a watchlist file, separated by whitespace or commas, `#` starts a comment.
+pub fn read_watchlist(path: &Path) -> GenResult<Vec<Symbol>> {
+    Ok(parse_watchlist(&fs::read_to_string(path)?))
+}
+
+fn parse_watchlist(text: &str) -> Vec<Symbol> {
+    text.lines()
+        .map(|line| line.split('#').next().unwrap_or_default())
+        .flat_map(|line| line.split(|c: char| c == ',' || c.is_whitespace()))
+        .filter(|symbol| !symbol.is_empty())
+        .map(|symbol| symbol.to_string())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::collections::HashMap;
+    use std::rc::Rc;
+
+    use crate::data::{Asset, DataClient, Query, Symbol};
+    use crate::errors::{GenError, GenResult};
+    use crate::mock_client::{MockDataClient, DATA_SIZE};
+    use crate::time_series::TimeSeries1D;
+    use crate::universe::{parse_watchlist, Universe, VolumeFilter};
+
+    /// Mock data without the volumes of B, counting prefetches
+    #[derive(Clone)]
+    struct MissingVolumes {
+        mock: MockDataClient,
+        prefetches: Rc<Cell<usize>>,
+    }
+
+    impl DataClient for MissingVolumes {
+        fn duplicate(&self) -> Box<dyn DataClient> {
+            Box::new(self.clone())
+        }
+        fn assets(&self) -> &HashMap<Symbol, Asset> {
+            DataClient::assets(&self.mock)
+        }
+        fn asset(&self, symbol: &Symbol) -> GenResult<&Asset> {
+            self.mock.asset(symbol)
+        }
+        fn query(&self, query: Query) -> GenResult<TimeSeries1D> {
+            if query.symbol() == "B" && query.series() == "volume" {
+                Err(GenError::from("no volume of B"))
+            } else {
+                self.mock.query(query)
+            }
+        }
+        fn prefetch(&self, _queries: Vec<Query>) -> GenResult<()> {
+            self.prefetches.set(self.prefetches.get() + 1);
+            Ok(())
+        }
+    }
+
+    fn symbols(universe: &Universe) -> GenResult<Vec<String>> {
+        Ok(universe
+            .resolve(&MockDataClient::new(), MockDataClient::today())?
+            .iter()
+            .map(|asset| asset.symbol().to_string())
+            .collect())
+    }
+
+    #[test]
+    fn include_and_exclude() -> GenResult<()> {
+        assert_eq!(symbols(&Universe::default())?, vec!["A", "B", "C"]);
+        assert_eq!(
+            symbols(&Universe::of(vec![String::from("C"), String::from("A")]))?,
+            vec!["A", "C"]
+        );
+        let universe = Universe {
+            exclude: vec![String::from("B")],
+            ..Universe::default()
+        };
+        assert_eq!(symbols(&universe)?, vec!["A", "C"]);
+        assert!(symbols(&Universe::of(vec![String::from("Z")])).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn filters() -> GenResult<()> {
+        let universe = Universe {
+            min_history: Some(DATA_SIZE + 1),
+            ..Universe::default()
+        };
+        assert!(symbols(&universe)?.is_empty());
+        let universe = Universe {
+            min_history: Some(DATA_SIZE),
+            ..Universe::default()
+        };
+        assert_eq!(symbols(&universe)?.len(), 3);
+        // mock volumes of A are 300K a day, B 2M, C rise from 1M to 3M
+        let universe = Universe {
+            min_average_volume: Some(VolumeFilter {
+                min: 1e6,
+                ..VolumeFilter::default()
+            }),
+            ..Universe::default()
+        };
+        assert_eq!(symbols(&universe)?, vec!["B", "C"]);
+        // mock prices of A oscillate around 10, B around 5, C rises from 5.3 to 7.2
+        let universe = Universe {
+            min_average_volume: Some(VolumeFilter {
+                series: String::from("close"),
+                min: 6.,
+                ..VolumeFilter::default()
+            }),
+            ..Universe::default()
+        };
+        assert_eq!(symbols(&universe)?, vec!["A", "C"]);
+        Ok(())
+    }
+
+    #[test]
+    fn failed_filters_exclude() -> GenResult<()> {
+        let data_client = MissingVolumes {
+            mock: MockDataClient::new(),
+            prefetches: Rc::new(Cell::new(0)),
+        };
+        let universe = Universe {
+            min_history: Some(DATA_SIZE),
+            min_average_volume: Some(VolumeFilter::default()),
+            ..Universe::default()
+        };
+        let symbols: Vec<String> = universe
+            .resolve(&data_client, MockDataClient::today())?
+            .iter()
+            .map(|asset| asset.symbol().to_string())
+            .collect();
+        assert_eq!(symbols, vec!["A", "C"]);
+        // history and volume of every asset in a single batch
+        assert_eq!(data_client.prefetches.get(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn parse_yaml() -> GenResult<()> {
+        let universe: Universe = serde_yaml::from_str(
+            "include: [A, B]\nmin_average_volume:\n  window: 5\n  min: 1000000\n",
+        )?;
+        assert_eq!(universe.include, vec!["A", "B"]);
+        assert_eq!(universe.history_series, "close");
+        let volume_filter = universe.min_average_volume.expect("volume filter");
+        assert_eq!(volume_filter.series, "volume");
+        assert_eq!(volume_filter.window, 5);
+        Ok(())
+    }
+
+    #[test]
+    fn watchlist() {
+        assert_eq!(
+            parse_watchlist("# tech\nA, B\n\nC # rising\n"),
+            vec!["A", "B", "C"]
+        );
+    }
+}
@@ -0,0 +1,65 @@
+use std::process::Command;
+use std::sync::Arc;
+
+use luckless::mock_client::MockDataClient;
+use luckless::query_server::serve;
+
+/// Run the luckless binary with `args`, returning its exit code and stdout
+fn luckless(args: &[&str]) -> (Option<i32>, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_luckless"))
+        .args(args)
+        .output()
+        .expect("unable to run luckless");
+    (
+        output.status.code(),
+        String::from_utf8_lossy(&output.stdout).to_string(),
+    )
+}
+
+#[test]
+fn grpc_stdout_holds_only_the_report() {
+    let server = serve(Arc::new(MockDataClient::new()), 0).expect("server");
+    let port = server.local_addr().port().expect("port").to_string();
+    let (code, stdout) = luckless(&[
+        "--grpc",
+        "--query-host",
+        "localhost",
+        "--query-port",
+        &port,
+        "score",
+        "--symbols",
+        "A",
+        "--at",
+        "2012-01-01T00:00:00+00:00",
+        "--format",
+        "json",
+    ]);
+    assert_eq!(code, Some(0));
+    let report: serde_json::Value = serde_json::from_str(&stdout).expect("a json report");
+    assert_eq!(report["scores"][0]["symbol"], "A");
+}
+
+#[test]
+fn grpc_retries_stay_off_stdout() {
+    // nothing listens on the port of a server dropped right away
+    let port = {
+        let server = serve(Arc::new(MockDataClient::new()), 0).expect("server");
+        server.local_addr().port().expect("port").to_string()
+    };
+    let (code, stdout) = luckless(&[
+        "--grpc",
+        "--query-host",
+        "localhost",
+        "--query-port",
+        &port,
+        "--query-retries",
+        "1",
+        "score",
+        "--symbols",
+        "A",
+        "--format",
+        "json",
+    ]);
+    assert_ne!(code, Some(0));
+    assert_eq!(stdout, "");
+}